@@ -77,7 +77,7 @@ fn fusion_length_scaling_benchmark(c: &mut Criterion) {
     let german_pipeline = normy::Normy::builder()
         .lang(DEU)
         .add_stage(CaseFold)
-        .add_stage(Transliterate)
+        .add_stage(Transliterate::default())
         .build();
 
     for (size_name, target_len, description) in &length_configs {
@@ -151,7 +151,7 @@ fn fusion_length_scaling_benchmark(c: &mut Criterion) {
     let russian_pipeline = normy::Normy::builder()
         .lang(RUS)
         .add_stage(CaseFold)
-        .add_stage(Transliterate)
+        .add_stage(Transliterate::default())
         .build();
 
     for (size_name, target_len, description) in &length_configs {
@@ -185,7 +185,7 @@ fn fusion_length_scaling_benchmark(c: &mut Criterion) {
     let french_pipeline = normy::Normy::builder()
         .lang(FRA)
         .add_stage(CaseFold)
-        .add_stage(Transliterate)
+        .add_stage(Transliterate::default())
         .add_stage(NormalizePunctuation)
         .add_stage(RemoveDiacritics)
         .build();
@@ -255,7 +255,7 @@ fn fusion_length_scaling_benchmark(c: &mut Criterion) {
     let japanese_pipeline = normy::Normy::builder()
         .lang(JPN)
         .add_stage(SegmentWords)
-        .add_stage(UnifyWidth)
+        .add_stage(UnifyWidth::default())
         .add_stage(NormalizePunctuation)
         .add_stage(COLLAPSE_WHITESPACE_UNICODE)
         .build();
@@ -233,7 +233,7 @@ define_normy_pipeline!(
     lang: FRA,
     stages: [
         LowerCase    => LowerCase,
-        Transliterate => Transliterate,
+        Transliterate => Transliterate::default(),
     ]
 );
 
@@ -212,7 +212,7 @@ fn bench_stage_length_scaling<S, C>(
 // ============================================================================
 
 fn bench_unify_width(c: &mut Criterion) {
-    bench_stage_length_scaling(c, "UnifyWidth", || UnifyWidth, TEXT_FULLWIDTH);
+    bench_stage_length_scaling(c, "UnifyWidth", UnifyWidth::default, TEXT_FULLWIDTH);
 }
 
 fn bench_nfc(c: &mut Criterion) {
@@ -273,7 +273,12 @@ fn bench_casefold_focused(c: &mut Criterion) {
 }
 
 fn bench_transliterate_focused(c: &mut Criterion) {
-    bench_stage_focused(c, "Transliterate", TRANSLITERATE_SAMPLES, || Transliterate);
+    bench_stage_focused(
+        c,
+        "Transliterate",
+        TRANSLITERATE_SAMPLES,
+        Transliterate::default,
+    );
 }
 
 fn bench_removediacritics_focused(c: &mut Criterion) {
@@ -319,7 +324,7 @@ fn bench_construction_overhead(c: &mut Criterion) {
         println!("  📦 Transliterate");
 
         group.bench_function("stage_construction/Transliterate", |b| {
-            b.iter(|| black_box(Transliterate))
+            b.iter(|| black_box(Transliterate::default()))
         });
 
         group.bench_function("context_construction/Transliterate", |b| {
@@ -327,7 +332,14 @@ fn bench_construction_overhead(c: &mut Criterion) {
         });
 
         group.bench_function("pipeline_construction/Transliterate", |b| {
-            b.iter(|| black_box(Normy::builder().lang(RUS).add_stage(Transliterate).build()))
+            b.iter(|| {
+                black_box(
+                    Normy::builder()
+                        .lang(RUS)
+                        .add_stage(Transliterate::default())
+                        .build(),
+                )
+            })
         });
     }
 
@@ -520,7 +532,7 @@ fn bench_needs_apply_overhead(c: &mut Criterion) {
         let label = "Russian Transliterate";
         let base_text = "Privet mir ";
         let lang = RUS;
-        let stage = Transliterate;
+        let stage = Transliterate::default();
 
         println!("  🔬 {}", label);
 
@@ -133,7 +133,7 @@ macro_rules! bench_processes {
             let name = stringify!($stage);
 
             // Call the bench functions, passing a closure that constructs the stage
-            collection_methods_benches_auto($c, name, || $stage);
+            collection_methods_benches_auto($c, name, $stage::default);
         )*
     };
 }
@@ -94,7 +94,7 @@ fn web_scraping() -> NormyBuilder<impl FusablePipeline> {
         .add_stage(StripHtml)
         .add_stage(StripControlChars)
         .add_stage(StripFormatControls)
-        .add_stage(UnifyWidth)
+        .add_stage(UnifyWidth::default())
         .add_stage(NORMALIZE_WHITESPACE_FULL)
 }
 
@@ -114,7 +114,7 @@ fn search() -> NormyBuilder<impl FusablePipeline> {
 fn cjk_search() -> NormyBuilder<impl FusablePipeline> {
     Normy::builder()
         .add_stage(NFKC)
-        .add_stage(UnifyWidth)
+        .add_stage(UnifyWidth::default())
         .add_stage(StripFormatControls)
         .add_stage(StripControlChars)
         .add_stage(NORMALIZE_WHITESPACE_FULL)
@@ -138,7 +138,7 @@ fn maximum() -> NormyBuilder<impl FusablePipeline> {
         .add_stage(StripMarkdown)
         .add_stage(StripFormatControls)
         .add_stage(StripControlChars)
-        .add_stage(UnifyWidth)
+        .add_stage(UnifyWidth::default())
         .add_stage(NORMALIZE_WHITESPACE_FULL)
         .add_stage(SegmentWords)
 }
@@ -151,7 +151,7 @@ fn social_media() -> NormyBuilder<impl FusablePipeline> {
         .add_stage(LowerCase)
         .add_stage(CaseFold)
         .add_stage(RemoveDiacritics)
-        .add_stage(UnifyWidth)
+        .add_stage(UnifyWidth::default())
         .add_stage(StripControlChars)
         .add_stage(StripFormatControls)
         .add_stage(NormalizePunctuation)
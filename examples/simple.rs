@@ -26,7 +26,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let deu = Normy::builder()
         .lang(DEU)
         .add_stage(CaseFold) // ß → ss
-        .add_stage(Transliterate) // Ä → ae, Ö → oe, Ü → ue
+        .add_stage(Transliterate::default()) // Ä → ae, Ö → oe, Ü → ue
         .build();
 
     println!(
@@ -55,7 +55,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     // ────────────────────────────────────────────────────────────────
     let zho = Normy::builder()
         .lang(ZHO)
-        .add_stage(UnifyWidth)
+        .add_stage(UnifyWidth::default())
         .add_stage(COLLAPSE_WHITESPACE_UNICODE)
         .add_stage(SegmentWords) // unigram segmentation
         .build();
@@ -72,7 +72,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let zho = Normy::builder()
         .lang(ZHO)
         .modify_lang(|le| le.set_unigram_cjk(true))
-        .add_stage(UnifyWidth)
+        .add_stage(UnifyWidth::default())
         .add_stage(COLLAPSE_WHITESPACE_UNICODE)
         .add_stage(SegmentWords) // unigram segmentation
         .build();
@@ -88,7 +88,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     // ────────────────────────────────────────────────────────────────
     let jpn = Normy::builder()
         .lang(JPN)
-        .add_stage(UnifyWidth)
+        .add_stage(UnifyWidth::default())
         .add_stage(COLLAPSE_WHITESPACE_UNICODE)
         .add_stage(SegmentWords) // script boundary segmentation
         .build();
@@ -0,0 +1,142 @@
+use crate::{
+    Normy, NormyError,
+    process::{DynamicProcess, FusablePipeline, Process},
+};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+
+/// Hit and miss counts of a [`CachedNormy`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+struct Entry {
+    output: String,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<Box<str>, Entry>,
+    tick: u64,
+    stats: CacheStats,
+}
+
+/// A [`Normy`] that remembers the output of its most recently used inputs.
+///
+/// Meant for hot paths with many duplicate inputs, such as tag or query
+/// normalization: a repeated input costs one hash lookup instead of a pipeline
+/// run. At most `capacity` inputs are kept; when full, the least recently used
+/// one is evicted. Eviction scans every entry, so keep the capacity small
+/// (hundreds to a few thousand). Errors are never cached.
+///
+/// The cache sits behind a mutex, so one `CachedNormy` can be shared across
+/// threads. Only available with the `cache` feature.
+pub struct CachedNormy<P: Process> {
+    normy: Normy<P>,
+    capacity: usize,
+    state: Mutex<CacheState>,
+}
+
+impl<P: Process> CachedNormy<P> {
+    /// Wrap `normy` with a cache of up to `capacity` inputs; `0` disables
+    /// caching.
+    pub fn new(normy: Normy<P>, capacity: usize) -> Self {
+        Self {
+            normy,
+            capacity,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// The wrapped pipeline.
+    pub fn inner(&self) -> &Normy<P> {
+        &self.normy
+    }
+
+    /// Hits and misses since creation or the last [`CachedNormy::clear`].
+    pub fn stats(&self) -> CacheStats {
+        self.lock().stats
+    }
+
+    /// Number of inputs currently cached.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop every cached entry and reset the stats.
+    pub fn clear(&self) {
+        *self.lock() = CacheState::default();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn cached<'a>(
+        &'a self,
+        text: &'a str,
+        normalize: impl FnOnce(&'a str) -> Result<Cow<'a, str>, NormyError>,
+    ) -> Result<String, NormyError> {
+        {
+            let mut state = self.lock();
+            state.tick += 1;
+            let tick = state.tick;
+            if let Some(entry) = state.entries.get_mut(text) {
+                entry.last_used = tick;
+                let output = entry.output.clone();
+                state.stats.hits += 1;
+                return Ok(output);
+            }
+            state.stats.misses += 1;
+        }
+        // The pipeline runs unlocked, so a slow input does not block hits
+        let output = normalize(text)?.into_owned();
+        if self.capacity == 0 {
+            return Ok(output);
+        }
+        let mut state = self.lock();
+        if state.entries.len() >= self.capacity
+            && !state.entries.contains_key(text)
+            && let Some(oldest) = state
+                .entries
+                .iter()
+                .min_by_key(|(_, e)| e.last_used)
+                .map(|(k, _)| k.clone())
+        {
+            state.entries.remove(&oldest);
+        }
+        let last_used = state.tick;
+        state.entries.insert(
+            text.into(),
+            Entry {
+                output: output.clone(),
+                last_used,
+            },
+        );
+        Ok(output)
+    }
+}
+
+impl<P: FusablePipeline> CachedNormy<P> {
+    /// [`Normy::normalize`], served from the cache when `text` was seen
+    /// recently.
+    pub fn normalize(&self, text: &str) -> Result<String, NormyError> {
+        self.cached(text, |text| self.normy.normalize(text))
+    }
+}
+
+impl CachedNormy<DynamicProcess> {
+    /// [`Normy::normalize`], served from the cache when `text` was seen
+    /// recently.
+    pub fn normalize(&self, text: &str) -> Result<String, NormyError> {
+        self.cached(text, |text| self.normy.normalize(text))
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{context::Context, stage::CharMapper};
+use smallvec::SmallVec;
+use std::borrow::Cow;
+
+/// Code points below this are served from the precomputed table.
+/// Covers ASCII, Latin-1, Latin Extended, Greek, Cyrillic, Hebrew and Arabic.
+const DENSE_LIMIT: u32 = 0x0800;
+
+/// A pipeline of pure 1:1 char stages collapsed into a single lookup.
+///
+/// Built by [`Normy::compile_char_table`](crate::Normy::compile_char_table).
+/// Chars below U+0800 are mapped through one precomputed table; rarer chars run
+/// through the composed stage mappings. Either way the text is walked once, with
+/// no per-stage `needs_apply` scans and no intermediate strings.
+pub struct CharTable<'a> {
+    dense: Box<[char]>,
+    mappers: SmallVec<[&'a dyn CharMapper; 4]>,
+    ctx: &'a Context,
+}
+
+impl<'a> CharTable<'a> {
+    pub(crate) fn new(mappers: SmallVec<[&'a dyn CharMapper; 4]>, ctx: &'a Context) -> Self {
+        let dense = (0..DENSE_LIMIT)
+            .map(|cp| {
+                let c = char::from_u32(cp).expect("no surrogates below U+0800");
+                mappers.iter().fold(c, |c, m| m.map_char(c, ctx))
+            })
+            .collect();
+        Self {
+            dense,
+            mappers,
+            ctx,
+        }
+    }
+
+    /// Map one char through every stage of the pipeline.
+    #[inline(always)]
+    pub fn map(&self, c: char) -> char {
+        match self.dense.get(c as usize) {
+            Some(&mapped) => mapped,
+            None => self.mappers.iter().fold(c, |c, m| m.map_char(c, self.ctx)),
+        }
+    }
+
+    /// Map every char of `text`. Zero-copy when no char changes.
+    pub fn apply<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        let Some(start) = text
+            .char_indices()
+            .find(|&(_, c)| self.map(c) != c)
+            .map(|(i, _)| i)
+        else {
+            return Cow::Borrowed(text);
+        };
+        let mut out = String::with_capacity(text.len());
+        out.push_str(&text[..start]);
+        out.extend(text[start..].chars().map(|c| self.map(c)));
+        Cow::Owned(out)
+    }
+}
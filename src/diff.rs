@@ -0,0 +1,205 @@
+use std::ops::Range;
+
+/// Upper bound on the LCS table (cells) before falling back to a single hunk.
+const MAX_LCS_CELLS: usize = 1 << 22;
+
+/// A changed region: bytes `before` in the old text became bytes `after` in the new one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Hunk {
+    pub before: Range<usize>,
+    pub after: Range<usize>,
+}
+
+/// Char-level diff between two strings, as a list of non-overlapping hunks.
+///
+/// Common prefix/suffix are trimmed first; the remaining window is aligned with
+/// an LCS table so several separate removals are reported as separate hunks.
+/// Oversized windows degrade to one hunk covering the whole window.
+pub(crate) fn hunks(before: &str, after: &str) -> Vec<Hunk> {
+    if before == after {
+        return Vec::new();
+    }
+
+    let prefix = before
+        .char_indices()
+        .zip(after.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or_else(|| before.len().min(after.len()), |((i, _), _)| i);
+    let suffix = before[prefix..]
+        .chars()
+        .rev()
+        .zip(after[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum::<usize>();
+
+    let old = &before[prefix..before.len() - suffix];
+    let new = &after[prefix..after.len() - suffix];
+
+    let a: Vec<(usize, char)> = old.char_indices().collect();
+    let b: Vec<(usize, char)> = new.char_indices().collect();
+    let (n, m) = (a.len(), b.len());
+
+    if n == 0 || m == 0 || (n + 1) * (m + 1) > MAX_LCS_CELLS {
+        return vec![Hunk {
+            before: prefix..prefix + old.len(),
+            after: prefix..prefix + new.len(),
+        }];
+    }
+
+    // lcs[i][j] = LCS length of a[i..] and b[j..]
+    let width = m + 1;
+    let mut lcs = vec![0u32; (n + 1) * width];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i * width + j] = if a[i].1 == b[j].1 {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let byte_a = |i: usize| prefix + a.get(i).map_or(old.len(), |&(o, _)| o);
+    let byte_b = |j: usize| prefix + b.get(j).map_or(new.len(), |&(o, _)| o);
+
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    let mut open: Option<(usize, usize)> = None;
+    while i < n || j < m {
+        if i < n && j < m && a[i].1 == b[j].1 {
+            if let Some((si, sj)) = open.take() {
+                out.push(Hunk {
+                    before: byte_a(si)..byte_a(i),
+                    after: byte_b(sj)..byte_b(j),
+                });
+            }
+            i += 1;
+            j += 1;
+            continue;
+        }
+        open.get_or_insert((i, j));
+        // Prefer deletions: stages mostly remove text
+        if i < n && (j == m || lcs[(i + 1) * width + j] >= lcs[i * width + j + 1]) {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    if let Some((si, sj)) = open {
+        out.push(Hunk {
+            before: byte_a(si)..byte_a(n),
+            after: byte_b(sj)..byte_b(m),
+        });
+    }
+    out
+}
+
+/// Carry per-char source offsets from `before` over to `after`.
+///
+/// `offsets[i]` is the source offset of the i-th char of `before`; the result
+/// holds one offset per char of `after`. Unchanged chars keep theirs. Inside a
+/// hunk, chars are paired one-to-one when both sides have the same char count.
+/// Otherwise `split` is asked what each old char became on its own (`None` =
+/// unchanged); if those pieces rebuild the hunk, each new char takes the offset
+/// of the old char that produced it. Failing that, every new char takes the
+/// offset of the hunk's first old char (`end` for insertions at the very end).
+pub(crate) fn carry_offsets(
+    before: &str,
+    offsets: &[usize],
+    after: &str,
+    end: usize,
+    split: &mut dyn FnMut(char) -> Option<String>,
+) -> Vec<usize> {
+    let mut out = Vec::with_capacity(after.len());
+    let (mut ci, mut byte) = (0, 0);
+    for h in hunks(before, after) {
+        let kept = before[byte..h.before.start].chars().count();
+        out.extend_from_slice(&offsets[ci..ci + kept]);
+        ci += kept;
+
+        let old = &before[h.before.clone()];
+        let new = &after[h.after.clone()];
+        let removed = old.chars().count();
+        let added = new.chars().count();
+        if removed == added {
+            out.extend_from_slice(&offsets[ci..ci + removed]);
+        } else if let Some(pieces) = split_hunk(old, new, split) {
+            for (i, n) in pieces.into_iter().enumerate() {
+                out.extend(std::iter::repeat_n(offsets[ci + i], n));
+            }
+        } else {
+            let anchor = offsets.get(ci).copied().unwrap_or(end);
+            out.extend(std::iter::repeat_n(anchor, added));
+        }
+        ci += removed;
+        byte = h.before.end;
+    }
+    out.extend_from_slice(&offsets[ci..]);
+    out
+}
+
+/// New-char counts per old char, when mapping `old` char by char yields `new`.
+fn split_hunk(
+    old: &str,
+    new: &str,
+    split: &mut dyn FnMut(char) -> Option<String>,
+) -> Option<Vec<usize>> {
+    let mut rest = new;
+    let mut counts = Vec::with_capacity(old.len());
+    for c in old.chars() {
+        let piece = split(c).unwrap_or_else(|| c.to_string());
+        rest = rest.strip_prefix(piece.as_str())?;
+        counts.push(piece.chars().count());
+    }
+    rest.is_empty().then_some(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separate_removals_become_separate_hunks() {
+        let before = "a\u{0007}bc\u{0000}d";
+        let h = hunks(before, "abcd");
+        assert_eq!(
+            h,
+            vec![
+                Hunk {
+                    before: 1..2,
+                    after: 1..1
+                },
+                Hunk {
+                    before: 4..5,
+                    after: 3..3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn replacements_cover_both_sides() {
+        let h = hunks("ｶﾀｶﾅ", "カタカナ");
+        assert_eq!(
+            h,
+            vec![Hunk {
+                before: 0..12,
+                after: 0..12
+            }]
+        );
+        assert!(hunks("same", "same").is_empty());
+    }
+
+    #[test]
+    fn offsets_follow_expansions_and_fall_back_to_anchor() {
+        let mut fold = |c: char| (c == 'ß').then(|| "ss".to_string());
+        let carried = carry_offsets("aßb", &[0, 1, 3], "assb", 4, &mut fold);
+        assert_eq!(carried, vec![0, 1, 1, 3]);
+
+        // Pieces that do not rebuild the hunk: everything points at its start
+        let mut none = |_: char| None;
+        let carried = carry_offsets("xab", &[0, 1, 2], "xc", 3, &mut none);
+        assert_eq!(carried, vec![0, 1]);
+    }
+}
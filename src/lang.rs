@@ -1,6 +1,14 @@
 pub mod data;
 
-use crate::{ENG, LANG_TABLE};
+use crate::{
+    ARA, BEN, COP, ELL, ENG, GOT, HEB, HIN, JPN, KHB, KHM, KOR, LANG_TABLE, LAO, MON, MYA, NOD,
+    RUS, TAM, THA, ZHO,
+    unicode::{
+        is_cjk_unified_ideograph, is_combining_mark, is_hangul, is_hiragana, is_kana_supplement,
+        is_katakana, is_nukta, is_virama,
+    },
+};
+use smallvec::SmallVec;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Lang {
@@ -17,6 +25,15 @@ impl Lang {
     pub const fn name(&self) -> &'static str {
         self.name
     }
+
+    /// Short, representative snippet of text in this language.
+    ///
+    /// Handy for demos, playgrounds and tests that want to show what a
+    /// language's normalization rules actually do.
+    #[inline(always)]
+    pub fn sample_text(&self) -> &'static str {
+        data::sample_text_of(self.code)
+    }
 }
 
 pub const DEFAULT_LANG: Lang = ENG;
@@ -45,6 +62,7 @@ pub struct LangEntry {
     // Already boolean from definition
     needs_segmentation: bool,
     unigram_cjk: bool,
+    joiners_in_words: bool,
 
     // === Data Arrays (Second Cache Line+) ===
     code: &'static str,
@@ -138,6 +156,24 @@ impl LangEntry {
         self.pre_composed_to_base_char_slice.contains(&c)
     }
 
+    /// Whether `c` belongs inside a word when tokenizing this language.
+    ///
+    /// Letters (including modifier letters such as the Hawaiian ʻokina),
+    /// digits, combining marks, viramas and nuktas are word chars everywhere.
+    /// ZWNJ and ZWJ are word chars only in languages whose data sets
+    /// `joiners_in_words` (Arabic-script and Indic), which write them inside
+    /// words; elsewhere they are stray format controls.
+    #[inline]
+    pub fn is_word_char(&self, c: char) -> bool {
+        if c.is_alphanumeric() {
+            return true;
+        }
+        match c {
+            '\u{200C}' | '\u{200D}' => self.joiners_in_words,
+            _ => is_combining_mark(c) || is_virama(c) || is_nukta(c),
+        }
+    }
+
     // ============================================================
     // CATEGORY 3: Text Analysis - Pattern: needs_*
     // ============================================================
@@ -460,6 +496,123 @@ pub fn get_lang_entry_by_code(code: &str) -> Option<&'static LangEntry> {
     LANG_TABLE.get(&code.to_ascii_uppercase())
 }
 
+/// `const` counterpart of [`from_code`](crate::from_code): case-insensitive
+/// lookup over [`all_langs`](crate::all_langs). Backs [`lang!`](crate::lang!).
+pub const fn from_code_const(code: &str) -> Option<Lang> {
+    let langs = crate::all_langs();
+    let code = code.as_bytes();
+    let mut i = 0;
+    while i < langs.len() {
+        let candidate = langs[i].code.as_bytes();
+        if candidate.len() == code.len() {
+            let mut j = 0;
+            while j < code.len() && candidate[j] == code[j].to_ascii_uppercase() {
+                j += 1;
+            }
+            if j == code.len() {
+                return Some(langs[i]);
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+/// The language a char's script points to, for [`detect_script_lang`]
+#[inline(always)]
+fn script_lang(c: char) -> Option<Lang> {
+    if is_hiragana(c) || is_katakana(c) || is_kana_supplement(c) {
+        return Some(JPN);
+    }
+    if is_cjk_unified_ideograph(c) {
+        return Some(ZHO);
+    }
+    if is_hangul(c) {
+        return Some(KOR);
+    }
+    Some(match c as u32 {
+        0x0370..=0x03FF | 0x1F00..=0x1FFF => ELL,
+        0x0400..=0x052F => RUS,
+        0x0590..=0x05FF | 0xFB1D..=0xFB4F => HEB,
+        0x0600..=0x06FF | 0x0750..=0x077F | 0x08A0..=0x08FF => ARA,
+        0xFB50..=0xFDFF | 0xFE70..=0xFEFE => ARA,
+        0x0900..=0x097F => HIN,
+        0x0980..=0x09FF => BEN,
+        0x0B80..=0x0BFF => TAM,
+        0x0E00..=0x0E7F => THA,
+        0x0E80..=0x0EFF => LAO,
+        0x1000..=0x109F => MYA,
+        0x1780..=0x17FF => KHM,
+        0x1980..=0x19DF => KHB,
+        0x1A20..=0x1AAF => NOD,
+        0x1800..=0x18AF => MON,
+        0x2C80..=0x2CFF => COP,
+        0x10330..=0x1034F => GOT,
+        _ => return None,
+    })
+}
+
+/// Guess a language from the dominant non-Latin script of `text`.
+///
+/// Each char votes for the language its script is most associated with, and
+/// the language with the most votes wins. This is a script heuristic, not
+/// language identification:
+///
+/// - Latin-script text (and text with no letters at all) returns `None` –
+///   English, Turkish and Vietnamese cannot be told apart by script.
+/// - Han ideographs count towards Japanese as soon as any kana is present,
+///   and towards Chinese otherwise. Kanji-only Japanese reads as `ZHO`.
+/// - Cyrillic always means `RUS` (not `SRP` or `MON`), Arabic script `ARA`,
+///   and Devanagari `HIN`.
+/// - Scripts without a supported language are ignored.
+pub fn detect_script_lang(text: &str) -> Option<Lang> {
+    let mut votes: SmallVec<[(Lang, usize); 4]> = SmallVec::new();
+    for lang in text
+        .chars()
+        .filter(|c| !c.is_ascii())
+        .filter_map(script_lang)
+    {
+        match votes.iter_mut().find(|(l, _)| *l == lang) {
+            Some((_, n)) => *n += 1,
+            None => votes.push((lang, 1)),
+        }
+    }
+    // Japanese writes its content words in kanji
+    if let Some(zho) = votes.iter().position(|(l, _)| *l == ZHO)
+        && let Some(jpn) = votes.iter().position(|(l, _)| *l == JPN)
+    {
+        votes[jpn].1 += votes[zho].1;
+        votes.remove(zho);
+    }
+    votes.iter().max_by_key(|(_, n)| *n).map(|(l, _)| *l)
+}
+
+/// Resolve a language code to its [`Lang`] at compile time.
+///
+/// Unknown codes are a compile error rather than a runtime `None`:
+///
+/// ```
+/// use normy::{TUR, lang};
+///
+/// const TURKISH: normy::lang::Lang = lang!("TUR");
+/// assert_eq!(TURKISH, TUR);
+/// assert_eq!(lang!("deu"), normy::DEU);
+/// ```
+///
+/// ```compile_fail
+/// let _ = normy::lang!("XXX"); // error: unknown language code `XXX`
+/// ```
+#[macro_export]
+macro_rules! lang {
+    ($code:literal) => {{
+        const LANG: $crate::lang::Lang = match $crate::lang::from_code_const($code) {
+            Some(lang) => lang,
+            None => panic!(concat!("unknown language code `", $code, "`")),
+        };
+        LANG
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{all_langs, lang::get_lang_entry_by_code};
@@ -522,6 +675,18 @@ mod tests {
         assert!(!ara.is_spacing_diacritic('ا'), "Base letter ا");
     }
 
+    #[test]
+    fn spacing_diacritics_accessor_never_needs_unwrapping() {
+        let harakat = lang("ARA").spacing_diacritics();
+        for c in ['\u{064E}', '\u{0650}', '\u{064F}', '\u{0652}', '\u{064B}'] {
+            assert!(harakat.contains(&c), "U+{:04X} missing", c as u32);
+        }
+        assert!(!harakat.contains(&'ا'));
+
+        assert!(lang("ENG").spacing_diacritics().is_empty());
+        assert!(!lang("ENG").has_spacing_diacritics());
+    }
+
     #[test]
     fn english_basic_case_folding() {
         let eng = lang("ENG");
@@ -541,7 +706,7 @@ mod tests {
     #[test]
     fn segmentation_languages() {
         // Languages requiring word segmentation
-        let needs_seg = ["JPN", "ZHO", "KOR", "THA", "MYA", "KHM"];
+        let needs_seg = ["JPN", "ZHO", "KOR", "THA", "MYA", "KHM", "NOD", "KHB"];
         for code in needs_seg {
             assert!(
                 lang(code).needs_segmentation(),
@@ -551,7 +716,7 @@ mod tests {
         }
 
         // Languages with space-delimited words
-        let no_seg = ["ENG", "TUR", "DEU", "FRA", "ARA"];
+        let no_seg = ["ENG", "TUR", "DEU", "FRA", "ARA", "MON", "COP", "GOT"];
         for code in no_seg {
             assert!(
                 !lang(code).needs_segmentation(),
@@ -610,6 +775,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn every_language_has_scripted_sample_text() {
+        use crate::unicode::{CharClass, classify};
+
+        for lang_info in all_langs() {
+            let sample = lang_info.sample_text();
+            assert!(
+                !sample.is_empty(),
+                "{}: empty sample text",
+                lang_info.code()
+            );
+
+            let expected = match lang_info.code() {
+                "JPN" | "ZHO" => CharClass::Cjk,
+                "KOR" => CharClass::Hangul,
+                "THA" | "LAO" | "MYA" | "KHM" | "NOD" | "KHB" => CharClass::SEAsian,
+                "HIN" | "BEN" | "TAM" => CharClass::Indic,
+                "ARA" | "HEB" | "ELL" | "RUS" | "SRP" | "MON" => CharClass::NonCJKScript,
+                "COP" | "GOT" => CharClass::Other, // Historical scripts
+                _ => CharClass::Western,
+            };
+            assert!(
+                sample.chars().any(|c| classify(c) == expected),
+                "{}: sample `{}` contains no {:?} characters",
+                lang_info.code(),
+                sample,
+                expected
+            );
+        }
+    }
+
     #[test]
     fn fold_targets_already_lowercase() {
         // Fold targets must be idempotent (already in lowercase form)
@@ -643,6 +839,28 @@ mod tests {
         assert_eq!(lang("DEU").apply_case_fold('A'), Some('a'));
     }
 
+    #[test]
+    fn word_chars_are_letters_digits_and_joiners() {
+        let eng = lang("ENG");
+        for c in ['a', 'Z', 'é', '7', 'ʻ', '\u{0301}'] {
+            assert!(eng.is_word_char(c), "{c:?}");
+        }
+        for c in [' ', '-', '.', '!', '\'', '\u{200C}', '\u{200D}'] {
+            assert!(!eng.is_word_char(c), "{c:?}");
+        }
+
+        // Hindi: ZWNJ/ZWJ control conjunct rendering inside words
+        let hin = lang("HIN");
+        assert!("क्\u{200D}ष".chars().all(|c| hin.is_word_char(c)));
+        assert!("क्\u{200C}ष".chars().all(|c| hin.is_word_char(c)));
+        assert!(hin.is_word_char('\u{093C}'), "nukta");
+        assert!(!hin.is_word_char('\u{0964}'), "danda is punctuation");
+        for code in ["ARA", "BEN", "TAM"] {
+            assert!(lang(code).is_word_char('\u{200C}'), "{code}");
+        }
+        assert!(!lang("JPN").is_word_char('\u{200D}'));
+    }
+
     #[test]
     fn apply_lowercase_always_succeeds() {
         // Lowercase is always 1→1, never fails
@@ -670,6 +888,57 @@ mod tests {
         assert!(!lang("DEU").has_one_to_one_folds());
     }
 
+    #[test]
+    fn detect_script_lang_follows_the_dominant_script() {
+        use crate::{ARA, JPN, KOR, RUS, ZHO, lang::detect_script_lang};
+
+        assert_eq!(detect_script_lang("你好世界"), Some(ZHO));
+        assert_eq!(detect_script_lang("東京に住んでいます"), Some(JPN));
+        assert_eq!(detect_script_lang("안녕하세요"), Some(KOR));
+        assert_eq!(detect_script_lang("Привет, world"), Some(RUS));
+        assert_eq!(detect_script_lang("مرحبا hi"), Some(ARA));
+        assert_eq!(detect_script_lang("Straße café"), None);
+        assert_eq!(detect_script_lang(""), None);
+    }
+
+    #[test]
+    fn coptic_lowercases_by_default_and_gothic_passes_through() {
+        use crate::{
+            COP, GOT, LowerCase, RemoveDiacritics, context::Context, lang::detect_script_lang,
+            stage::Stage,
+        };
+        use std::borrow::Cow;
+
+        let ctx = Context::new(COP);
+        let out = LowerCase.apply(Cow::Borrowed("ⲦⲘⲚⲦⲢⲘⲚⲔⲎⲘⲈ"), &ctx).unwrap();
+        assert_eq!(out, "ⲧⲙⲛⲧⲣⲙⲛⲕⲏⲙⲉ");
+        // Ni above over the nu is optional
+        let out = RemoveDiacritics
+            .apply(Cow::Borrowed("ⲛ\u{2CEF}ⲧⲉ"), &ctx)
+            .unwrap();
+        assert_eq!(out, "ⲛⲧⲉ");
+
+        let ctx = Context::new(GOT);
+        let gothic = GOT.sample_text();
+        assert!(!LowerCase.needs_apply(gothic, &ctx).unwrap());
+        assert!(!RemoveDiacritics.needs_apply(gothic, &ctx).unwrap());
+
+        assert_eq!(detect_script_lang("Ⲡⲓⲛⲟⲩⲧⲉ"), Some(COP));
+        assert_eq!(detect_script_lang(gothic), Some(GOT));
+    }
+
+    #[test]
+    fn lang_macro_resolves_codes_at_compile_time() {
+        const TURKISH: crate::lang::Lang = crate::lang!("TUR");
+        assert_eq!(TURKISH, crate::TUR);
+        assert_eq!(crate::lang!("nld"), crate::NLD);
+        for &lang in all_langs() {
+            assert_eq!(super::from_code_const(lang.code()), Some(lang));
+        }
+        assert_eq!(super::from_code_const("XXX"), None);
+        assert_eq!(super::from_code_const("TU"), None);
+    }
+
     #[test]
     fn get_lang_entry_by_code_case_insensitive() {
         assert!(get_lang_entry_by_code("TUR").is_some());
@@ -16,7 +16,9 @@ macro_rules! define_languages {
         spacing_diacritics: [ $($d:expr),* $(,)? ],
         needs_word_segmentation: $needs_word_segmentation:expr,
         segment_rules: [ $($sr:expr),* $(,)? ],
-        unigram_cjk: $unigram:expr
+        unigram_cjk: $unigram:expr,
+        joiners_in_words: $joiners:expr,
+        sample_text: $sample:literal
     ),* $(,)?) => {
         $(
             #[doc = concat!(
@@ -29,6 +31,8 @@ macro_rules! define_languages {
                 "- **Needs word segmentation:** ", stringify!($needs_word_segmentation), "\n",
                 "- **Segment rules:** [", stringify!($($sr),*), "]\n",
                 "- **CJK unigram tokens:** ", stringify!($unigram), "\n",
+                "- **ZWJ/ZWNJ inside words:** ", stringify!($joiners), "\n",
+                "- **Sample text:** ", $sample, "\n",
             )]
             pub const $code: Lang = Lang { code: $code_str, name: $name };
         )*
@@ -65,6 +69,7 @@ macro_rules! define_languages {
 
                     pub static SEGMENT_RULES: &[SegmentRule] = &[$($sr),*];
                     pub const UNIGRAM_CJK: bool = $unigram;
+                    pub const JOINERS_IN_WORDS: bool = $joiners;
 
                     // === Precomputed Boolean Flags ===
                     pub const HAS_CASE_MAP: bool = {
@@ -191,6 +196,7 @@ macro_rules! define_languages {
                         has_one_to_one_transliterate: [<$code:lower _data>]::HAS_ONE_TO_ONE_TRANSLITERATE,
                         needs_segmentation: [<$code:lower _data>]::NEEDS_WORD_SEGMENTATION,
                         unigram_cjk: [<$code:lower _data>]::UNIGRAM_CJK,
+                        joiners_in_words: [<$code:lower _data>]::JOINERS_IN_WORDS,
 
                         // === Data Arrays ===
                         code: [<$code:lower _data>]::CODE,
@@ -217,6 +223,16 @@ macro_rules! define_languages {
             }
         }
 
+        /// Representative sample text for a language code – backs `Lang::sample_text()`
+        pub(crate) fn sample_text_of(code: &str) -> &'static str {
+            match code {
+                $(
+                    $code_str => $sample,
+                )*
+                _ => "",
+            }
+        }
+
         /// All supported languages – for testing and introspection
         pub const fn all_langs() -> &'static [Lang] {
             &[
@@ -238,6 +254,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "İSTANBUL İĞNE İĞDE",
 
     DEU, "DEU", "German",
         case: [],
@@ -248,6 +266,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "GRÜNE STRAßE",
 
     NLD, "NLD", "Dutch",
         case: [],
@@ -258,6 +278,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "IJssEL Ĳssel",
 
     DAN, "DAN", "Danish",
         case: [],
@@ -268,6 +290,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "Åse Ææble Øre",
 
     NOR, "NOR", "Norwegian",
         case: [],
@@ -278,6 +302,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "Ærlig Øl Åtte",
 
     SWE, "SWE", "Swedish",
         case: [],
@@ -288,6 +314,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "Ålder Ääkta Öga",
 
     ISL, "ISL", "Icelandic",
         case: [],
@@ -298,6 +326,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "Þórður Ægir Þóra",
 
     // ⚡ OPTIMIZED: Frequency-ordered (Fatha most common)
     ARA, "ARA", "Arabic",
@@ -325,6 +355,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: true,
+        sample_text: "ٱلْكِتَابُ مُحَمَّدٌ ـــــ",
 
     // ⚡ OPTIMIZED: Frequency-ordered (Shva most common)
     HEB, "HEB", "Hebrew",
@@ -357,6 +389,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "ספר עִבְרִית",
 
     // ⚡ OPTIMIZED: Frequency-ordered by vowel family (a > o > e > u > i > y)
     // Lowercase first (95%+ of text), uppercase last
@@ -411,6 +445,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "Việt Nam Phở",
 
     FRA, "FRA", "French",
         case: [],
@@ -439,6 +475,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "SŒUR NAÏVE À L’ŒUF",
 
     CES, "CES", "Czech",
         case: [],
@@ -464,6 +502,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "Český řeřicha háček",
 
     SLK, "SLK", "Slovak",
         case: [],
@@ -486,6 +526,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "Ďakujem, Ľubica",
 
     // ⚡ OPTIMIZED: Frequency-ordered (ą/Ą most common)
     POL, "POL", "Polish",
@@ -507,6 +549,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "Łódź Żółć Kraków",
 
     // [('L', '·' => "l·l"),('l', '·' => "l·l")],
     // Catalan l·l → L·L is a case mapping rule, not a fold, The rule says: "Catalan preserves middle dot contextually" → this refers to case mapping, not search folding.
@@ -525,6 +569,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "Façade plaça Barça",
 
     SPA, "SPA", "Spanish",
         case: [],
@@ -535,6 +581,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "¡España mañana!",
 
     POR, "POR", "Portuguese",
         case: [],
@@ -545,6 +593,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "Ação e coração em São Paulo",
 
     ITA, "ITA", "Italian",
         case: [],
@@ -555,6 +605,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "Perché è così città",
 
     HRV, "HRV", "Croatian",
         case: [],
@@ -565,6 +617,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "Čakovec Đurđevac Šibenik",
 
     SRP, "SRP", "Serbian",
         case: [],
@@ -575,6 +629,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "Ђорђе Јовановић љубав",
 
     LIT, "LIT", "Lithuanian",
         case: [ 'Ė' => 'ė', 'Į' => 'į', 'Ų' => 'ų' ],
@@ -585,8 +641,11 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "IĮ Į Ĩ IĮ ĖĖ ŲŲ",
 
-    // No case_map needed: Unicode lowercase correctly handles final sigma (Σ → ς word-finally)
+    // No case_map: a per-char map cannot produce final sigma, since Σ → ς depends on the
+    // following char. `LowerCase` always gives σ; use `ContextualLowerCase` for word-final ς
     ELL, "ELL", "Greek",
         case: [],
         fold: [],
@@ -596,6 +655,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "ΑΡΧΙΜΗΔΗΣ ΕΛΛΑΣ",
 
     HIN, "HIN", "Hindi",
         case: [],
@@ -609,6 +670,8 @@ define_languages! {
             SegmentRule::ScriptToWestern,
         ],
         unigram_cjk: false,
+        joiners_in_words: true,
+        sample_text: "हिन्दी ज़िंदगी",
 
     BEN, "BEN", "Bengali",
         case: [],
@@ -622,6 +685,8 @@ define_languages! {
             SegmentRule::ScriptToWestern,
         ],
         unigram_cjk: false,
+        joiners_in_words: true,
+        sample_text: "বাংলা ভাষা",
 
     TAM, "TAM", "Tamil",
         case: [],
@@ -635,6 +700,8 @@ define_languages! {
             SegmentRule::ScriptToWestern,
         ],
         unigram_cjk: false,
+        joiners_in_words: true,
+        sample_text: "தமிழ் மொழி",
 
     // ⚡ OPTIMIZED: Frequency-ordered (о most common at 10.97%)
     // Lowercase first (90%+ of text), uppercase paired immediately after
@@ -681,6 +748,8 @@ define_languages! {
         needs_word_segmentation: false,
         segment_rules: [],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "ЁЛКИ-ПАЛКИ А́ННА",
 
     JPN, "JPN", "Japanese",
         case: [],
@@ -694,6 +763,8 @@ define_languages! {
             SegmentRule::ScriptToWestern,
         ],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "日本語テキストです ﾊﾟﾋﾟﾌﾟ",
 
     ZHO, "ZHO", "Chinese (Simplified)",
         case: [],
@@ -708,6 +779,8 @@ define_languages! {
             SegmentRule::ScriptToWestern,
         ],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "北京的秋天特别美丽，长城非常壮观！",
 
     KOR, "KOR", "Korean",
         case: [],
@@ -721,6 +794,8 @@ define_languages! {
             SegmentRule::ScriptToWestern,
         ],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "한글 ＫＯＲＥＡ",
 
     // ⚡ OPTIMIZED: Frequency-ordered (vowel signs first, tone marks last)
     THA, "THA", "Thai",
@@ -744,6 +819,8 @@ define_languages! {
             SegmentRule::ScriptToWestern,
         ],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "ภาษาไทย สวัสดี",
 
     LAO, "LAO", "Lao",
         case: [],
@@ -757,6 +834,8 @@ define_languages! {
             SegmentRule::ScriptToWestern,
         ],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "ພາສາລາວ ສະບາຍດີ",
 
     MYA, "MYA", "Myanmar",
         case: [],
@@ -770,6 +849,8 @@ define_languages! {
             SegmentRule::ScriptToWestern,
         ],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "မြန်မာဘာသာ",
 
     // ⚡ OPTIMIZED: Frequency-ordered (vowel signs most common)
     KHM, "KHM", "Khmer",
@@ -799,6 +880,80 @@ define_languages! {
             SegmentRule::ScriptToWestern,
         ],
         unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "ភាសាខ្មែរ",
+
+    // Tai Tham (Lanna) script; sakot (U+1A60) stacks consonants like Khmer coeng
+    NOD, "NOD", "Northern Thai",
+        case: [],
+        fold: [],
+        transliterate: [],
+        precomposed_to_base: [],
+        spacing_diacritics: [],
+        needs_word_segmentation: true,
+        segment_rules: [
+            SegmentRule::WesternToScript,
+            SegmentRule::ScriptToWestern,
+        ],
+        unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "ᨲᩫ᩠ᩅᨾᩮᩬᩥᨦ",
+
+    // New Tai Lue script: vowels and tones are spacing letters, no virama
+    KHB, "KHB", "Tai Lue",
+        case: [],
+        fold: [],
+        transliterate: [],
+        precomposed_to_base: [],
+        spacing_diacritics: [],
+        needs_word_segmentation: true,
+        segment_rules: [
+            SegmentRule::WesternToScript,
+            SegmentRule::ScriptToWestern,
+        ],
+        unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "ᦟᦲᧉᦺᦑᦟᦹᧉ",
+
+    // Traditional script is written vertically, but words are space-delimited
+    MON, "MON", "Mongolian",
+        case: [],
+        fold: [],
+        transliterate: [],
+        precomposed_to_base: [],
+        spacing_diacritics: [],
+        needs_word_segmentation: false,
+        segment_rules: [],
+        unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "ᠮᠣᠩᠭᠣᠯ ᠪᠢᠴᠢᠭ",
+
+    // Bicameral: casing comes from the Unicode defaults, as for Greek.
+    // Supralinear marks (ni above, spiritus asper/lenis) are optional
+    COP, "COP", "Coptic",
+        case: [],
+        fold: [],
+        transliterate: [],
+        precomposed_to_base: [],
+        spacing_diacritics: ['\u{2CEF}', '\u{2CF0}', '\u{2CF1}'],
+        needs_word_segmentation: false,
+        segment_rules: [],
+        unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "Ⲧⲙⲛⲧⲣⲙⲛⲕⲏⲙⲉ",
+
+    // Caseless, no diacritics: every stage passes Gothic through
+    GOT, "GOT", "Gothic",
+        case: [],
+        fold: [],
+        transliterate: [],
+        precomposed_to_base: [],
+        spacing_diacritics: [],
+        needs_word_segmentation: false,
+        segment_rules: [],
+        unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "𐌲𐌿𐍄𐌹𐍃𐌺 𐍂𐌰𐌶𐌳𐌰",
 
     ENG, "ENG", "English",
         case: [],
@@ -808,5 +963,7 @@ define_languages! {
         spacing_diacritics: [],
         needs_word_segmentation: false,
         segment_rules: [],
-        unigram_cjk: false
+        unigram_cjk: false,
+        joiners_in_words: false,
+        sample_text: "Hello naïve World!"
 }
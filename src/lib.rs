@@ -8,28 +8,91 @@ pub mod process;
 pub mod stage;
 pub mod testing;
 
+#[cfg(feature = "cache")]
+pub use cached_normy::{CacheStats, CachedNormy};
+pub use char_table::CharTable;
 pub use lang::data::*;
-pub use normy::{DynamicNormyBuilder, Normy, NormyBuilder, NormyError};
+pub use normy::{
+    DisabledView, DynamicNormyBuilder, Edit, ErrorPolicy, NormalizeStats, Normy, NormyBuilder,
+    NormyError, PipelineWarning, diff_across_langs,
+};
+pub use registry::ParseError;
+pub use small_string::SmallString;
+pub use unicode::CharClass;
 
+pub use stage::canonicalize_marks::CanonicalizeMarks;
 pub use stage::case_fold::CaseFold;
-pub use stage::lower_case::LowerCase;
+pub use stage::dedup_tone_marks::DedupToneMarks;
+pub use stage::expand_iteration_marks::ExpandIterationMarks;
+pub use stage::expand_ligatures::ExpandLigatures;
+pub use stage::lower_case::{ContextualLowerCase, LowerCase};
 pub use stage::normalization::{NFC, NFD, NFKC, NFKD};
+pub use stage::normalize_arabic_presentation_forms::NormalizeArabicPresentationForms;
+pub use stage::normalize_bullets::{
+    BulletShapes, NORMALIZE_BULLETS, NORMALIZE_GEOMETRIC_BULLETS, NormalizeBullets,
+};
+pub use stage::normalize_cjk_compatibility::NormalizeCjkCompatibility;
+pub use stage::normalize_cjk_punctuation::{
+    NORMALIZE_CJK_PUNCTUATION, NORMALIZE_CJK_PUNCTUATION_AND_QUOTES, NormalizeCjkPunctuation,
+};
+pub use stage::normalize_currency::{CURRENCY_TO_CODES, NORMALIZE_CURRENCY, NormalizeCurrency};
+pub use stage::normalize_enclosed_alphanumerics::NormalizeEnclosedAlphanumerics;
+pub use stage::normalize_enclosed_cjk::NormalizeEnclosedCjk;
+pub use stage::normalize_general_punctuation::NormalizeGeneralPunctuation;
+pub use stage::normalize_keycaps::NormalizeKeycaps;
+pub use stage::normalize_letterlike_symbols::{
+    FOLD_LETTERLIKE_LETTERS, NORMALIZE_LETTERLIKE_SYMBOLS, NormalizeLetterlikeSymbols,
+};
+pub use stage::normalize_modifier_letters::NormalizeModifierLetters;
+pub use stage::normalize_mongolian::NormalizeMongolian;
+pub use stage::normalize_number_forms::{
+    NORMALIZE_NUMBER_FORMS, NUMBER_FORMS_TO_VALUES, NormalizeNumberForms, NumberFormMode,
+};
+pub use stage::normalize_phonetic_extensions::NormalizePhoneticExtensions;
 pub use stage::normalize_punctuation::NormalizePunctuation;
+pub use stage::normalize_radicals::NormalizeRadicals;
+pub use stage::normalize_squared_cjk::NormalizeSquaredCjk;
+pub use stage::normalize_superscripts::NormalizeSuperscripts;
+pub use stage::normalize_technical_symbols::{
+    NAME_TECHNICAL_SYMBOLS, NormalizeTechnicalSymbols, STRIP_TECHNICAL_SYMBOLS, TechnicalSymbolMode,
+};
+pub use stage::normalize_thai_sara_am::{COMPOSE_SARA_AM, DECOMPOSE_SARA_AM, NormalizeThaiSaraAm};
+pub use stage::normalize_units::{JOIN_UNITS, NormalizeUnits, SPACE_UNITS};
 pub use stage::normalize_whitespace::{
     COLLAPSE_WHITESPACE, COLLAPSE_WHITESPACE_UNICODE, NORMALIZE_WHITESPACE_FULL, TRIM_WHITESPACE,
     TRIM_WHITESPACE_UNICODE,
 };
-pub use stage::remove_diacritics::RemoveDiacritics;
+pub use stage::remove_combining_marks::RemoveCombiningMarks;
+pub use stage::remove_diacritics::{RemoveDiacritics, UniversalRemoveDiacritics};
 pub use stage::segment_words::SegmentWords;
-pub use stage::strip_control_chars::StripControlChars;
+pub use stage::slugify::Slugify;
+pub use stage::strip_braille::StripBraille;
+pub use stage::strip_combining_half_marks::StripCombiningHalfMarks;
+pub use stage::strip_control_chars::{
+    ControlCharPolicy, NormalizeControlChars, REPLACE_CONTROL_CHARS, StripControlChars,
+};
 pub use stage::strip_format_controls::StripFormatControls;
 pub use stage::strip_html::StripHtml;
+pub use stage::strip_ideographic_description_chars::StripIdeographicDescriptionChars;
 pub use stage::strip_markdown::StripMarkdown;
-pub use stage::transliterate::Transliterate;
-pub use stage::unify_width::UnifyWidth;
+pub use stage::strip_modifier_tone_letters::{
+    KEEP_TONE_BARS, STRIP_MODIFIER_TONE_LETTERS, StripModifierToneLetters,
+};
+pub use stage::strip_ocr_symbols::StripOcrSymbols;
+pub use stage::strip_private_use::{REPLACE_PRIVATE_USE, STRIP_PRIVATE_USE, StripPrivateUse};
+pub use stage::transliterate::{
+    TRANSLITERATE, TRANSLITERATE_ESCAPED, Transliterate, TransliterateFallback,
+};
+pub use stage::unify_width::{UNIFY_WIDTH, UNIFY_WIDTH_KEEP_KANA, UnifyWidth};
 
 // Internal only
+#[cfg(feature = "cache")]
+mod cached_normy;
+mod char_table;
+mod diff;
 mod normy;
+mod registry;
+mod small_string;
 mod unicode;
 
 #[cfg(test)]
@@ -1,12 +1,18 @@
 use crate::{
+    char_table::CharTable,
     context::Context,
-    lang::{DEFAULT_LANG, Lang, LangEntry},
+    diff,
+    lang::{DEFAULT_LANG, Lang, LangEntry, detect_script_lang},
     process::{ChainedProcess, DynamicProcess, EmptyProcess, FusablePipeline, Process},
-    stage::{Stage, StageError, StaticFusableStage},
+    registry::{ParseError, stage_from_kv},
+    small_string::SmallString,
+    stage::{Stage, StageError, StaticFusableStage, transliterate::push_escaped},
+    unicode::{CharClass, classify},
 };
 use smallvec::SmallVec;
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, ops::Range, sync::Arc};
 use thiserror::Error;
+use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Debug, Error)]
 pub enum NormyError {
@@ -14,6 +20,92 @@ pub enum NormyError {
     Stage(#[from] StageError),
 }
 
+/// One transformation recorded by [`Normy::normalize_with_log`].
+///
+/// `source_range` is a byte range into the text *as the stage received it*
+/// (the output of the previous stage), and `replacement` is what the stage
+/// wrote there – empty for pure removals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    pub stage: &'static str,
+    pub source_range: Range<usize>,
+    pub replacement: String,
+}
+
+/// Size counts returned by [`Normy::normalize_stats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NormalizeStats {
+    pub bytes_in: usize,
+    pub bytes_out: usize,
+    pub chars_in: usize,
+    pub chars_out: usize,
+    /// Stages that changed the text
+    pub stages_fired: usize,
+}
+
+/// What a [`Normy`] does when a stage returns an error.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Propagate the error to the caller (default).
+    #[default]
+    Abort,
+    /// Skip the failing stage and carry on with the text it received.
+    SkipStage,
+    /// Give up on normalization and return the original input unchanged.
+    UseInput,
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// FNV-1a – unlike `DefaultHasher`, its output is fixed by definition
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Longer differing middle of `a` and `b` once common affixes are trimmed
+fn byte_distance(a: &[u8], b: &[u8]) -> usize {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    a.len().max(b.len()) - suffix
+}
+
+/// Run one stage on `text` – `None` when the stage does not apply.
+#[inline]
+fn run_stage(stage: &dyn Stage, text: &str, ctx: &Context) -> Result<Option<String>, StageError> {
+    if !stage.needs_apply(text, ctx)? {
+        return Ok(None);
+    }
+    Ok(Some(stage.apply(Cow::Borrowed(text), ctx)?.into_owned()))
+}
+
+/// The most frequent script class of `text`, whitespace excluded, `Other`
+/// only as a fallback
+fn dominant_class(text: &str) -> CharClass {
+    let mut votes: SmallVec<[(CharClass, usize); 4]> = SmallVec::new();
+    for class in text.chars().map(classify) {
+        if matches!(class, CharClass::Whitespace | CharClass::Other) {
+            continue;
+        }
+        match votes.iter_mut().find(|(c, _)| *c == class) {
+            Some((_, n)) => *n += 1,
+            None => votes.push((class, 1)),
+        }
+    }
+    votes
+        .iter()
+        .max_by_key(|(_, n)| *n)
+        .map_or(CharClass::Other, |(c, _)| *c)
+}
+
 /// # Safety: `text` **must** be valid UTF-8.
 #[cfg(debug_assertions)]
 #[inline(always)]
@@ -29,6 +121,7 @@ pub struct Normy<P: Process> {
     pipeline: P,
     all_fusable: bool,
     stage_count: usize,
+    error_policy: ErrorPolicy,
 }
 
 impl<P: Process> Normy<P> {
@@ -36,6 +129,459 @@ impl<P: Process> Normy<P> {
     pub fn uses_fusion(&self) -> bool {
         self.all_fusable && self.stage_count > 1
     }
+
+    /// Resolve a pipeline failure according to the configured [`ErrorPolicy`].
+    #[cold]
+    fn recover<'a>(
+        &self,
+        text: &'a str,
+        err: StageError,
+        skip: &[&str],
+        ctx: &Context,
+    ) -> Result<Cow<'a, str>, NormyError> {
+        match self.error_policy {
+            ErrorPolicy::Abort => Err(err.into()),
+            ErrorPolicy::UseInput => Ok(Cow::Borrowed(text)),
+            ErrorPolicy::SkipStage => {
+                // Replay stage by stage, dropping the ones that fail
+                let mut current = Cow::Borrowed(text);
+                self.pipeline.for_each_stage(&mut |stage| {
+                    if skip.contains(&stage.name()) {
+                        return Ok(());
+                    }
+                    if let Ok(Some(next)) = run_stage(stage, &current, ctx) {
+                        current = Cow::Owned(next);
+                    }
+                    Ok(())
+                })?;
+                Ok(current)
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn process_unfused<'a>(&self, text: &'a str) -> Result<Cow<'a, str>, NormyError> {
+        self.pipeline
+            .process(Cow::Borrowed(text), &self.ctx)
+            .or_else(|e| self.recover(text, e, &[], &self.ctx))
+    }
+
+    /// Normalize under the language implied by the text's dominant script.
+    ///
+    /// The language comes from [`detect_script_lang`]: Han and kana select
+    /// `ZHO`/`JPN`, Hangul `KOR`, Cyrillic `RUS`, and so on, so
+    /// language-parameterized stages such as [`SegmentWords`](crate::SegmentWords)
+    /// follow the input. When no script is recognized – Latin text included –
+    /// the pipeline's own language is used. A detected language runs with its
+    /// canonical rules: [`NormyBuilder::modify_lang`] overrides only apply to
+    /// the pipeline's own language. See [`detect_script_lang`] for the limits
+    /// of the heuristic.
+    pub fn auto<'a>(&self, text: &'a str) -> Result<Cow<'a, str>, NormyError> {
+        #[cfg(debug_assertions)]
+        assert_utf8(text);
+        let ctx = match detect_script_lang(text) {
+            Some(lang) if lang != self.ctx.lang => Context::new(lang),
+            _ => self.ctx,
+        };
+        self.pipeline
+            .process(Cow::Borrowed(text), &ctx)
+            .or_else(|e| self.recover(text, e, &[], &ctx))
+    }
+
+    /// A view of this pipeline that skips every stage whose `name()` is in
+    /// `names`, without rebuilding the pipeline type.
+    ///
+    /// Handy for A/B testing a stage in a statically built [`Normy`]. Unknown
+    /// names are ignored.
+    pub fn with_disabled<'n>(&'n self, names: &'n [&'n str]) -> DisabledView<'n, P> {
+        DisabledView {
+            normy: self,
+            disabled: names,
+        }
+    }
+
+    /// Normalize text and truncate the result to at most `max_bytes` bytes.
+    ///
+    /// The cut falls on an extended grapheme cluster boundary, so it never
+    /// splits a UTF-8 char, a base char from its marks or vowel signs, or an
+    /// emoji from its modifiers – a cluster that does not fit is dropped
+    /// whole. The returned
+    /// flag is `true` when anything was cut off. Borrowed output stays borrowed.
+    pub fn normalize_truncated<'a>(
+        &self,
+        text: &'a str,
+        max_bytes: usize,
+    ) -> Result<(Cow<'a, str>, bool), NormyError> {
+        #[cfg(debug_assertions)]
+        assert_utf8(text);
+        let normalized = self.process_unfused(text)?;
+        if normalized.len() <= max_bytes {
+            return Ok((normalized, false));
+        }
+
+        // End of the last whole extended grapheme cluster that fits
+        let cut = normalized
+            .grapheme_indices(true)
+            .map(|(i, g)| i + g.len())
+            .take_while(|&end| end <= max_bytes)
+            .last()
+            .unwrap_or(0);
+
+        let truncated = match normalized {
+            Cow::Borrowed(s) => Cow::Borrowed(&s[..cut]),
+            Cow::Owned(mut s) => {
+                s.truncate(cut);
+                Cow::Owned(s)
+            }
+        };
+        Ok((truncated, true))
+    }
+
+    /// Normalize text and count the extended grapheme clusters of the result.
+    ///
+    /// Useful for user-facing length limits, where a base char plus its
+    /// combining marks counts as one "character".
+    pub fn normalize_grapheme_count<'a>(
+        &self,
+        text: &'a str,
+    ) -> Result<(Cow<'a, str>, usize), NormyError> {
+        #[cfg(debug_assertions)]
+        assert_utf8(text);
+        let normalized = self.process_unfused(text)?;
+        let count = normalized.graphemes(true).count();
+        Ok((normalized, count))
+    }
+
+    /// Normalize text and measure how much the pipeline changed it.
+    ///
+    /// The distance is byte-level and linear-time: after trimming the longest
+    /// common prefix and suffix, it is the length in bytes of the longer of
+    /// the two differing middles. It is zero exactly when the output equals
+    /// the input, and an upper bound on the byte edit distance otherwise –
+    /// cheap enough to log for every input when tuning aggressive pipelines.
+    pub fn normalize_with_distance<'a>(
+        &self,
+        text: &'a str,
+    ) -> Result<(Cow<'a, str>, usize), NormyError> {
+        #[cfg(debug_assertions)]
+        assert_utf8(text);
+        let normalized = self.process_unfused(text)?;
+        let distance = match &normalized {
+            Cow::Borrowed(_) => 0,
+            Cow::Owned(out) => byte_distance(text.as_bytes(), out.as_bytes()),
+        };
+        Ok((normalized, distance))
+    }
+
+    /// Normalize each script run of `text` with the pipeline `router` picks
+    /// for its [`CharClass`], and join the results.
+    ///
+    /// Runs are maximal stretches of one script class; whitespace and symbols
+    /// ([`CharClass::Whitespace`], [`CharClass::Other`]) stay with the run
+    /// before them, or the first run after them at the start. So
+    /// `"Hello 世界"` sends `"Hello "` to the `Western` pipeline and `"世界"`
+    /// to the `Cjk` one. ASCII digits and punctuation classify as `Western`
+    /// and start a run of their own. Text without any script chars runs
+    /// through `self`.
+    pub fn normalize_segmented<'r, Q: Process + 'r>(
+        &self,
+        text: &str,
+        router: impl Fn(CharClass) -> &'r Normy<Q>,
+    ) -> Result<String, NormyError> {
+        #[cfg(debug_assertions)]
+        assert_utf8(text);
+        let mut out = String::with_capacity(text.len());
+        let mut start = 0;
+        let mut class = None;
+        for (i, c) in text.char_indices() {
+            let next = match classify(c) {
+                CharClass::Whitespace | CharClass::Other => continue,
+                next => next,
+            };
+            match class {
+                Some(current) if current != next => {
+                    out.push_str(&router(current).process_unfused(&text[start..i])?);
+                    start = i;
+                }
+                _ => {}
+            }
+            class = Some(next);
+        }
+        let tail = &text[start..];
+        match class {
+            Some(current) => out.push_str(&router(current).process_unfused(tail)?),
+            None => out.push_str(&self.process_unfused(tail)?),
+        }
+        Ok(out)
+    }
+
+    /// Decode bytes as UTF-8, replacing invalid sequences with U+FFFD, then
+    /// normalize.
+    ///
+    /// Saves byte-oriented callers (network data, files with stray invalid
+    /// bytes) a manual conversion. The result is always owned.
+    pub fn normalize_bytes_lossy(&self, input: &[u8]) -> Result<String, NormyError> {
+        let decoded = String::from_utf8_lossy(input);
+        Ok(self.process_unfused(&decoded)?.into_owned())
+    }
+
+    /// Normalize, then require the output to be pure ASCII, for legacy
+    /// systems that accept nothing else.
+    ///
+    /// Fails with [`StageError::NonAscii`] naming the first char the pipeline
+    /// left behind and its byte offset in the output. Pair it with a
+    /// transliterating pipeline; see [`Normy::normalize_ascii_escaped`] to
+    /// escape such chars instead.
+    pub fn normalize_ascii(&self, text: &str) -> Result<String, NormyError> {
+        let out = self.process_unfused(text)?;
+        if let Some((offset, first_char)) = out.char_indices().find(|(_, c)| !c.is_ascii()) {
+            return Err(StageError::NonAscii { first_char, offset }.into());
+        }
+        Ok(out.into_owned())
+    }
+
+    /// Normalize, then write every non-ASCII char left in the output as
+    /// `\u{XXXX}`, as [`TransliterateFallback::Escape`](crate::TransliterateFallback::Escape)
+    /// does. The result is always ASCII.
+    pub fn normalize_ascii_escaped(&self, text: &str) -> Result<String, NormyError> {
+        let out = self.process_unfused(text)?;
+        if out.is_ascii() {
+            return Ok(out.into_owned());
+        }
+        let mut escaped = String::with_capacity(out.len() + 8);
+        for c in out.chars() {
+            if c.is_ascii() {
+                escaped.push(c);
+            } else {
+                push_escaped(c, |e| escaped.push(e));
+            }
+        }
+        Ok(escaped)
+    }
+
+    /// Collapse the pipeline into a single [`CharTable`] when every stage is a
+    /// pure 1:1 char mapping under this pipeline's language.
+    ///
+    /// Returns `None` if any stage may insert, drop, or reorder chars (or is a
+    /// custom stage without [`Stage::as_char_mapper`]). The table gives the same
+    /// output as [`Normy::normalize`] in one pass over the text.
+    pub fn compile_char_table(&self) -> Option<CharTable<'_>> {
+        let mut mappers = SmallVec::new();
+        let mut all_pure = true;
+        self.pipeline
+            .for_each_stage(&mut |stage| {
+                match stage.as_char_mapper(&self.ctx) {
+                    Some(mapper) => mappers.push(mapper),
+                    None => all_pure = false,
+                }
+                Ok(())
+            })
+            .ok()?;
+        all_pure.then(|| CharTable::new(mappers, &self.ctx))
+    }
+
+    /// A hash of the language code and the ordered stage names and options.
+    ///
+    /// Store it next to normalized values: a different fingerprint means the
+    /// value was produced by a different pipeline and should be recomputed.
+    /// The hash is FNV-1a, so it is the same across runs, platforms and crate
+    /// versions as long as the pipeline is. Changes made through `modify_lang`
+    /// and the behavior of closure stages are not part of it.
+    pub fn pipeline_fingerprint(&self) -> u64 {
+        let mut hash = fnv1a(FNV_OFFSET_BASIS, self.ctx.lang.code().as_bytes());
+        let _ = self.pipeline.for_each_stage(&mut |stage| {
+            // Unit/record separators keep `a` + `bc` apart from `ab` + `c`
+            hash = fnv1a(hash, b"\x1E");
+            hash = fnv1a(hash, stage.name().as_bytes());
+            hash = fnv1a(hash, b"\x1F");
+            hash = fnv1a(hash, stage.options().as_bytes());
+            Ok(())
+        });
+        hash
+    }
+
+    /// The pipeline as a spec string that [`DynamicNormyBuilder::from_spec`]
+    /// parses back: the language code, then each stage's name and options,
+    /// separated by `|` – `TUR|normalize_whitespace:collapse=true,…|lowercase`.
+    ///
+    /// Store it with normalized values to rebuild the same pipeline later.
+    /// Like the fingerprint, it leaves out `modify_lang` changes; closure
+    /// stages appear by name but cannot be rebuilt.
+    pub fn to_spec(&self) -> String {
+        let mut spec = self.ctx.lang.code().to_owned();
+        let _ = self.pipeline.for_each_stage(&mut |stage| {
+            spec.push('|');
+            spec.push_str(stage.name());
+            let options = stage.options();
+            if !options.is_empty() {
+                spec.push(':');
+                spec.push_str(&options);
+            }
+            Ok(())
+        });
+        spec
+    }
+
+    /// Normalize text and record every change each stage made, for auditing.
+    ///
+    /// Always runs the non-fused path so each stage's input and output can be
+    /// compared. Edits are listed in stage order; within a stage they are in
+    /// source order and their ranges refer to that stage's input.
+    ///
+    /// Under [`ErrorPolicy::SkipStage`] a failing stage simply logs nothing;
+    /// under [`ErrorPolicy::UseInput`] the input is returned with an empty log.
+    pub fn normalize_with_log<'a>(
+        &self,
+        text: &'a str,
+    ) -> Result<(Cow<'a, str>, Vec<Edit>), NormyError> {
+        #[cfg(debug_assertions)]
+        assert_utf8(text);
+        let mut current = Cow::Borrowed(text);
+        let mut edits = Vec::new();
+        let outcome = self.pipeline.for_each_stage(&mut |stage| {
+            let next = match run_stage(stage, &current, &self.ctx) {
+                Ok(Some(next)) => next,
+                Ok(None) => return Ok(()),
+                Err(_) if self.error_policy == ErrorPolicy::SkipStage => return Ok(()),
+                Err(e) => return Err(e),
+            };
+            edits.extend(diff::hunks(&current, &next).into_iter().map(|h| Edit {
+                stage: stage.name(),
+                source_range: h.before,
+                replacement: next[h.after].to_string(),
+            }));
+            current = Cow::Owned(next);
+            Ok(())
+        });
+        match outcome {
+            Ok(()) => Ok((current, edits)),
+            Err(_) if self.error_policy == ErrorPolicy::UseInput => {
+                Ok((Cow::Borrowed(text), Vec::new()))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Normalize text and count what changed, for metrics.
+    ///
+    /// A lighter alternative to [`Normy::normalize_with_log`]: no edits are
+    /// recorded, only input and output sizes and the number of stages that
+    /// fired. Runs the non-fused path so each stage can be counted. Error
+    /// policies apply as in [`Normy::normalize_with_log`]; a skipped stage
+    /// does not count as fired.
+    pub fn normalize_stats<'a>(
+        &self,
+        text: &'a str,
+    ) -> Result<(Cow<'a, str>, NormalizeStats), NormyError> {
+        #[cfg(debug_assertions)]
+        assert_utf8(text);
+        let mut current = Cow::Borrowed(text);
+        let mut stages_fired = 0;
+        let outcome = self.pipeline.for_each_stage(&mut |stage| {
+            match run_stage(stage, &current, &self.ctx) {
+                Ok(Some(next)) => {
+                    if next != *current {
+                        stages_fired += 1;
+                    }
+                    current = Cow::Owned(next);
+                }
+                Ok(None) => {}
+                Err(_) if self.error_policy == ErrorPolicy::SkipStage => {}
+                Err(e) => return Err(e),
+            }
+            Ok(())
+        });
+        match outcome {
+            Ok(()) => {}
+            Err(_) if self.error_policy == ErrorPolicy::UseInput => {
+                current = Cow::Borrowed(text);
+                stages_fired = 0;
+            }
+            Err(e) => return Err(e.into()),
+        }
+        let stats = NormalizeStats {
+            bytes_in: text.len(),
+            bytes_out: current.len(),
+            chars_in: text.chars().count(),
+            chars_out: current.chars().count(),
+            stages_fired,
+        };
+        Ok((current, stats))
+    }
+
+    /// Normalize text and yield each output char with the byte offset of the
+    /// source char it came from, for streaming highlighters.
+    ///
+    /// Offsets are exact for char-level stages – 1:1 mappings (`LowerCase`),
+    /// removals (`StripControlChars`) and per-char expansions (`ß` → `ss`, both
+    /// `s` pointing at the `ß`). Stages that look across chars (`ﾊﾟ` → `パ`,
+    /// whitespace collapsing, HTML stripping) get a diff-based mapping where each
+    /// produced char points at the start of the source span it replaced.
+    ///
+    /// Not streaming: runs the non-fused path, keeps one offset per char of the
+    /// current text and returns the buffered result. Each stage that changes
+    /// the text is diffed against its input after trimming the common prefix
+    /// and suffix; the changed window costs O(n·m) time and memory in its char
+    /// counts. Windows over 4M cells (about 2048 × 2048 chars) skip the diff and
+    /// become one hunk: char-level stages still map exactly through the
+    /// per-char split, anything else points every produced char at the start of
+    /// the window. Error policies apply as in [`Normy::normalize_with_log`].
+    pub fn normalize_char_offsets<'a>(
+        &'a self,
+        text: &'a str,
+    ) -> Result<impl Iterator<Item = (char, usize)> + 'a, NormyError> {
+        #[cfg(debug_assertions)]
+        assert_utf8(text);
+        let mut current = Cow::Borrowed(text);
+        let mut offsets: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+        let outcome = self.pipeline.for_each_stage(&mut |stage| {
+            let next = match run_stage(stage, &current, &self.ctx) {
+                Ok(Some(next)) => next,
+                Ok(None) => return Ok(()),
+                Err(_) if self.error_policy == ErrorPolicy::SkipStage => return Ok(()),
+                Err(e) => return Err(e),
+            };
+            let mut split = |c: char| {
+                run_stage(stage, c.encode_utf8(&mut [0; 4]), &self.ctx)
+                    .ok()
+                    .flatten()
+            };
+            offsets = diff::carry_offsets(&current, &offsets, &next, text.len(), &mut split);
+            current = Cow::Owned(next);
+            Ok(())
+        });
+        if let Err(e) = outcome {
+            if self.error_policy != ErrorPolicy::UseInput {
+                return Err(e.into());
+            }
+            current = Cow::Borrowed(text);
+            offsets = text.char_indices().map(|(i, _)| i).collect();
+        }
+        let pairs: Vec<(char, usize)> = current.chars().zip(offsets).collect();
+        Ok(pairs.into_iter())
+    }
+
+    /// Run the stages one by one and return each stage's name with the text
+    /// it produced, for golden snapshots of every stage transition.
+    ///
+    /// Test helper: every stage gets an entry, holding its input unchanged
+    /// when it had nothing to do. Panics if a stage fails, whatever the
+    /// error policy.
+    #[cfg(test)]
+    pub fn snapshot(&self, input: &str) -> Vec<(&'static str, String)> {
+        let mut current = input.to_owned();
+        let mut snapshots = Vec::new();
+        self.pipeline
+            .for_each_stage(&mut |stage| {
+                if let Some(next) = run_stage(stage, &current, &self.ctx)? {
+                    current = next;
+                }
+                snapshots.push((stage.name(), current.clone()));
+                Ok(())
+            })
+            .unwrap_or_else(|e| panic!("snapshot of {input:?} failed: {e}"));
+        snapshots
+    }
 }
 
 impl<P: FusablePipeline> Normy<P> {
@@ -49,12 +595,76 @@ impl<P: FusablePipeline> Normy<P> {
             // Use fusion path
             self.pipeline
                 .process_fused(Cow::Borrowed(text), &self.ctx)
-                .map_err(Into::into)
+                .or_else(|e| self.recover(text, e, &[], &self.ctx))
         } else {
             // Use apply path (faster for single/zero stages)
-            self.pipeline
-                .process(Cow::Borrowed(text), &self.ctx)
-                .map_err(Into::into)
+            self.process_unfused(text)
+        }
+    }
+
+    /// Normalize into a [`SmallString`], which keeps outputs of up to
+    /// [`SmallString::INLINE_CAPACITY`] bytes off the heap.
+    ///
+    /// Meant for short inputs such as search queries and tags. When every
+    /// stage is fusable the fused iterator writes straight into the inline
+    /// buffer, so a short result costs no heap allocation at all; longer
+    /// results move to the heap once. Pipelines with non-fusable stages
+    /// allocate inside those stages as usual and only the final copy is
+    /// kept inline.
+    pub fn normalize_small(&self, text: &str) -> Result<SmallString, NormyError> {
+        #[cfg(debug_assertions)]
+        assert_utf8(text);
+        if !self.all_fusable {
+            return Ok(self.process_unfused(text)?.as_ref().into());
+        }
+        match self.pipeline.any_needs_apply(text, &self.ctx) {
+            Ok(false) => Ok(text.into()),
+            Ok(true) => {
+                let mut out = SmallString::new();
+                out.extend(self.pipeline.fused_iter(text.chars(), &self.ctx));
+                Ok(out)
+            }
+            Err(e) => Ok(self.recover(text, e, &[], &self.ctx)?.as_ref().into()),
+        }
+    }
+
+    /// Normalize text and report the dominant script class of the result,
+    /// for routing it to a language-specific downstream model.
+    ///
+    /// The [`CharClass`] with the most chars in the *normalized* text wins.
+    /// Whitespace does not vote, and `Other` (symbols, emoji) only wins when
+    /// nothing else is present – text with no chars at all reports `Other`.
+    /// ASCII digits and punctuation count as `Western`.
+    pub fn normalize_with_script<'a>(
+        &'a self,
+        text: &'a str,
+    ) -> Result<(Cow<'a, str>, CharClass), NormyError> {
+        let out = self.normalize(text)?;
+        let class = dominant_class(&out);
+        Ok((out, class))
+    }
+
+    /// `true` when normalizing `text` would return it unchanged: the text is
+    /// a fixed point of this pipeline.
+    ///
+    /// A stage can report work in `needs_apply` and still write the input
+    /// back, so this checks the output itself. When every stage is fusable
+    /// the fused output is compared with the input char by char, stopping at
+    /// the first difference, without allocating. Other pipelines compare the
+    /// unfused result.
+    pub fn is_normalized(&self, text: &str) -> Result<bool, NormyError> {
+        #[cfg(debug_assertions)]
+        assert_utf8(text);
+        if !self.all_fusable {
+            return Ok(*self.process_unfused(text)? == *text);
+        }
+        match self.pipeline.any_needs_apply(text, &self.ctx) {
+            Ok(false) => Ok(true),
+            Ok(true) => Ok(self
+                .pipeline
+                .fused_iter(text.chars(), &self.ctx)
+                .eq(text.chars())),
+            Err(e) => Ok(*self.recover(text, e, &[], &self.ctx)? == *text),
         }
     }
 
@@ -66,9 +676,7 @@ impl<P: FusablePipeline> Normy<P> {
     pub fn normalize_no_fusion<'a>(&'a self, text: &'a str) -> Result<Cow<'a, str>, NormyError> {
         #[cfg(debug_assertions)]
         assert_utf8(text);
-        self.pipeline
-            .process(Cow::Borrowed(text), &self.ctx)
-            .map_err(Into::into)
+        self.process_unfused(text)
     }
 }
 
@@ -79,20 +687,102 @@ impl Normy<DynamicProcess> {
     pub fn normalize<'a>(&self, text: &'a str) -> Result<Cow<'a, str>, NormyError> {
         #[cfg(debug_assertions)]
         assert_utf8(text);
-        self.pipeline
-            .process(Cow::Borrowed(text), &self.ctx)
-            .map_err(Into::into)
+        self.process_unfused(text)
+    }
+
+    /// Normalize text and report the dominant script class of the result –
+    /// see the fusable [`Normy::normalize_with_script`]
+    pub fn normalize_with_script<'a>(
+        &self,
+        text: &'a str,
+    ) -> Result<(Cow<'a, str>, CharClass), NormyError> {
+        let out = self.normalize(text)?;
+        let class = dominant_class(&out);
+        Ok((out, class))
+    }
+
+    /// Normalize into a [`SmallString`] – the stages allocate as usual and
+    /// only the final copy is kept inline
+    pub fn normalize_small(&self, text: &str) -> Result<SmallString, NormyError> {
+        #[cfg(debug_assertions)]
+        assert_utf8(text);
+        Ok(self.process_unfused(text)?.as_ref().into())
+    }
+
+    /// `true` when normalizing `text` would return it unchanged – compares
+    /// the unfused result with the input
+    pub fn is_normalized(&self, text: &str) -> Result<bool, NormyError> {
+        #[cfg(debug_assertions)]
+        assert_utf8(text);
+        Ok(*self.process_unfused(text)? == *text)
+    }
+}
+
+/// A [`Normy`] with some stages switched off – see [`Normy::with_disabled`].
+pub struct DisabledView<'n, P: Process> {
+    normy: &'n Normy<P>,
+    disabled: &'n [&'n str],
+}
+
+impl<'n, P: Process> DisabledView<'n, P> {
+    /// Normalize text with the disabled stages skipped.
+    ///
+    /// Always uses the apply path; the configured [`ErrorPolicy`] still applies.
+    pub fn normalize<'a>(&self, text: &'a str) -> Result<Cow<'a, str>, NormyError> {
+        #[cfg(debug_assertions)]
+        assert_utf8(text);
+        let normy = self.normy;
+        normy
+            .pipeline
+            .process_skipping(Cow::Borrowed(text), &normy.ctx, self.disabled)
+            .or_else(|e| normy.recover(text, e, self.disabled, &normy.ctx))
     }
 }
 
 // ============================================================================
 // Builder – tracks fusability while building
 // ============================================================================
+
+/// A stage order that likely gives wrong results, reported by
+/// [`NormyBuilder::warnings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum PipelineWarning {
+    /// A stage that only matches precomposed chars (`RemoveDiacritics`,
+    /// `Transliterate`) runs before any NFC or NFKC stage, so decomposed
+    /// input such as `e` + U+0301 slips past it.
+    #[error(
+        "stage `{stage}` only matches precomposed chars, but no `nfc` or `nfkc` runs before it"
+    )]
+    MissingNfc { stage: &'static str },
+}
+
+/// Warnings for stages named `names`, in execution order
+fn pipeline_warnings(names: impl IntoIterator<Item = &'static str>) -> Vec<PipelineWarning> {
+    let mut composed = false;
+    names
+        .into_iter()
+        .filter_map(|stage| {
+            match stage {
+                "nfc" | "nfkc" | "canonicalize_marks" => composed = true,
+                "nfd" | "nfkd" => composed = false,
+                "remove_diacritics" | "remove_diacritics_universal" | "transliterate"
+                    if !composed =>
+                {
+                    return Some(PipelineWarning::MissingNfc { stage });
+                }
+                _ => {}
+            }
+            None
+        })
+        .collect()
+}
+
 pub struct NormyBuilder<P: Process> {
     ctx: Context,
     current: P,
     all_fusable: bool,
     stage_count: usize,
+    error_policy: ErrorPolicy,
 }
 
 impl Default for NormyBuilder<EmptyProcess> {
@@ -103,21 +793,41 @@ impl Default for NormyBuilder<EmptyProcess> {
             current: EmptyProcess,
             all_fusable: true,
             stage_count: 0,
+            error_policy: ErrorPolicy::Abort,
         }
     }
 }
 
 impl<P: Process> NormyBuilder<P> {
+    /// Set the pipeline language. Without a call to `lang`, the builder uses
+    /// [`DEFAULT_LANG`] (English).
     #[inline(always)]
     pub fn lang(mut self, lang: Lang) -> Self {
         self.ctx = Context::new(lang);
         self
     }
+    /// Use [`DEFAULT_LANG`] (English) explicitly, discarding any earlier
+    /// `lang` or `modify_lang` call.
+    #[inline(always)]
+    pub fn lang_default(self) -> Self {
+        self.lang(DEFAULT_LANG)
+    }
+    /// The language configured so far.
+    #[inline(always)]
+    pub fn current_lang(&self) -> Lang {
+        self.ctx.lang
+    }
     #[inline(always)]
     pub fn modify_lang(mut self, f: impl FnOnce(&mut crate::lang::LangEntry)) -> Self {
         self.ctx = Context::with_modified(self.ctx.lang, f);
         self
     }
+    /// Choose how stage failures are handled (default: [`ErrorPolicy::Abort`]).
+    #[inline(always)]
+    pub fn on_stage_error(mut self, policy: ErrorPolicy) -> Self {
+        self.error_policy = policy;
+        self
+    }
     #[inline(always)]
     pub fn add_stage<S: Stage + StaticFusableStage + 'static>(
         mut self,
@@ -135,6 +845,25 @@ impl<P: Process> NormyBuilder<P> {
             },
             all_fusable: self.all_fusable,
             stage_count: self.stage_count,
+            error_policy: self.error_policy,
+        }
+    }
+    /// Problems with the stage order so far, such as a table-based stage
+    /// without NFC before it. Empty for a well-ordered pipeline.
+    pub fn warnings(&self) -> Vec<PipelineWarning> {
+        let mut names = Vec::with_capacity(self.stage_count);
+        let _ = self.current.for_each_stage(&mut |stage| {
+            names.push(stage.name());
+            Ok(())
+        });
+        pipeline_warnings(names)
+    }
+    /// [`build`](Self::build), failing with the first of
+    /// [`warnings`](Self::warnings) instead of ignoring it.
+    pub fn build_strict(self) -> Result<Normy<P>, PipelineWarning> {
+        match self.warnings().first() {
+            Some(&warning) => Err(warning),
+            None => Ok(self.build()),
         }
     }
     #[inline(always)]
@@ -144,6 +873,7 @@ impl<P: Process> NormyBuilder<P> {
             pipeline: self.current,
             all_fusable: self.all_fusable,
             stage_count: self.stage_count,
+            error_policy: self.error_policy,
         }
     }
 }
@@ -158,10 +888,37 @@ impl Normy<EmptyProcess> {
 // ============================================================================
 // Dynamic builder path
 // ============================================================================
+
+/// Closure-backed stage created by [`DynamicNormyBuilder::add_fn`]
+struct FnStage<F> {
+    name: &'static str,
+    f: F,
+}
+
+impl<F> Stage for FnStage<F>
+where
+    F: for<'a> Fn(Cow<'a, str>, &Context) -> Result<Cow<'a, str>, StageError> + Send + Sync,
+{
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, _text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        Ok(true) // Opaque closure – always run it
+    }
+
+    #[inline(always)]
+    fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        (self.f)(text, ctx)
+    }
+}
 pub struct DynamicNormyBuilder {
     ctx: Context,
     stages: SmallVec<[Arc<dyn Stage + Send + Sync>; 12]>,
     all_fusable: bool,
+    error_policy: ErrorPolicy,
+    max_stages: Option<usize>,
 }
 
 impl Default for DynamicNormyBuilder {
@@ -171,21 +928,42 @@ impl Default for DynamicNormyBuilder {
             ctx: Context::new(DEFAULT_LANG),
             stages: SmallVec::new(),
             all_fusable: true,
+            error_policy: ErrorPolicy::Abort,
+            max_stages: None,
         }
     }
 }
 
 impl DynamicNormyBuilder {
+    /// Set the pipeline language. Without a call to `lang`, the builder uses
+    /// [`DEFAULT_LANG`] (English).
     #[inline(always)]
     pub fn lang(mut self, lang: Lang) -> Self {
         self.ctx = Context::new(lang);
         self
     }
+    /// Use [`DEFAULT_LANG`] (English) explicitly, discarding any earlier
+    /// `lang` or `modify_lang` call.
+    #[inline(always)]
+    pub fn lang_default(self) -> Self {
+        self.lang(DEFAULT_LANG)
+    }
+    /// The language configured so far.
+    #[inline(always)]
+    pub fn current_lang(&self) -> Lang {
+        self.ctx.lang
+    }
     #[inline(always)]
     pub fn modify_lang(mut self, f: impl FnOnce(&mut LangEntry)) -> Self {
         self.ctx = Context::with_modified(self.ctx.lang, f);
         self
     }
+    /// Choose how stage failures are handled (default: [`ErrorPolicy::Abort`]).
+    #[inline(always)]
+    pub fn on_stage_error(mut self, policy: ErrorPolicy) -> Self {
+        self.error_policy = policy;
+        self
+    }
     #[inline(always)]
     pub fn add_stage<T: Stage + Send + Sync + 'static>(self, stage: T) -> Self {
         self.add_arc_stage(Arc::new(stage))
@@ -200,6 +978,113 @@ impl DynamicNormyBuilder {
         self.stages.push(stage.into());
         self
     }
+    /// Add a closure as an ad-hoc stage – handy for prototyping one-off transforms.
+    ///
+    /// The closure runs on every input (there is no `needs_apply` pre-check), so
+    /// return the input unchanged when there is nothing to do.
+    #[inline(always)]
+    pub fn add_fn<F>(self, name: &'static str, f: F) -> Self
+    where
+        F: for<'a> Fn(Cow<'a, str>, &Context) -> Result<Cow<'a, str>, StageError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_stage(FnStage { name, f })
+    }
+    /// Cap the number of stages [`add_kv`](Self::add_kv) and
+    /// [`add_spec`](Self::add_spec) may bring the pipeline to.
+    ///
+    /// Set it before parsing configs from untrusted sources: a longer list is
+    /// rejected with [`ParseError::TooManyStages`] before any stage is built.
+    /// Stages already added count towards the limit; stages added in code are
+    /// never rejected. [`from_kv`](Self::from_kv) and
+    /// [`from_spec`](Self::from_spec) are uncapped; use
+    /// [`from_kv_with_max_stages`](Self::from_kv_with_max_stages) and
+    /// [`from_spec_with_max_stages`](Self::from_spec_with_max_stages), or
+    /// `DynamicNormyBuilder::default().with_max_stages(n).add_spec(spec)`.
+    #[inline(always)]
+    pub fn with_max_stages(mut self, n: usize) -> Self {
+        self.max_stages = Some(n);
+        self
+    }
+    fn check_stage_count(&self, added: usize) -> Result<(), ParseError> {
+        let count = self.stages.len() + added;
+        match self.max_stages {
+            Some(limit) if count > limit => Err(ParseError::TooManyStages { limit, count }),
+            _ => Ok(()),
+        }
+    }
+    /// Build from an ordered list of `(stage name, options)` pairs, e.g. read
+    /// from environment variables or a config file.
+    ///
+    /// Names are the stages' [`Stage::name`]s. Options are comma-separated
+    /// `key=value` pairs naming the fields of the stage's config struct
+    /// (`"collapse=true,trim=false"`); fields not mentioned keep the stage's
+    /// `Default`. Chars are written literally or as `U+XXXX`.
+    pub fn from_kv(lang: Lang, pairs: &[(&str, &str)]) -> Result<Self, ParseError> {
+        Self::default().lang(lang).add_kv(pairs)
+    }
+    /// [`from_kv`](Self::from_kv), rejecting more than `max_stages` pairs
+    /// with [`ParseError::TooManyStages`].
+    pub fn from_kv_with_max_stages(
+        lang: Lang,
+        pairs: &[(&str, &str)],
+        max_stages: usize,
+    ) -> Result<Self, ParseError> {
+        Self::default()
+            .lang(lang)
+            .with_max_stages(max_stages)
+            .add_kv(pairs)
+    }
+    /// Append the stages of `(stage name, options)` pairs, in the
+    /// [`from_kv`](Self::from_kv) format.
+    pub fn add_kv(self, pairs: &[(&str, &str)]) -> Result<Self, ParseError> {
+        self.check_stage_count(pairs.len())?;
+        pairs.iter().try_fold(self, |builder, &(name, options)| {
+            Ok(builder.add_arc_stage(stage_from_kv(name, options)?))
+        })
+    }
+    /// Build from a spec string produced by [`Normy::to_spec`].
+    ///
+    /// The first `|`-separated field is the language code; each following one
+    /// is a stage name, optionally followed by `:` and its options in the
+    /// [`from_kv`](Self::from_kv) format.
+    pub fn from_spec(spec: &str) -> Result<Self, ParseError> {
+        Self::default().add_spec(spec)
+    }
+    /// [`from_spec`](Self::from_spec), rejecting more than `max_stages`
+    /// stages with [`ParseError::TooManyStages`].
+    pub fn from_spec_with_max_stages(spec: &str, max_stages: usize) -> Result<Self, ParseError> {
+        Self::default().with_max_stages(max_stages).add_spec(spec)
+    }
+    /// Set the language and append the stages of a spec string, in the
+    /// [`from_spec`](Self::from_spec) format.
+    pub fn add_spec(self, spec: &str) -> Result<Self, ParseError> {
+        let mut fields = spec.split('|').map(str::trim);
+        let code = fields.next().unwrap_or_default();
+        let lang =
+            crate::from_code(code).ok_or_else(|| ParseError::UnknownLang(code.to_owned()))?;
+        let mut stages = fields.filter(|field| !field.is_empty());
+        self.check_stage_count(stages.clone().count())?;
+        stages.try_fold(self.lang(lang), |builder, field| {
+            let (name, options) = field.split_once(':').unwrap_or((field, ""));
+            Ok(builder.add_arc_stage(stage_from_kv(name.trim(), options)?))
+        })
+    }
+    /// Problems with the stage order so far, such as a table-based stage
+    /// without NFC before it. Empty for a well-ordered pipeline.
+    pub fn warnings(&self) -> Vec<PipelineWarning> {
+        pipeline_warnings(self.stages.iter().map(|stage| stage.name()))
+    }
+    /// [`build`](Self::build), failing with the first of
+    /// [`warnings`](Self::warnings) instead of ignoring it.
+    pub fn build_strict(self) -> Result<Normy<DynamicProcess>, PipelineWarning> {
+        match self.warnings().first() {
+            Some(&warning) => Err(warning),
+            None => Ok(self.build()),
+        }
+    }
     #[inline(always)]
     pub fn build(self) -> Normy<DynamicProcess> {
         let stage_len = self.stages.len();
@@ -210,6 +1095,7 @@ impl DynamicNormyBuilder {
             },
             all_fusable: self.all_fusable,
             stage_count: stage_len,
+            error_policy: self.error_policy,
         }
     }
 }
@@ -220,3 +1106,39 @@ impl Normy<DynamicProcess> {
         DynamicNormyBuilder::default()
     }
 }
+
+/// Run the same stages under every language and report where the output
+/// departs from the [`DEFAULT_LANG`] one.
+///
+/// `stages_spec` is the stage part of a [`Normy::to_spec`] string, without
+/// the language code: `"nfc|lowercase"`. The first entry is the default
+/// language with its output; it is followed by each language whose output
+/// differs, in [`all_langs`](crate::all_langs) order. For `"INTEL"` and
+/// `lowercase`, `TUR` shows up with `ıntel`. A language whose pipeline fails
+/// on `input` is left out.
+pub fn diff_across_langs(
+    input: &str,
+    stages_spec: &str,
+) -> Result<Vec<(Lang, String)>, ParseError> {
+    let run = |lang: Lang| -> Result<Option<String>, ParseError> {
+        let normy =
+            DynamicNormyBuilder::from_spec(&format!("{}|{stages_spec}", lang.code()))?.build();
+        Ok(normy.normalize(input).ok().map(Cow::into_owned))
+    };
+    let baseline = run(DEFAULT_LANG)?;
+    let mut outputs = Vec::new();
+    if let Some(out) = &baseline {
+        outputs.push((DEFAULT_LANG, out.clone()));
+    }
+    for &lang in crate::all_langs() {
+        if lang == DEFAULT_LANG {
+            continue;
+        }
+        if let Some(out) = run(lang)?
+            && baseline.as_ref() != Some(&out)
+        {
+            outputs.push((lang, out));
+        }
+    }
+    Ok(outputs)
+}
@@ -7,6 +7,31 @@ use std::{borrow::Cow, iter::FusedIterator, sync::Arc};
 
 pub trait Process {
     fn process<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError>;
+
+    /// Like `process`, but stages whose `name()` is in `skip` are bypassed.
+    ///
+    /// The default runs the stages visited by `for_each_stage` one by one.
+    fn process_skipping<'a>(
+        &self,
+        text: Cow<'a, str>,
+        ctx: &Context,
+        skip: &[&str],
+    ) -> Result<Cow<'a, str>, StageError> {
+        let mut current = text;
+        self.for_each_stage(&mut |stage| {
+            if !skip.contains(&stage.name()) && stage.needs_apply(&current, ctx)? {
+                current = stage.apply(std::mem::take(&mut current), ctx)?;
+            }
+            Ok(())
+        })?;
+        Ok(current)
+    }
+
+    /// Visit every stage in execution order, stopping at the first error.
+    fn for_each_stage<'s>(
+        &'s self,
+        f: &mut dyn FnMut(&'s dyn Stage) -> Result<(), StageError>,
+    ) -> Result<(), StageError>;
 }
 
 pub trait FusablePipeline: Process {
@@ -45,6 +70,14 @@ impl Process for EmptyProcess {
     fn process<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
         Ok(text)
     }
+
+    #[inline(always)]
+    fn for_each_stage<'s>(
+        &'s self,
+        _f: &mut dyn FnMut(&'s dyn Stage) -> Result<(), StageError>,
+    ) -> Result<(), StageError> {
+        Ok(())
+    }
 }
 
 impl FusablePipeline for EmptyProcess {
@@ -81,6 +114,27 @@ impl<S: Stage, P: Process> Process for ChainedProcess<S, P> {
         }
         self.stage.apply(current, ctx)
     }
+
+    fn process_skipping<'a>(
+        &self,
+        text: Cow<'a, str>,
+        ctx: &Context,
+        skip: &[&str],
+    ) -> Result<Cow<'a, str>, StageError> {
+        let current = self.previous.process_skipping(text, ctx, skip)?;
+        if skip.contains(&self.stage.name()) || !self.stage.needs_apply(&current, ctx)? {
+            return Ok(current);
+        }
+        self.stage.apply(current, ctx)
+    }
+
+    fn for_each_stage<'s>(
+        &'s self,
+        f: &mut dyn FnMut(&'s dyn Stage) -> Result<(), StageError>,
+    ) -> Result<(), StageError> {
+        self.previous.for_each_stage(f)?;
+        f(&self.stage)
+    }
 }
 
 // Fused implementation: ONLY exists if S is StaticFusable and P is BuildIter
@@ -152,4 +206,25 @@ impl Process for DynamicProcess {
         }
         Ok(text)
     }
+
+    fn process_skipping<'a>(
+        &self,
+        mut text: Cow<'a, str>,
+        ctx: &Context,
+        skip: &[&str],
+    ) -> Result<Cow<'a, str>, StageError> {
+        for stage in &self.stages {
+            if !skip.contains(&stage.name()) && stage.needs_apply(&text, ctx)? {
+                text = stage.apply(text, ctx)?;
+            }
+        }
+        Ok(text)
+    }
+
+    fn for_each_stage<'s>(
+        &'s self,
+        f: &mut dyn FnMut(&'s dyn Stage) -> Result<(), StageError>,
+    ) -> Result<(), StageError> {
+        self.stages.iter().try_for_each(|stage| f(&**stage))
+    }
 }
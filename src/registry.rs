@@ -0,0 +1,491 @@
+use crate::{
+    BulletShapes, CanonicalizeMarks, CaseFold, ContextualLowerCase, ControlCharPolicy,
+    DedupToneMarks, ExpandIterationMarks, ExpandLigatures, LowerCase,
+    NormalizeArabicPresentationForms, NormalizeBullets, NormalizeCjkCompatibility,
+    NormalizeCjkPunctuation, NormalizeControlChars, NormalizeCurrency,
+    NormalizeEnclosedAlphanumerics, NormalizeEnclosedCjk, NormalizeGeneralPunctuation,
+    NormalizeKeycaps, NormalizeLetterlikeSymbols, NormalizeModifierLetters, NormalizeMongolian,
+    NormalizeNumberForms, NormalizePhoneticExtensions, NormalizePunctuation, NormalizeRadicals,
+    NormalizeSquaredCjk, NormalizeSuperscripts, NormalizeTechnicalSymbols, NormalizeThaiSaraAm,
+    NormalizeUnits, NumberFormMode, REPLACE_CONTROL_CHARS, RemoveCombiningMarks, RemoveDiacritics,
+    SegmentWords, Slugify, StripBraille, StripCombiningHalfMarks, StripControlChars,
+    StripFormatControls, StripHtml, StripIdeographicDescriptionChars, StripMarkdown,
+    StripModifierToneLetters, StripOcrSymbols, StripPrivateUse, TechnicalSymbolMode, Transliterate,
+    TransliterateFallback, UnifyWidth,
+    stage::{
+        Stage,
+        normalization::{NfcStage, NfdStage, NfkcStage, NfkdStage},
+        normalize_whitespace::NormalizeWhitespace,
+    },
+};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Why a textual pipeline description could not be turned into stages.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("unknown language `{0}`")]
+    UnknownLang(String),
+    #[error("pipeline has {count} stages, more than the limit of {limit}")]
+    TooManyStages { limit: usize, count: usize },
+    #[error("unknown stage `{0}`")]
+    UnknownStage(String),
+    #[error("stage `{stage}` has no option `{option}`")]
+    UnknownOption { stage: String, option: String },
+    #[error("invalid value `{value}` for option `{option}` of stage `{stage}`")]
+    InvalidValue {
+        stage: String,
+        option: String,
+        value: String,
+    },
+}
+
+fn unknown(stage: &str, option: &str) -> ParseError {
+    ParseError::UnknownOption {
+        stage: stage.to_owned(),
+        option: option.to_owned(),
+    }
+}
+
+fn invalid(stage: &str, option: &str, value: &str) -> ParseError {
+    ParseError::InvalidValue {
+        stage: stage.to_owned(),
+        option: option.to_owned(),
+        value: value.to_owned(),
+    }
+}
+
+fn parse_bool(stage: &str, option: &str, value: &str) -> Result<bool, ParseError> {
+    value.parse().map_err(|_| invalid(stage, option, value))
+}
+
+/// A single char, or `U+XXXX` for chars that are awkward to write inline
+fn parse_char(stage: &str, option: &str, value: &str) -> Result<char, ParseError> {
+    let parsed = match value.strip_prefix("U+") {
+        Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+        None => {
+            let mut chars = value.chars();
+            chars.next().filter(|_| chars.next().is_none())
+        }
+    };
+    parsed.ok_or_else(|| invalid(stage, option, value))
+}
+
+/// The `key=value` pairs of a comma-separated option string, trimmed
+fn option_pairs<'s>(
+    stage: &'s str,
+    options: &'s str,
+) -> impl Iterator<Item = Result<(&'s str, &'s str), ParseError>> + 's {
+    options
+        .split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(move |pair| match pair.split_once('=') {
+            Some((key, value)) => Ok((key.trim(), value.trim())),
+            None => Err(invalid(stage, pair, "")),
+        })
+}
+
+type Options<'s> = dyn Iterator<Item = Result<(&'s str, &'s str), ParseError>> + 's;
+type Built = Result<Arc<dyn Stage + Send + Sync>, ParseError>;
+type Build = fn(&str, &mut Options<'_>) -> Built;
+
+/// Every stage the textual builders know, keyed by its [`Stage::name`].
+///
+/// Each entry builds the stage from its `Default` and overrides one field per
+/// `key=value` option; stages without configuration accept no options.
+const STAGES: &[(&str, Build)] = &[
+    ("canonicalize_marks", unit::<CanonicalizeMarks>),
+    ("case_fold", unit::<CaseFold>),
+    ("contextual_lowercase", unit::<ContextualLowerCase>),
+    ("dedup_tone_marks", unit::<DedupToneMarks>),
+    ("expand_iteration_marks", unit::<ExpandIterationMarks>),
+    ("expand_ligatures", unit::<ExpandLigatures>),
+    ("lowercase", unit::<LowerCase>),
+    ("nfc", unit::<NfcStage>),
+    ("nfd", unit::<NfdStage>),
+    ("nfkc", unit::<NfkcStage>),
+    ("nfkd", unit::<NfkdStage>),
+    (
+        "normalize_arabic_presentation_forms",
+        unit::<NormalizeArabicPresentationForms>,
+    ),
+    ("normalize_bullets", normalize_bullets),
+    (
+        "normalize_cjk_compatibility",
+        unit::<NormalizeCjkCompatibility>,
+    ),
+    ("normalize_cjk_punctuation", normalize_cjk_punctuation),
+    ("normalize_control_chars", normalize_control_chars),
+    ("normalize_currency", normalize_currency),
+    (
+        "normalize_enclosed_alphanumerics",
+        unit::<NormalizeEnclosedAlphanumerics>,
+    ),
+    ("normalize_enclosed_cjk", unit::<NormalizeEnclosedCjk>),
+    (
+        "normalize_general_punctuation",
+        unit::<NormalizeGeneralPunctuation>,
+    ),
+    ("normalize_keycaps", unit::<NormalizeKeycaps>),
+    ("normalize_letterlike_symbols", normalize_letterlike_symbols),
+    (
+        "normalize_modifier_letters",
+        unit::<NormalizeModifierLetters>,
+    ),
+    ("normalize_mongolian", unit::<NormalizeMongolian>),
+    ("normalize_number_forms", normalize_number_forms),
+    (
+        "normalize_phonetic_extensions",
+        unit::<NormalizePhoneticExtensions>,
+    ),
+    ("normalize_punctuation", unit::<NormalizePunctuation>),
+    ("normalize_radicals", unit::<NormalizeRadicals>),
+    ("normalize_squared_cjk", unit::<NormalizeSquaredCjk>),
+    ("normalize_superscripts", unit::<NormalizeSuperscripts>),
+    ("normalize_technical_symbols", normalize_technical_symbols),
+    ("normalize_thai_sara_am", normalize_thai_sara_am),
+    ("normalize_units", normalize_units),
+    ("normalize_whitespace", normalize_whitespace),
+    ("remove_combining_marks", unit::<RemoveCombiningMarks>),
+    ("remove_control_chars", unit::<StripControlChars>),
+    ("remove_diacritics", unit::<RemoveDiacritics>),
+    ("remove_diacritics_universal", remove_diacritics_universal),
+    ("remove_format_controls", unit::<StripFormatControls>),
+    ("segment_words", unit::<SegmentWords>),
+    ("slugify", unit::<Slugify>),
+    ("strip_braille", unit::<StripBraille>),
+    (
+        "strip_combining_half_marks",
+        unit::<StripCombiningHalfMarks>,
+    ),
+    ("strip_html", unit::<StripHtml>),
+    (
+        "strip_ideographic_description_chars",
+        unit::<StripIdeographicDescriptionChars>,
+    ),
+    ("strip_markdown", unit::<StripMarkdown>),
+    ("strip_modifier_tone_letters", strip_modifier_tone_letters),
+    ("strip_ocr_symbols", unit::<StripOcrSymbols>),
+    ("strip_private_use", strip_private_use),
+    ("transliterate", transliterate),
+    ("unify_width", unify_width),
+];
+
+/// Accepted spellings that are not a [`Stage::name`]
+const ALIASES: &[(&str, &str)] = &[("lower_case", "lowercase")];
+
+/// Build the stage registered under `name` (its [`Stage::name`] or an alias),
+/// configured from a comma-separated `key=value` option string.
+pub(crate) fn stage_from_kv(name: &str, options: &str) -> Built {
+    let name = ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map_or(name, |&(_, target)| target);
+    let (name, build) = STAGES
+        .iter()
+        .find(|(registered, _)| *registered == name)
+        .ok_or_else(|| ParseError::UnknownStage(name.to_owned()))?;
+    build(name, &mut option_pairs(name, options))
+}
+
+/// Stages without configuration accept no options
+fn no_options(name: &str, opts: &mut Options<'_>) -> Result<(), ParseError> {
+    match opts.next() {
+        Some(pair) => Err(unknown(name, pair?.0)),
+        None => Ok(()),
+    }
+}
+
+fn unit<S: Stage + Default + Send + Sync + 'static>(name: &str, opts: &mut Options<'_>) -> Built {
+    no_options(name, opts)?;
+    Ok(Arc::new(S::default()))
+}
+
+fn remove_diacritics_universal(name: &str, opts: &mut Options<'_>) -> Built {
+    no_options(name, opts)?;
+    Ok(Arc::new(RemoveDiacritics::universal()))
+}
+
+fn normalize_whitespace(name: &str, opts: &mut Options<'_>) -> Built {
+    let mut stage = NormalizeWhitespace::default();
+    for pair in opts {
+        let (key, value) = pair?;
+        match key {
+            "collapse" => stage.collapse = parse_bool(name, key, value)?,
+            "trim" => stage.trim = parse_bool(name, key, value)?,
+            "normalize_unicode" => stage.normalize_unicode = parse_bool(name, key, value)?,
+            "replacement_char" => stage.replacement_char = parse_char(name, key, value)?,
+            _ => return Err(unknown(name, key)),
+        }
+    }
+    Ok(Arc::new(stage))
+}
+
+fn normalize_control_chars(name: &str, opts: &mut Options<'_>) -> Built {
+    let mut stage = NormalizeControlChars::default();
+    for pair in opts {
+        let (key, value) = pair?;
+        stage.policy = match (key, value) {
+            ("policy", "remove") => ControlCharPolicy::Remove,
+            ("policy", "replace") => REPLACE_CONTROL_CHARS.policy,
+            ("policy", _) => match value.strip_prefix("replace:") {
+                Some(c) => ControlCharPolicy::Replace(parse_char(name, key, c)?),
+                None => return Err(invalid(name, key, value)),
+            },
+            _ => return Err(unknown(name, key)),
+        };
+    }
+    Ok(Arc::new(stage))
+}
+
+fn strip_private_use(name: &str, opts: &mut Options<'_>) -> Built {
+    let mut stage = StripPrivateUse::default();
+    for pair in opts {
+        let (key, value) = pair?;
+        match key {
+            "replacement" => stage.replacement = Some(parse_char(name, key, value)?),
+            _ => return Err(unknown(name, key)),
+        }
+    }
+    Ok(Arc::new(stage))
+}
+
+fn normalize_cjk_punctuation(name: &str, opts: &mut Options<'_>) -> Built {
+    let mut stage = NormalizeCjkPunctuation::default();
+    for pair in opts {
+        let (key, value) = pair?;
+        match key {
+            "fold_quotes" => stage.fold_quotes = parse_bool(name, key, value)?,
+            _ => return Err(unknown(name, key)),
+        }
+    }
+    Ok(Arc::new(stage))
+}
+
+fn normalize_thai_sara_am(name: &str, opts: &mut Options<'_>) -> Built {
+    let mut stage = NormalizeThaiSaraAm::default();
+    for pair in opts {
+        let (key, value) = pair?;
+        match key {
+            "compose" => stage.compose = parse_bool(name, key, value)?,
+            _ => return Err(unknown(name, key)),
+        }
+    }
+    Ok(Arc::new(stage))
+}
+
+fn normalize_number_forms(name: &str, opts: &mut Options<'_>) -> Built {
+    let mut stage = NormalizeNumberForms::default();
+    for pair in opts {
+        let (key, value) = pair?;
+        let mode = match value {
+            "keep" => NumberFormMode::Keep,
+            "ascii" => NumberFormMode::Ascii,
+            "value" => NumberFormMode::Value,
+            _ if matches!(key, "fractions" | "roman") => {
+                return Err(invalid(name, key, value));
+            }
+            _ => return Err(unknown(name, key)),
+        };
+        match key {
+            "fractions" => stage.fractions = mode,
+            "roman" => stage.roman = mode,
+            _ => return Err(unknown(name, key)),
+        }
+    }
+    Ok(Arc::new(stage))
+}
+
+fn normalize_letterlike_symbols(name: &str, opts: &mut Options<'_>) -> Built {
+    let mut stage = NormalizeLetterlikeSymbols::default();
+    for pair in opts {
+        let (key, value) = pair?;
+        match key {
+            "expand" => stage.expand = parse_bool(name, key, value)?,
+            _ => return Err(unknown(name, key)),
+        }
+    }
+    Ok(Arc::new(stage))
+}
+
+fn strip_modifier_tone_letters(name: &str, opts: &mut Options<'_>) -> Built {
+    let mut stage = StripModifierToneLetters::default();
+    for pair in opts {
+        let (key, value) = pair?;
+        match key {
+            "keep_tone_bars" => stage.keep_tone_bars = parse_bool(name, key, value)?,
+            _ => return Err(unknown(name, key)),
+        }
+    }
+    Ok(Arc::new(stage))
+}
+
+fn normalize_bullets(name: &str, opts: &mut Options<'_>) -> Built {
+    let mut stage = NormalizeBullets::default();
+    for pair in opts {
+        let (key, value) = pair?;
+        match (key, value) {
+            ("shapes", "decorative") => stage.shapes = BulletShapes::Decorative,
+            ("shapes", "geometric") => stage.shapes = BulletShapes::Geometric,
+            ("shapes", _) => return Err(invalid(name, key, value)),
+            ("bullet", _) => stage.bullet = parse_char(name, key, value)?,
+            _ => return Err(unknown(name, key)),
+        }
+    }
+    Ok(Arc::new(stage))
+}
+
+fn normalize_currency(name: &str, opts: &mut Options<'_>) -> Built {
+    let mut stage = NormalizeCurrency::default();
+    for pair in opts {
+        let (key, value) = pair?;
+        match key {
+            "to_codes" => stage.to_codes = parse_bool(name, key, value)?,
+            _ => return Err(unknown(name, key)),
+        }
+    }
+    Ok(Arc::new(stage))
+}
+
+fn normalize_technical_symbols(name: &str, opts: &mut Options<'_>) -> Built {
+    let mut stage = NormalizeTechnicalSymbols::default();
+    for pair in opts {
+        let (key, value) = pair?;
+        stage.mode = match (key, value) {
+            ("mode", "remove") => TechnicalSymbolMode::Remove,
+            ("mode", "name") => TechnicalSymbolMode::Name,
+            ("mode", _) => return Err(invalid(name, key, value)),
+            _ => return Err(unknown(name, key)),
+        };
+    }
+    Ok(Arc::new(stage))
+}
+
+fn normalize_units(name: &str, opts: &mut Options<'_>) -> Built {
+    let mut stage = NormalizeUnits::default();
+    for pair in opts {
+        let (key, value) = pair?;
+        match key {
+            "insert_space" => stage.insert_space = parse_bool(name, key, value)?,
+            _ => return Err(unknown(name, key)),
+        }
+    }
+    Ok(Arc::new(stage))
+}
+
+fn transliterate(name: &str, opts: &mut Options<'_>) -> Built {
+    let mut stage = Transliterate::default();
+    for pair in opts {
+        let (key, value) = pair?;
+        stage.fallback = match (key, value) {
+            ("fallback", "pass_through") => TransliterateFallback::PassThrough,
+            ("fallback", "drop") => TransliterateFallback::Drop,
+            ("fallback", "escape") => TransliterateFallback::Escape,
+            ("fallback", _) => match value.strip_prefix("replace:") {
+                Some(c) => TransliterateFallback::Replace(parse_char(name, key, c)?),
+                None => return Err(invalid(name, key, value)),
+            },
+            _ => return Err(unknown(name, key)),
+        };
+    }
+    Ok(Arc::new(stage))
+}
+
+fn unify_width(name: &str, opts: &mut Options<'_>) -> Built {
+    let mut stage = UnifyWidth::default();
+    for pair in opts {
+        let (key, value) = pair?;
+        match key {
+            "keep_halfwidth_kana" => {
+                stage.keep_halfwidth_kana = parse_bool(name, key, value)?;
+            }
+            _ => return Err(unknown(name, key)),
+        }
+    }
+    Ok(Arc::new(stage))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_registered_stage_reports_its_registry_name() {
+        for &(name, _) in STAGES {
+            let stage = stage_from_kv(name, "").unwrap_or_else(|e| panic!("{name}: {e}"));
+            assert_eq!(stage.name(), name);
+        }
+        for &(alias, target) in ALIASES {
+            assert!(STAGES.iter().all(|&(name, _)| name != alias), "{alias}");
+            assert_eq!(stage_from_kv(alias, "").unwrap().name(), target);
+        }
+    }
+
+    #[test]
+    fn stage_options_round_trip() {
+        let stages: [Arc<dyn Stage + Send + Sync>; 15] = [
+            Arc::new(crate::COLLAPSE_WHITESPACE),
+            Arc::new(crate::REPLACE_CONTROL_CHARS),
+            Arc::new(NormalizeControlChars {
+                policy: ControlCharPolicy::Remove,
+            }),
+            Arc::new(crate::REPLACE_PRIVATE_USE),
+            Arc::new(crate::NORMALIZE_CJK_PUNCTUATION_AND_QUOTES),
+            Arc::new(crate::DECOMPOSE_SARA_AM),
+            Arc::new(crate::JOIN_UNITS),
+            Arc::new(crate::NUMBER_FORMS_TO_VALUES),
+            Arc::new(crate::UNIFY_WIDTH_KEEP_KANA),
+            Arc::new(crate::FOLD_LETTERLIKE_LETTERS),
+            Arc::new(crate::KEEP_TONE_BARS),
+            Arc::new(crate::TRANSLITERATE_ESCAPED),
+            Arc::new(crate::CURRENCY_TO_CODES),
+            Arc::new(crate::NAME_TECHNICAL_SYMBOLS),
+            Arc::new(crate::NORMALIZE_GEOMETRIC_BULLETS),
+        ];
+        for stage in stages {
+            let rebuilt = stage_from_kv(stage.name(), &stage.options()).unwrap();
+            assert_eq!(rebuilt.options(), stage.options(), "{}", stage.name());
+        }
+    }
+
+    #[test]
+    fn bad_options_are_reported() {
+        assert_eq!(
+            stage_from_kv("lower-case", "").err(),
+            Some(ParseError::UnknownStage("lower-case".into()))
+        );
+        assert_eq!(
+            stage_from_kv("lower_case", "turkish=true").err(),
+            Some(ParseError::UnknownOption {
+                stage: "lowercase".into(),
+                option: "turkish".into(),
+            })
+        );
+        assert_eq!(
+            stage_from_kv("normalize_whitespace", "trim=yes").err(),
+            Some(ParseError::InvalidValue {
+                stage: "normalize_whitespace".into(),
+                option: "trim".into(),
+                value: "yes".into(),
+            })
+        );
+        assert!(stage_from_kv("strip_private_use", "replacement=ab").is_err());
+        assert!(stage_from_kv("strip_private_use", "replacement=U+FFFD").is_ok());
+        assert!(stage_from_kv("normalize_control_chars", "replacement=?").is_err());
+        assert!(stage_from_kv("transliterate", "fallback=drop,replacement=?").is_err());
+        assert_eq!(
+            stage_from_kv("transliterate", "fallback=replace:?")
+                .unwrap()
+                .options(),
+            "fallback=replace:U+003F"
+        );
+        assert!(stage_from_kv("normalize_control_chars", "policy=replace:ab").is_err());
+        assert_eq!(
+            stage_from_kv("normalize_control_chars", "policy=replace:?")
+                .unwrap()
+                .options(),
+            "policy=replace:U+003F"
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use smallvec::SmallVec;
+use std::{fmt, ops::Deref};
+
+/// Inline capacity of a [`SmallString`], in bytes
+const INLINE: usize = 64;
+
+/// A string that keeps short contents inline, without a heap allocation.
+///
+/// Returned by [`Normy::normalize_small`](crate::Normy::normalize_small).
+/// Up to [`SmallString::INLINE_CAPACITY`] bytes live on the stack; longer
+/// contents move to a `String` once, the first time they outgrow the buffer.
+/// Derefs to `str`.
+#[derive(Clone)]
+pub struct SmallString {
+    repr: Repr,
+}
+
+#[derive(Clone)]
+enum Repr {
+    /// Always valid UTF-8, never spilled: pushes move to `Heap` first
+    Inline(SmallVec<[u8; INLINE]>),
+    Heap(String),
+}
+
+impl SmallString {
+    /// Bytes that fit without a heap allocation.
+    pub const INLINE_CAPACITY: usize = INLINE;
+
+    pub(crate) fn new() -> Self {
+        Self {
+            repr: Repr::Inline(SmallVec::new()),
+        }
+    }
+
+    pub(crate) fn push(&mut self, c: char) {
+        let mut utf8 = [0; 4];
+        let encoded = c.encode_utf8(&mut utf8);
+        match &mut self.repr {
+            Repr::Inline(buf) if buf.len() + encoded.len() <= INLINE => {
+                buf.extend_from_slice(encoded.as_bytes());
+            }
+            Repr::Inline(buf) => {
+                let mut heap = String::with_capacity(INLINE * 2);
+                heap.push_str(inline_str(buf));
+                heap.push(c);
+                self.repr = Repr::Heap(heap);
+            }
+            Repr::Heap(s) => s.push(c),
+        }
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match &self.repr {
+            Repr::Inline(buf) => inline_str(buf),
+            Repr::Heap(s) => s,
+        }
+    }
+
+    /// `true` while the contents are stored inline, without a heap allocation.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        matches!(self.repr, Repr::Inline(_))
+    }
+
+    pub fn into_string(self) -> String {
+        match self.repr {
+            Repr::Inline(buf) => inline_str(&buf).to_owned(),
+            Repr::Heap(s) => s,
+        }
+    }
+}
+
+#[inline]
+fn inline_str(buf: &[u8]) -> &str {
+    std::str::from_utf8(buf).expect("inline buffer holds whole chars only")
+}
+
+impl From<&str> for SmallString {
+    fn from(s: &str) -> Self {
+        let repr = if s.len() <= INLINE {
+            Repr::Inline(SmallVec::from_slice(s.as_bytes()))
+        } else {
+            Repr::Heap(s.to_owned())
+        };
+        Self { repr }
+    }
+}
+
+impl From<String> for SmallString {
+    fn from(s: String) -> Self {
+        if s.len() <= INLINE {
+            Self::from(s.as_str())
+        } else {
+            Self {
+                repr: Repr::Heap(s),
+            }
+        }
+    }
+}
+
+impl Extend<char> for SmallString {
+    fn extend<T: IntoIterator<Item = char>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|c| self.push(c));
+    }
+}
+
+impl Deref for SmallString {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SmallString {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for SmallString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SmallString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl fmt::Debug for SmallString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for SmallString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -1,14 +1,46 @@
+pub mod canonicalize_marks;
 pub mod case_fold;
+pub mod dedup_tone_marks;
+pub mod expand_iteration_marks;
+pub mod expand_ligatures;
 pub mod lower_case;
 pub mod normalization;
+pub mod normalize_arabic_presentation_forms;
+pub mod normalize_bullets;
+pub mod normalize_cjk_compatibility;
+pub mod normalize_cjk_punctuation;
+pub mod normalize_currency;
+pub mod normalize_enclosed_alphanumerics;
+pub mod normalize_enclosed_cjk;
+pub mod normalize_general_punctuation;
+pub mod normalize_keycaps;
+pub mod normalize_letterlike_symbols;
+pub mod normalize_modifier_letters;
+pub mod normalize_mongolian;
+pub mod normalize_number_forms;
+pub mod normalize_phonetic_extensions;
 pub mod normalize_punctuation;
+pub mod normalize_radicals;
+pub mod normalize_squared_cjk;
+pub mod normalize_superscripts;
+pub mod normalize_technical_symbols;
+pub mod normalize_thai_sara_am;
+pub mod normalize_units;
 pub mod normalize_whitespace;
+pub mod remove_combining_marks;
 pub mod remove_diacritics;
 pub mod segment_words;
+pub mod slugify;
+pub mod strip_braille;
+pub mod strip_combining_half_marks;
 pub mod strip_control_chars;
 pub mod strip_format_controls;
 pub mod strip_html;
+pub mod strip_ideographic_description_chars;
 pub mod strip_markdown;
+pub mod strip_modifier_tone_letters;
+pub mod strip_ocr_symbols;
+pub mod strip_private_use;
 pub mod transliterate;
 pub mod unify_width;
 
@@ -24,6 +56,11 @@ pub enum StageError {
 
     #[error("Normalization validation failed at stage `{0}`: {1}")]
     Validation(&'static str, String),
+
+    /// Output of [`Normy::normalize_ascii`](crate::Normy::normalize_ascii)
+    /// still holds a non-ASCII char, at byte `offset` of the output.
+    #[error("Normalized output is not ASCII: {first_char:?} at byte {offset}")]
+    NonAscii { first_char: char, offset: usize },
 }
 
 /// # The Normy Stage Contract
@@ -42,8 +79,8 @@ pub enum StageError {
 ///    - Must **never** attempt to "salvage" zero-copy by comparing output with input.
 ///    - Must trust `needs_apply` unconditionally.
 ///
-/// Stages that can transform text without allocation (e.g. pure character mappings)
-/// should implement `as_char_mapper()` or `into_dyn_char_mapper()` instead.
+/// Stages that are pure character mappings should also implement
+/// `as_char_mapper()` so whole pipelines can be compiled into one lookup.
 pub trait Stage: Send + Sync {
     /// Human-readable name – used for profiling and error messages.
     fn name(&self) -> &'static str;
@@ -56,6 +93,27 @@ pub trait Stage: Send + Sync {
     /// Always allocate. May mutate and may be slow.
     /// You must never try to "be clever" and return the input unchanged.
     fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError>;
+
+    /// This stage as a pure 1:1 char mapping under `ctx`, if it is one.
+    ///
+    /// Used by `Normy::compile_char_table`. Only return `Some` when
+    /// `map_char` reproduces `apply` exactly, char for char.
+    fn as_char_mapper(&self, _ctx: &Context) -> Option<&dyn CharMapper> {
+        None
+    }
+
+    /// This stage's configuration as `key=value` pairs, comma-separated, in the
+    /// form `DynamicNormyBuilder::from_kv` accepts. Empty for stages without
+    /// configuration.
+    fn options(&self) -> String {
+        String::new()
+    }
+}
+
+/// A stage that maps each char to exactly one char, looking at nothing but
+/// the char itself and the context – no insertions, removals, or reordering.
+pub trait CharMapper: Send + Sync {
+    fn map_char(&self, c: char, ctx: &Context) -> char;
 }
 
 /// Static (monomorphized) version for compile-time optimization
@@ -0,0 +1,153 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{
+        Stage, StageError, StaticFusableStage, StaticIdentityAdapter,
+        normalization::{ICU4X_NFC, ICU4X_NFD},
+    },
+    testing::stage_contract::StageTestConfig,
+};
+use icu_normalizer::properties::CanonicalCombiningClassMapBorrowed;
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+const CCC: CanonicalCombiningClassMapBorrowed<'static> = CanonicalCombiningClassMapBorrowed::new();
+
+/// `c` repeats `prev` and is a combining mark (non-zero combining class)
+#[inline(always)]
+fn is_repeated_mark(prev: Option<char>, c: char) -> bool {
+    prev == Some(c) && CCC.get_u8(c) != 0
+}
+
+/// Produces clean canonical output: NFC with repeated combining marks removed.
+///
+/// Stacking stages that emit combining marks (a custom transliteration, a
+/// decomposition followed by a re-accenting step, …) can leave the same mark
+/// twice on one base: `e` + `◌́` + `◌́`. NFC keeps such sequences – only one
+/// acute composes into `é` and the other dangles. This stage decomposes the
+/// text, drops every combining mark identical to the mark right before it,
+/// and recomposes, so `é` + `◌́` becomes `é`.
+///
+/// Canonical reordering happens before the comparison, so repeats separated
+/// only by marks of another combining class are also caught (`a◌́◌̣◌́` → `ạ́`).
+/// Distinct stacked marks (`ệ`) are kept. Place it last in a pipeline as a
+/// safety net, whatever the order of the stages before it.
+///
+/// Zero-copy when the text is already NFC with no repeated marks. Not
+/// fusable, like the other normalization stages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CanonicalizeMarks;
+
+impl Stage for CanonicalizeMarks {
+    fn name(&self) -> &'static str {
+        "canonicalize_marks"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        if !ICU4X_NFC.is_normalized(text) {
+            return Ok(true);
+        }
+        // A repeat may hide inside a precomposed base (`é` + `◌́`)
+        let mut prev = None;
+        Ok(ICU4X_NFD.normalize_iter(text.chars()).any(|c| {
+            let repeated = is_repeated_mark(prev, c);
+            prev = Some(c);
+            repeated
+        }))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut deduped = String::with_capacity(text.len());
+        let mut prev = None;
+        for c in ICU4X_NFD.normalize(&text).chars() {
+            if !is_repeated_mark(prev, c) {
+                deduped.push(c);
+            }
+            prev = Some(c);
+        }
+        Ok(Cow::Owned(ICU4X_NFC.normalize(&deduped).into_owned()))
+    }
+}
+
+impl StaticFusableStage for CanonicalizeMarks {
+    type Adapter<'a, I>
+        = StaticIdentityAdapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        StaticIdentityAdapter::new(input)
+    }
+}
+
+impl StageTestConfig for CanonicalizeMarks {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "e\u{0301}\u{0301}",
+            "\u{00E9}\u{0301}",
+            "a\u{0301}\u{0323}\u{0301}",
+            "cafe\u{0301}",
+            "ệ tiếng Việt",
+            "e\u{0323}\u{0302}\u{0302}t \u{00E9}\u{0301}",
+            "\u{05E9}\u{05C1}\u{05C1}",
+            "plain text",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["café", "ệ", "tiếng Việt", "日本語", "hello world", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("e\u{0301}\u{0301}", "\u{00E9}"),
+            ("\u{00E9}\u{0301}", "\u{00E9}"),
+            ("cafe\u{0301}", "café"),
+            ("a\u{0301}\u{0323}\u{0301}", "\u{1EA1}\u{0301}"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(CanonicalizeMarks);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VIE;
+
+    #[test]
+    fn distinct_marks_survive_and_repeats_collapse() {
+        let ctx = Context::new(VIE);
+        // ệ with its circumflex doubled, and a plain second acute on é
+        let input = "e\u{0323}\u{0302}\u{0302}t \u{00E9}\u{0301}";
+        let out = CanonicalizeMarks.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "ệt é");
+    }
+}
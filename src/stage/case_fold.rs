@@ -2,7 +2,7 @@ use crate::{
     CAT, DAN, DEU, ELL, ENG, FRA, ISL, ITA, LIT, NLD, NOR, POR, SPA, SWE, TUR,
     context::Context,
     lang::{Lang, LangEntry},
-    stage::{FusedIterator, Stage, StageError, StaticFusableStage},
+    stage::{CharMapper, FusedIterator, Stage, StageError, StaticFusableStage},
     testing::stage_contract::StageTestConfig,
 };
 use std::borrow::Cow;
@@ -70,6 +70,19 @@ impl Stage for CaseFold {
 
         Ok(Cow::Owned(out))
     }
+
+    fn as_char_mapper(&self, ctx: &Context) -> Option<&dyn CharMapper> {
+        // Multi-char folds (German ß → ss) rule out a 1:1 mapping
+        let entry = ctx.lang_entry;
+        (!entry.has_fold_map() || entry.has_one_to_one_folds()).then_some(self as &dyn CharMapper)
+    }
+}
+
+impl CharMapper for CaseFold {
+    #[inline(always)]
+    fn map_char(&self, c: char, ctx: &Context) -> char {
+        ctx.lang_entry.apply_case_fold(c).unwrap_or(c)
+    }
 }
 
 impl StaticFusableStage for CaseFold {
@@ -0,0 +1,219 @@
+use crate::{
+    LAO, THA, VIE,
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+    unicode::is_combining_mark,
+};
+use smallvec::SmallVec;
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// The tone marks of `lang`, empty when it is not written with any
+fn tone_marks(lang: Lang) -> &'static [char] {
+    match lang {
+        // Mai ek, mai tho, mai tri, mai chattawa
+        THA => &['\u{0E48}', '\u{0E49}', '\u{0E4A}', '\u{0E4B}'],
+        // Mai ek, mai tho, mai ti, mai catawa
+        LAO => &['\u{0EC8}', '\u{0EC9}', '\u{0ECA}', '\u{0ECB}'],
+        // Huyền, sắc, ngã, hỏi, nặng as combining marks
+        VIE => &['\u{0300}', '\u{0301}', '\u{0303}', '\u{0309}', '\u{0323}'],
+        _ => &[],
+    }
+}
+
+/// Removes repeated tone marks from a syllable in tonal scripts.
+///
+/// A base letter carries at most one tone mark, but bad input methods and
+/// pastes from broken renderers double them: `ก่่า` renders like `ก่า` yet
+/// compares unequal. This stage keeps the first occurrence of each tone mark
+/// on a base and drops later copies, even when another mark such as a vowel
+/// sign sits between them.
+///
+/// | Language | Tone marks                                   |
+/// |----------|----------------------------------------------|
+/// | `THA`    | U+0E48–0E4B (mai ek … mai chattawa)          |
+/// | `LAO`    | U+0EC8–0ECB                                  |
+/// | `VIE`    | combining grave, acute, tilde, hook, dot below |
+///
+/// A base's marks are the combining marks and the language's spacing
+/// diacritics that follow it. Distinct tone marks on one base are all kept –
+/// choosing between them needs more than the text. Vietnamese tones are only
+/// seen as combining marks, so run `NFD` first for precomposed input. Other
+/// languages have no tone marks and pass through untouched.
+///
+/// Zero-copy when no syllable repeats a tone mark.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DedupToneMarks;
+
+impl Stage for DedupToneMarks {
+    fn name(&self) -> &'static str {
+        "dedup_tone_marks"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, ctx: &Context) -> Result<bool, StageError> {
+        let tones = tone_marks(ctx.lang);
+        if tones.is_empty() || text.is_ascii() {
+            return Ok(false);
+        }
+        let mut scan = self.static_fused_adapter(text.chars(), ctx);
+        while !scan.dropped && scan.next().is_some() {}
+        Ok(scan.dropped)
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(self.static_fused_adapter(text.chars(), ctx));
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for DedupToneMarks {
+    type Adapter<'a, I>
+        = DedupToneMarksAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        DedupToneMarksAdapter {
+            input,
+            tones: tone_marks(ctx.lang),
+            marks: ctx.lang_entry.spacing_diacritics(),
+            seen: SmallVec::new(),
+            dropped: false,
+        }
+    }
+}
+
+pub struct DedupToneMarksAdapter<I> {
+    input: I,
+    tones: &'static [char],
+    /// Spacing diacritics of the language, which extend a base like marks
+    marks: &'static [char],
+    /// Tone marks already emitted on the current base
+    seen: SmallVec<[char; 2]>,
+    /// A repeated tone mark has been dropped
+    dropped: bool,
+}
+
+impl<I: Iterator<Item = char>> Iterator for DedupToneMarksAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let c = self.input.next()?;
+            if self.tones.contains(&c) {
+                if self.seen.contains(&c) {
+                    self.dropped = true;
+                    continue;
+                }
+                self.seen.push(c);
+            } else if !is_combining_mark(c) && !self.marks.contains(&c) {
+                self.seen.clear();
+            }
+            return Some(c);
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.input.size_hint().1)
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for DedupToneMarksAdapter<I> {}
+
+impl StageTestConfig for DedupToneMarks {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(lang: Lang) -> &'static [&'static str] {
+        match lang {
+            THA => &[
+                "ก\u{0E48}\u{0E48}า",
+                "ก\u{0E48}\u{0E34}\u{0E48}",
+                "ไม\u{0E48}\u{0E48} ใช\u{0E48}",
+                "สวัสดี",
+                "ภาษาไทย",
+                "",
+            ],
+            LAO => &["ກ\u{0EC8}\u{0EC8}າ", "ສະບາຍດີ", ""],
+            VIE => &[
+                "a\u{0301}\u{0301}",
+                "e\u{0323}\u{0302}\u{0323}",
+                "tiếng Việt",
+                "",
+            ],
+            _ => &["plain text", "ก\u{0E48}\u{0E48}า", ""],
+        }
+    }
+
+    fn should_pass_through(lang: Lang) -> &'static [&'static str] {
+        match lang {
+            THA => &["สวัสดี", "ก\u{0E48}า ข\u{0E48}า", "hello", ""],
+            LAO => &["ສະບາຍດີ", "hello", ""],
+            VIE => &["tiếng Việt", "a\u{0301}", "hello", ""],
+            _ => &["plain text", "ก\u{0E48}\u{0E48}า", ""],
+        }
+    }
+
+    fn should_transform(lang: Lang) -> &'static [(&'static str, &'static str)] {
+        match lang {
+            THA => &[
+                ("ก\u{0E48}\u{0E48}า", "ก\u{0E48}า"),
+                ("ก\u{0E48}\u{0E34}\u{0E48}", "ก\u{0E48}\u{0E34}"),
+            ],
+            LAO => &[("ກ\u{0EC8}\u{0EC8}າ", "ກ\u{0EC8}າ")],
+            VIE => &[("a\u{0301}\u{0301}", "a\u{0301}")],
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(DedupToneMarks);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ENG;
+
+    #[test]
+    fn doubled_thai_tone_mark_leaves_one() {
+        let ctx = Context::new(THA);
+        // "ไม่" (not) with mai ek typed twice, next to a clean "ใช่"
+        let input = "ไม\u{0E48}\u{0E48} ใช\u{0E48}";
+        let out = DedupToneMarks.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "ไม่ ใช่");
+
+        // English has no tone marks: the same text is left alone
+        assert!(
+            !DedupToneMarks
+                .needs_apply(input, &Context::new(ENG))
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use crate::{
+    JPN,
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+    unicode::{is_cjk_unified_ideograph, is_hiragana, is_katakana},
+};
+use smallvec::SmallVec;
+use std::borrow::Cow;
+use std::iter::{FusedIterator, Peekable};
+
+/// Expands CJK iteration marks into the characters they repeat.
+///
+/// Iteration marks are shorthand for "repeat what came before":
+///
+/// - `々` (U+3005) or its vertical form `〻` (U+303B) after a kanji/hanzi →
+///   the ideograph (`人々` → `人人`)
+/// - `ヽ` (U+30FD) after a katakana → the katakana (`カヽ` → `カカ`)
+/// - `ゝ` (U+309D) after a hiragana → the hiragana (`こゝ` → `ここ`)
+/// - `〃` (U+3003) standing alone between blanks → the preceding
+///   whitespace-separated token (`東京 港区 〃` → `東京 港区 港区`)
+///
+/// Expansion uses a lookbehind on the *expanded* output, so chains resolve
+/// naturally (`人々々` → `人人人`, `A 〃 〃` → `A A A`). A mark with nothing
+/// valid to repeat (sentence-initial, or after the wrong script) is left
+/// unchanged, as is a ditto mark attached to other text (`〃号`). Voiced
+/// marks (`ヾ`, `ゞ`) are preserved, since expanding them would need a
+/// dakuten composition step.
+///
+/// The marks are handled the same way in every language: `々` is used in
+/// Chinese and Korean text too, and the ditto mark across East Asia.
+///
+/// Zero-copy when no expandable mark is present. The kana and ideograph
+/// marks map 1:1; the fused adapter buffers ditto expansions.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ExpandIterationMarks;
+
+/// IDEOGRAPHIC DITTO MARK
+const DITTO: char = '\u{3003}';
+
+/// Resolve `c` against the previously emitted char.
+///
+/// Returns the repeated char for an expandable iteration mark, `None` otherwise.
+#[inline(always)]
+fn expand_mark(c: char, prev: Option<char>) -> Option<char> {
+    let prev = prev?;
+    let repeats = match c {
+        '々' | '〻' => is_cjk_unified_ideograph(prev),
+        'ヽ' => is_katakana(prev) && !matches!(prev, '\u{30FB}'..='\u{30FF}'),
+        'ゝ' => is_hiragana(prev) && !matches!(prev, '\u{3099}'..='\u{309F}'),
+        _ => false,
+    };
+    repeats.then_some(prev)
+}
+
+/// `c` is a ditto mark standing alone as a token, between `prev` and `next`
+#[inline(always)]
+fn is_lone_ditto(c: char, prev: Option<char>, next: Option<char>) -> bool {
+    c == DITTO && prev.is_none_or(char::is_whitespace) && next.is_none_or(char::is_whitespace)
+}
+
+impl Stage for ExpandIterationMarks {
+    fn name(&self) -> &'static str {
+        "expand_iteration_marks"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        let mut prev = None;
+        // A token a ditto mark could copy has been seen
+        let mut has_token = false;
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if is_lone_ditto(c, prev, chars.peek().copied()) {
+                if has_token {
+                    return Ok(true);
+                }
+            } else if expand_mark(c, prev).is_some() {
+                return Ok(true);
+            } else if !c.is_whitespace() {
+                has_token = true;
+            }
+            prev = Some(c);
+        }
+        Ok(false)
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(self.static_fused_adapter(text.chars(), ctx));
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for ExpandIterationMarks {
+    type Adapter<'a, I>
+        = ExpandIterationMarksAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        ExpandIterationMarksAdapter {
+            input: input.peekable(),
+            prev: None,
+            token: SmallVec::new(),
+            in_token: false,
+            pending: SmallVec::new(),
+        }
+    }
+}
+
+pub struct ExpandIterationMarksAdapter<I: Iterator<Item = char>> {
+    input: Peekable<I>,
+    /// Last emitted char – the lookbehind for the next mark
+    prev: Option<char>,
+    /// The last token emitted, as a ditto mark would copy it
+    token: SmallVec<[char; 16]>,
+    /// `prev` belongs to `token`
+    in_token: bool,
+    /// Remaining chars of the current ditto expansion, stored in reverse
+    pending: SmallVec<[char; 16]>,
+}
+
+impl<I: Iterator<Item = char>> Iterator for ExpandIterationMarksAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.pending.pop() {
+            self.prev = Some(c);
+            return Some(c);
+        }
+        let c = self.input.next()?;
+        if is_lone_ditto(c, self.prev, self.input.peek().copied()) {
+            if let Some(&first) = self.token.first() {
+                self.pending.extend(self.token[1..].iter().rev().copied());
+                self.in_token = true;
+                self.prev = Some(first);
+                return Some(first);
+            }
+            // Nothing to copy: keep the mark, and do not let it be copied
+            self.prev = Some(c);
+            return Some(c);
+        }
+        let emitted = expand_mark(c, self.prev).unwrap_or(c);
+        if emitted.is_whitespace() {
+            self.in_token = false;
+        } else {
+            if !self.in_token {
+                self.token.clear();
+                self.in_token = true;
+            }
+            self.token.push(emitted);
+        }
+        self.prev = Some(emitted);
+        Some(emitted)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, _) = self.input.size_hint();
+        (lower + self.pending.len(), None) // Ditto expansions can grow the text
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for ExpandIterationMarksAdapter<I> {}
+
+impl StageTestConfig for ExpandIterationMarks {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[] // 1:1 but context-sensitive (lookbehind)
+    }
+
+    fn samples(lang: Lang) -> &'static [&'static str] {
+        match lang {
+            JPN => &[
+                "人々",
+                "時々雨",
+                "人々々",
+                "々人々",
+                "日々の生活",
+                "々から",
+                "いすゞ",
+                "カヽカ",
+                "カヽとこゝ",
+                "こゝろ",
+                "東京 港区\n〃 渋谷区",
+                "〃 先頭",
+                "〃 a 〃 〃",
+                "人々 〃",
+                "",
+            ],
+            _ => &[
+                "人々",
+                "人〻",
+                "hello",
+                "々",
+                "テスト",
+                "A 〃 〃",
+                "〃号",
+                "",
+            ],
+        }
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "人人",
+            "々から",
+            "hello world",
+            "ヽ",
+            "aゝ",
+            "〃号",
+            "〃 first",
+            "",
+        ]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("人々", "人人"),
+            ("時々雨", "時時雨"),
+            ("人々々", "人人人"),
+            ("カヽ", "カカ"),
+            ("こゝろ", "こころ"),
+            ("人〻", "人人"),
+            ("A 〃", "A A"),
+            ("東京 港区\n〃 渋谷区", "東京 港区\n港区 渋谷区"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(ExpandIterationMarks);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KOR, ZHO};
+
+    #[test]
+    fn marks_without_a_valid_base_are_preserved() {
+        let ctx = Context::new(JPN);
+        let stage = ExpandIterationMarks;
+
+        // Sentence-initial mark has nothing to repeat
+        assert!(!stage.needs_apply("々は", &ctx).unwrap());
+        // Kanji mark after kana, kana mark after kanji
+        assert!(!stage.needs_apply("の々", &ctx).unwrap());
+        assert!(!stage.needs_apply("人ゝ", &ctx).unwrap());
+
+        assert_eq!(
+            stage.apply(Cow::Borrowed("々人々"), &ctx).unwrap(),
+            "々人人"
+        );
+    }
+
+    #[test]
+    fn ditto_mark_repeats_the_preceding_token() {
+        let ctx = Context::new(JPN);
+        let stage = ExpandIterationMarks;
+        let run = |text| stage.apply(Cow::Borrowed(text), &ctx).unwrap();
+
+        assert_eq!(run("佐藤 東京都 〃 〃"), "佐藤 東京都 東京都 東京都");
+        assert_eq!(run("品川区 人々\n〃 日々"), "品川区 人人\n人人 日日");
+        // Attached to other text, or with no token before it, the mark stays
+        assert!(!stage.needs_apply("同〃", &ctx).unwrap());
+        assert!(!stage.needs_apply("〃 〃 東京", &ctx).unwrap());
+    }
+
+    #[test]
+    fn ideograph_repetition_expands_in_every_cjk_language() {
+        let stage = ExpandIterationMarks;
+        for (lang, input, expected) in [
+            (JPN, "人々", "人人"),
+            (ZHO, "天天〻好好", "天天天好好"),
+            (ZHO, "年々", "年年"),
+            (KOR, "時々", "時時"),
+        ] {
+            let ctx = Context::new(lang);
+            assert!(stage.needs_apply(input, &ctx).unwrap());
+            assert_eq!(stage.apply(Cow::Borrowed(input), &ctx).unwrap(), expected);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{
+        Stage, StageError, StaticFusableStage,
+        normalization::{ExpandCharsAdapter, ICU4X_NFKD, nfkd_expand},
+    },
+    testing::stage_contract::StageTestConfig,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Expands the Alphabetic Presentation Forms block (U+FB00–FB4F) to the
+/// base sequences they stand for.
+///
+/// The block holds typographic ligatures and presentation forms that a
+/// search for the plain letters should still match:
+///
+/// - `ﬁ` → `fi`, `ﬄ` → `ffl`, `ﬅ` → `st` (Latin ligatures)
+/// - `ﬓ` → `մն`, `ﬔ` → `մե` (Armenian ligatures)
+/// - `שׁ` → `ש` + shin dot, `ﬡ` → `א` (Hebrew pointed and wide letters)
+/// - `ﭏ` → `אל` (Hebrew ligature alef lamed)
+///
+/// Mappings are the NFKD forms of the block, applied to these characters
+/// only – the rest of the text is untouched. Hebrew points therefore stay as
+/// separate combining marks after the letter. `ﬞ` (judeo-spanish varika),
+/// which has no decomposition, is preserved.
+///
+/// Zero-copy when no presentation forms are present; the fused adapter
+/// buffers multi-char expansions.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ExpandLigatures;
+
+#[inline(always)]
+fn is_alphabetic_presentation_form(c: char) -> bool {
+    matches!(c, '\u{FB00}'..='\u{FB4F}')
+}
+
+/// `true` when `c` is a presentation form with a decomposition
+#[inline(always)]
+fn folds(c: char) -> bool {
+    is_alphabetic_presentation_form(c) && !ICU4X_NFKD.is_normalized(c.encode_utf8(&mut [0; 4]))
+}
+
+impl Stage for ExpandLigatures {
+    fn name(&self) -> &'static str {
+        "expand_ligatures"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(folds))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len() + (text.len() >> 2));
+        for c in text.chars() {
+            if folds(c) {
+                out.extend(ICU4X_NFKD.normalize_iter(std::iter::once(c)));
+            } else {
+                out.push(c);
+            }
+        }
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for ExpandLigatures {
+    type Adapter<'a, I>
+        = ExpandCharsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        ExpandCharsAdapter::new(input, folds, nfkd_expand)
+    }
+}
+
+impl StageTestConfig for ExpandLigatures {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "ﬁnd the ﬂoor",
+            "oﬄine ﬅ ﬆ",
+            "ﬓ ﬔ ﬕ ﬖ ﬗ",
+            "\u{FB13}ալ",
+            "שָׁלוֹם \u{FB2A}",
+            "\u{FB21}\u{FB29}\u{FB4F}",
+            "\u{FB31}\u{FB35}\u{FB26}",
+            "\u{FB1E}",
+            "plain text",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["plain text", "fi", "մն", "שלום", "\u{FB1E}", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("ﬁ", "fi"),
+            ("ﬄ", "ffl"),
+            ("ﬅ", "st"),
+            ("ﬓ", "մն"),
+            ("\u{FB2A}", "\u{05E9}\u{05C1}"),
+            ("\u{FB4F}", "\u{05D0}\u{05DC}"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(ExpandLigatures);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ENG, HEB};
+
+    #[test]
+    fn armenian_ligatures_expand() {
+        let stage = ExpandLigatures;
+        let ctx = Context::new(ENG);
+
+        // "մնալ" (to stay) typeset with the men-now ligature
+        let input = "\u{FB13}ալ";
+        let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "մնալ");
+    }
+
+    #[test]
+    fn hebrew_presentation_forms_become_letter_and_points() {
+        let stage = ExpandLigatures;
+        let ctx = Context::new(HEB);
+
+        // Dagesh forms of bet and vav, wide final mem
+        let input = "\u{FB31}\u{FB35}\u{FB26}";
+        let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "\u{05D1}\u{05BC}\u{05D5}\u{05BC}\u{05DD}");
+    }
+}
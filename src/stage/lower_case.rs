@@ -2,8 +2,9 @@ use crate::{
     CAT, DAN, DEU, ELL, ENG, FRA, ISL, ITA, LIT, NLD, NOR, POR, SPA, SWE, TUR,
     context::Context,
     lang::{Lang, LangEntry},
-    stage::{Stage, StageError, StaticFusableStage},
+    stage::{CharMapper, Stage, StageError, StaticFusableStage, StaticIdentityAdapter},
     testing::stage_contract::StageTestConfig,
+    unicode::is_combining_mark,
 };
 use std::borrow::Cow;
 use std::iter::FusedIterator;
@@ -49,6 +50,17 @@ impl Stage for LowerCase {
 
         Ok(Cow::Owned(out))
     }
+
+    fn as_char_mapper(&self, _ctx: &Context) -> Option<&dyn CharMapper> {
+        Some(self)
+    }
+}
+
+impl CharMapper for LowerCase {
+    #[inline(always)]
+    fn map_char(&self, c: char, ctx: &Context) -> char {
+        ctx.lang_entry.apply_lowercase(c)
+    }
 }
 
 impl StaticFusableStage for LowerCase {
@@ -150,6 +162,130 @@ impl StageTestConfig for LowerCase {
     }
 }
 
+/// `LowerCase` plus context-sensitive lowercasing rules.
+///
+/// Identical to `LowerCase` except for Greek capital sigma, which Unicode
+/// lowercases by word position (the `Final_Sigma` condition):
+///
+/// - `Σ` at the end of a word → `ς`
+/// - `Σ` anywhere else → `σ`
+///
+/// Example: "ΟΔΟΣ ΣΟΦΟΣ" → "οδος σοφος" with both trailing sigmas as `ς`.
+///
+/// `char::to_lowercase` is context-free, so `LowerCase` always yields `σ`.
+/// Already-lowercase `σ` is never rewritten. For case-insensitive matching use
+/// `CaseFold`, which folds `ς` and `σ` together.
+///
+/// Not fusable: the sigma rule needs lookahead.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ContextualLowerCase;
+
+const CAPITAL_SIGMA: char = 'Σ';
+
+// Cased letters participate in word context; marks and apostrophes are see-through
+#[inline(always)]
+fn is_cased(c: char) -> bool {
+    c.is_lowercase() || c.is_uppercase()
+}
+
+#[inline(always)]
+fn is_case_ignorable(c: char) -> bool {
+    is_combining_mark(c) || matches!(c, '\'' | '.' | ':' | '·' | '\u{2019}')
+}
+
+/// Unicode `Final_Sigma`: a cased letter before, no cased letter after.
+#[inline]
+fn is_final_sigma(before: &str, after: &str) -> bool {
+    let preceded = before
+        .chars()
+        .rev()
+        .find(|&c| !is_case_ignorable(c))
+        .is_some_and(is_cased);
+    let followed = after
+        .chars()
+        .find(|&c| !is_case_ignorable(c))
+        .is_some_and(is_cased);
+    preceded && !followed
+}
+
+impl Stage for ContextualLowerCase {
+    fn name(&self) -> &'static str {
+        "contextual_lowercase"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, ctx: &Context) -> Result<bool, StageError> {
+        // Σ itself needs lowercasing, so the context rule never adds work
+        LowerCase.needs_apply(text, ctx)
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        for (i, c) in text.char_indices() {
+            if c == CAPITAL_SIGMA && is_final_sigma(&text[..i], &text[i + c.len_utf8()..]) {
+                out.push('ς');
+            } else {
+                out.push(ctx.lang_entry.apply_lowercase(c));
+            }
+        }
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for ContextualLowerCase {
+    type Adapter<'a, I>
+        = StaticIdentityAdapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        StaticIdentityAdapter::new(input)
+    }
+}
+
+impl StageTestConfig for ContextualLowerCase {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(lang: Lang) -> &'static [&'static str] {
+        match lang {
+            ELL => &["ΣΟΦΟΣ", "ΟΔΟΣ", "ΟΔΟΣ.", "Σ", "ΟΔΟΣ ΣΟΦΟΣ", "ΌΣΟΙ"],
+            TUR => &["İSTANBUL", "ISPARTA"],
+            _ => &["HELLO", "World 123", " café ", "ΣΟΦΟΣ"],
+        }
+    }
+
+    fn should_pass_through(lang: Lang) -> &'static [&'static str] {
+        match lang {
+            ELL => &["σοφοσ", "οδος", "σοφος"],
+            _ => &["hello", "world", "test123", ""],
+        }
+    }
+
+    fn should_transform(lang: Lang) -> &'static [(&'static str, &'static str)] {
+        match lang {
+            ELL => &[
+                ("ΣΟΦΟΣ", "σοφος"),
+                ("ΟΔΟΣ", "οδος"),
+                ("ΟΔΟΣ ΣΟΦΟΣ", "οδος σοφος"),
+                ("Σ", "σ"),
+            ],
+            TUR => &[("İSTANBUL", "istanbul")],
+            _ => &[("HELLO", "hello")],
+        }
+    }
+}
+
 // Universal contract compliance
 #[cfg(test)]
 mod contract_tests {
@@ -159,6 +295,7 @@ mod contract_tests {
     #[test]
     fn universal_contract_compliance() {
         assert_stage_contract!(LowerCase);
+        assert_stage_contract!(ContextualLowerCase);
     }
 }
 
@@ -193,4 +330,34 @@ mod tests {
             "ijssel"
         );
     }
+
+    fn lower(text: &str) -> String {
+        ContextualLowerCase
+            .apply(Cow::Borrowed(text), &Context::new(ELL))
+            .unwrap()
+            .into_owned()
+    }
+
+    #[test]
+    fn word_final_sigma_becomes_final_form() {
+        assert_eq!(lower("ΟΔΟΣ"), "οδο\u{03C2}");
+        assert_eq!(lower("ΟΔΌΣ"), "οδό\u{03C2}");
+        assert_eq!(lower("ΣΟΦΟΣ ΑΝΘΡΩΠΟΣ"), "σοφο\u{03C2} ανθρωπο\u{03C2}");
+        // Punctuation ends the word
+        assert_eq!(lower("ΟΔΟΣ, ΝΑΙ"), "οδο\u{03C2}, ναι");
+    }
+
+    #[test]
+    fn non_final_sigma_stays_medial() {
+        assert_eq!(lower("ΣΟΦΟΣ"), "\u{03C3}οφο\u{03C2}");
+        // Lone sigma has no preceding letter, so it is not word-final
+        assert_eq!(lower("Σ"), "\u{03C3}");
+        // LowerCase itself stays context-free
+        assert_eq!(
+            LowerCase
+                .apply(Cow::Borrowed("ΟΔΟΣ"), &Context::new(ELL))
+                .unwrap(),
+            "οδο\u{03C3}"
+        );
+    }
 }
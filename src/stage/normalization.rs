@@ -5,6 +5,7 @@ use crate::{
     stage::{Stage, StageError, StaticFusableStage},
     testing::stage_contract::StageTestConfig,
 };
+use smallvec::SmallVec;
 use std::iter::FusedIterator;
 
 use icu_normalizer::{
@@ -12,14 +13,14 @@ use icu_normalizer::{
     DecomposingNormalizerBorrowed,
 };
 use std::{borrow::Cow, sync::LazyLock};
-// ── ICU4X ──
-static ICU4X_NFC: LazyLock<ComposingNormalizerBorrowed> =
+// ── ICU4X ── shared by every stage that needs a normalizer
+pub(crate) static ICU4X_NFC: LazyLock<ComposingNormalizerBorrowed> =
     LazyLock::new(ComposingNormalizer::new_nfc);
-static ICU4X_NFKC: LazyLock<ComposingNormalizerBorrowed> =
+pub(crate) static ICU4X_NFKC: LazyLock<ComposingNormalizerBorrowed> =
     LazyLock::new(ComposingNormalizer::new_nfkc);
-static ICU4X_NFD: LazyLock<DecomposingNormalizerBorrowed<'static>> =
+pub(crate) static ICU4X_NFD: LazyLock<DecomposingNormalizerBorrowed<'static>> =
     LazyLock::new(DecomposingNormalizer::new_nfd);
-static ICU4X_NFKD: LazyLock<DecomposingNormalizerBorrowed<'static>> =
+pub(crate) static ICU4X_NFKD: LazyLock<DecomposingNormalizerBorrowed<'static>> =
     LazyLock::new(DecomposingNormalizerBorrowed::new_nfkd);
 
 // Unicode Normalization Form C (Canonical Composition)
@@ -176,6 +177,79 @@ impl<'a, I: Iterator<Item = char>> Iterator for NormalizationDecomposeAdapter<'a
 
 impl<'a, I: Iterator<Item = char>> FusedIterator for NormalizationDecomposeAdapter<'a, I> {}
 
+/// Push the NFKC form of `c` through `push`
+#[inline(always)]
+pub(crate) fn nfkc_expand(c: char, push: &mut dyn FnMut(char)) {
+    ICU4X_NFKC.normalize_iter(std::iter::once(c)).for_each(push);
+}
+
+/// Push the NFKD form of `c` through `push`
+#[inline(always)]
+pub(crate) fn nfkd_expand(c: char, push: &mut dyn FnMut(char)) {
+    ICU4X_NFKD.normalize_iter(std::iter::once(c)).for_each(push);
+}
+
+/// Fused adapter for stages that rewrite a few chars one at a time, usually
+/// to their NFKC or NFKD form, and leave the rest of the text alone.
+///
+/// `folds` picks the chars to rewrite and `expand` pushes each replacement;
+/// multi-char replacements are buffered.
+pub struct ExpandCharsAdapter<I> {
+    input: I,
+    folds: fn(char) -> bool,
+    expand: fn(char, &mut dyn FnMut(char)),
+    /// Remaining chars of the current expansion, stored in reverse
+    pending: SmallVec<[char; 8]>,
+}
+
+impl<I> ExpandCharsAdapter<I> {
+    /// Rewrite the chars matching `folds` to their NFKC form
+    #[inline(always)]
+    pub(crate) fn nfkc(input: I, folds: fn(char) -> bool) -> Self {
+        Self::new(input, folds, nfkc_expand)
+    }
+
+    #[inline(always)]
+    pub(crate) fn new(
+        input: I,
+        folds: fn(char) -> bool,
+        expand: fn(char, &mut dyn FnMut(char)),
+    ) -> Self {
+        Self {
+            input,
+            folds,
+            expand,
+            pending: SmallVec::new(),
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for ExpandCharsAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.pending.pop() {
+            return Some(c);
+        }
+        let c = self.input.next()?;
+        if !(self.folds)(c) {
+            return Some(c);
+        }
+        (self.expand)(c, &mut |c| self.pending.push(c));
+        self.pending.reverse();
+        self.pending.pop()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, _) = self.input.size_hint();
+        (lower + self.pending.len(), None) // Expansions can grow the text
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for ExpandCharsAdapter<I> {}
+
 macro_rules! impl_stage_test_config {
     ($type:ty) => {
         impl StageTestConfig for $type {
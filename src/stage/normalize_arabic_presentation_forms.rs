@@ -0,0 +1,157 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{
+        Stage, StageError, StaticFusableStage,
+        normalization::{ExpandCharsAdapter, ICU4X_NFKC},
+    },
+    testing::stage_contract::StageTestConfig,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Folds the Arabic Presentation Forms (U+FB50–FDFF, U+FE70–FEFE) to
+/// standard Arabic letters.
+///
+/// The blocks hold the positional (initial, medial, final, isolated) shapes
+/// of each letter and a large set of ligatures. Text copied out of PDFs or
+/// legacy renderers often carries them, and a search for the plain letters
+/// would miss it:
+///
+/// - `ﺏ ﺑ ﺒ ﺐ` → `ب` (positional forms)
+/// - `ﻷ` → `لأ`, `ﻻ` → `لا` (lam-alef ligatures)
+/// - `ﷲ` → `الله`, `ﲝ` → `بح` (word and letter-pair ligatures)
+///
+/// Mappings are the NFKC forms of these characters only – the rest of the
+/// text is untouched, so hamza and madda recompose with their alef as in
+/// ordinary Arabic text. Isolated harakat forms (`ﹰ`, `ﹲ`, …) become a
+/// space followed by the combining mark. Characters without a
+/// compatibility mapping (`﴾ ﴿`, `﷽`) are preserved.
+/// U+FEFF, the byte order mark, is left to `StripFormatControls`.
+///
+/// Zero-copy when no presentation forms are present; the fused adapter
+/// buffers multi-char expansions.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NormalizeArabicPresentationForms;
+
+#[inline(always)]
+fn is_arabic_presentation_form(c: char) -> bool {
+    matches!(c, '\u{FB50}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFE}')
+}
+
+/// `true` when `c` is a presentation form with a compatibility mapping
+#[inline(always)]
+fn folds(c: char) -> bool {
+    is_arabic_presentation_form(c) && !ICU4X_NFKC.is_normalized(c.encode_utf8(&mut [0; 4]))
+}
+
+impl Stage for NormalizeArabicPresentationForms {
+    fn name(&self) -> &'static str {
+        "normalize_arabic_presentation_forms"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(folds))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len() + (text.len() >> 2));
+        for c in text.chars() {
+            if folds(c) {
+                out.extend(ICU4X_NFKC.normalize_iter(std::iter::once(c)));
+            } else {
+                out.push(c);
+            }
+        }
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for NormalizeArabicPresentationForms {
+    type Adapter<'a, I>
+        = ExpandCharsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        ExpandCharsAdapter::nfkc(input, folds)
+    }
+}
+
+impl StageTestConfig for NormalizeArabicPresentationForms {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "\u{FEDF}\u{FEF4}\u{FEA4}",
+            "\u{FEF7} \u{FEFB} \u{FEF5} \u{FEF9}",
+            "\u{FEF8}\u{0646} \u{0633}\u{FEFC}\u{0645}",
+            "\u{FDF2} \u{FC9D}",
+            "\u{FE8F}\u{FE91}\u{FE92}\u{FE90}",
+            "\u{FE70}\u{FE72}",
+            "\u{FD3E}\u{FD3F}",
+            "مرحبا بالعالم",
+            "plain text",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["مرحبا", "لأ", "\u{FD3E}", "\u{FEFF}", "plain text", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("\u{FEF7}", "\u{0644}\u{0623}"),
+            ("\u{FEFB}", "\u{0644}\u{0627}"),
+            ("\u{FE8F}\u{FE91}", "\u{0628}\u{0628}"),
+            ("\u{FDF2}", "\u{0627}\u{0644}\u{0644}\u{0647}"),
+            ("\u{FC9D}", "\u{0628}\u{062D}"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeArabicPresentationForms);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ARA;
+
+    #[test]
+    fn lam_alef_ligatures_fold_to_base_letters() {
+        let stage = NormalizeArabicPresentationForms;
+        let ctx = Context::new(ARA);
+
+        // "لأن" and "سلام" typeset with the final lam-alef ligatures
+        let input = "\u{FEF8}\u{0646} \u{0633}\u{FEFC}\u{0645}";
+        let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "لأن سلام");
+    }
+}
@@ -0,0 +1,280 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+};
+use std::borrow::Cow;
+use std::iter::{FusedIterator, Peekable};
+
+/// Which shapes count as list markers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulletShapes {
+    /// Shapes with hardly any use besides marking list items:
+    /// `• ‣ ⁃ ∙ ◦ ▪ ▫ ▸ ▹ ► ▻ ⦁ ⦾ ⦿`
+    Decorative,
+    /// `Decorative` plus the Geometric Shapes that also carry meaning
+    /// elsewhere (ratings, checkboxes, play buttons):
+    /// `● ○ ◉ ◎ ■ □ ◼ ◻ ◆ ◇ ▶ ▷`
+    Geometric,
+}
+
+impl BulletShapes {
+    #[inline(always)]
+    fn contains(self, c: char) -> bool {
+        match c {
+            '\u{2022}' | '\u{2023}' | '\u{2043}' | '\u{2219}' | '\u{25E6}' => true,
+            '\u{25AA}' | '\u{25AB}' | '\u{25B8}'..='\u{25BB}' => true,
+            '\u{2981}' | '\u{29BE}' | '\u{29BF}' => true,
+            '\u{25CF}' | '\u{25CB}' | '\u{25C9}' | '\u{25CE}' | '\u{25A0}' | '\u{25A1}'
+            | '\u{25FC}' | '\u{25FB}' | '\u{25C6}' | '\u{25C7}' | '\u{25B6}' | '\u{25B7}' => {
+                self == BulletShapes::Geometric
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Blank that may precede or follow a marker; line breaks end a line instead
+#[inline(always)]
+fn is_blank(c: char) -> bool {
+    c.is_whitespace() && !matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
+
+/// Whether the char after `c` still starts a line
+#[inline(always)]
+fn starts_line_after(line_start: bool, c: char) -> bool {
+    if is_blank(c) {
+        line_start
+    } else {
+        c.is_whitespace()
+    }
+}
+
+/// Replaces the shapes used as list markers with one canonical bullet.
+///
+/// Scraped content marks list items with whatever the page's stylesheet
+/// drew: `▪ item`, `▸ item`, `● item`. This stage rewrites them all to
+/// `bullet` (`•` by default), so the lists match each other.
+///
+/// A shape is a marker only at the start of a line – after optional blanks –
+/// and followed by a blank: `▸ Settings` changes, but a rating `●●●○○` or
+/// `A ▸ B` breadcrumbs do not. `shapes` picks the set of shapes:
+/// `Decorative` ones are always safe, while `Geometric` also takes shapes
+/// such as `●` and `□` that are meaningful outside lists.
+///
+/// Common presets:
+/// - `NORMALIZE_BULLETS`: decorative shapes to `•` (default)
+/// - `NORMALIZE_GEOMETRIC_BULLETS`: geometric shapes too
+///
+/// Zero-copy when no list markers need to change. Fully fusable 1:1 mapping
+/// (position-dependent, so it cannot be compiled into a char table).
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizeBullets {
+    pub shapes: BulletShapes,
+    pub bullet: char,
+}
+
+/// Rewrite decorative list markers to `•`.
+pub const NORMALIZE_BULLETS: NormalizeBullets = NormalizeBullets {
+    shapes: BulletShapes::Decorative,
+    bullet: '\u{2022}',
+};
+
+/// Rewrite decorative and geometric list markers to `•`.
+pub const NORMALIZE_GEOMETRIC_BULLETS: NormalizeBullets = NormalizeBullets {
+    shapes: BulletShapes::Geometric,
+    bullet: '\u{2022}',
+};
+
+impl Default for NormalizeBullets {
+    fn default() -> Self {
+        NORMALIZE_BULLETS
+    }
+}
+
+impl NormalizeBullets {
+    /// `c`, read at the start of a line, is a marker to rewrite
+    #[inline(always)]
+    fn rewrites<I: Iterator<Item = char>>(&self, c: char, rest: &mut Peekable<I>) -> bool {
+        c != self.bullet && self.shapes.contains(c) && rest.peek().is_some_and(|&n| is_blank(n))
+    }
+}
+
+impl Stage for NormalizeBullets {
+    fn name(&self) -> &'static str {
+        "normalize_bullets"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        let mut line_start = true;
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if line_start && self.rewrites(c, &mut chars) {
+                return Ok(true);
+            }
+            line_start = starts_line_after(line_start, c);
+        }
+        Ok(false)
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(self.static_fused_adapter(text.chars(), ctx));
+        Ok(Cow::Owned(out))
+    }
+
+    fn options(&self) -> String {
+        let shapes = match self.shapes {
+            BulletShapes::Decorative => "decorative",
+            BulletShapes::Geometric => "geometric",
+        };
+        format!("shapes={shapes},bullet=U+{:04X}", self.bullet as u32)
+    }
+}
+
+impl StaticFusableStage for NormalizeBullets {
+    type Adapter<'a, I>
+        = NormalizeBulletsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizeBulletsAdapter {
+            input: input.peekable(),
+            stage: *self,
+            line_start: true,
+        }
+    }
+}
+
+pub struct NormalizeBulletsAdapter<I: Iterator<Item = char>> {
+    input: Peekable<I>,
+    stage: NormalizeBullets,
+    /// Only blanks have been seen since the last line break
+    line_start: bool,
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeBulletsAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.input.next()?;
+        let out = if self.line_start && self.stage.rewrites(c, &mut self.input) {
+            self.stage.bullet
+        } else {
+            c
+        };
+        self.line_start = starts_line_after(self.line_start, c);
+        Some(out)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.input.size_hint()
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeBulletsAdapter<I> {}
+
+impl StageTestConfig for NormalizeBullets {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "▪ one\n▪ two",
+            "  ▸ nested\n\t◦ deeper",
+            "‣ a\r\n⁃ b",
+            "● filled\n○ hollow",
+            "rating ●●●○○",
+            "Features:\n▪ fast\n  ▸ zero-copy\n● safe ●●○\n■ tested",
+            "□ todo\n◆ done",
+            "Home ▸ Settings",
+            "▪\nnot a marker",
+            "• already",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "• already\n• canonical",
+            "rating ●●●○○",
+            "Home ▸ Settings",
+            "● filled",
+            "▪item",
+            "- plain list",
+            "",
+        ]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("▪ one", "• one"),
+            ("▪ one\n▸ two", "• one\n• two"),
+            ("  ◦ nested", "  • nested"),
+            ("‣ a\r\n⁃ b", "• a\r\n• b"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+    use crate::testing::stage_contract::fused_path_equivalent_to_apply;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeBullets::default());
+        // Shares the samples; only the expected transforms differ
+        fused_path_equivalent_to_apply(NORMALIZE_GEOMETRIC_BULLETS);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(stage: NormalizeBullets, text: &str) -> String {
+        let ctx = Context::default();
+        stage.apply(Cow::Borrowed(text), &ctx).unwrap().into_owned()
+    }
+
+    #[test]
+    fn shape_bulleted_list_gets_one_bullet() {
+        let list = "Features:\n▪ fast\n  ▸ zero-copy\n● safe ●●○\n■ tested";
+        assert_eq!(
+            run(NORMALIZE_BULLETS, list),
+            "Features:\n• fast\n  • zero-copy\n● safe ●●○\n■ tested"
+        );
+        assert_eq!(
+            run(NORMALIZE_GEOMETRIC_BULLETS, list),
+            "Features:\n• fast\n  • zero-copy\n• safe ●●○\n• tested"
+        );
+
+        let ascii = NormalizeBullets {
+            bullet: '-',
+            ..NORMALIZE_GEOMETRIC_BULLETS
+        };
+        assert_eq!(run(ascii, "□ todo\n◆ done"), "- todo\n- done");
+    }
+}
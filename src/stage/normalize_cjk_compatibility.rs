@@ -0,0 +1,190 @@
+use crate::{
+    all_langs,
+    context::Context,
+    lang::Lang,
+    stage::{CharMapper, Stage, StageError, StaticFusableStage, normalization::ICU4X_NFD},
+    testing::stage_contract::StageTestConfig,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Folds CJK Compatibility Ideographs to their unified ideographs.
+///
+/// The compatibility blocks (U+F900–FAFF and the supplement U+2F800–2FA1F)
+/// duplicate unified ideographs for round-tripping legacy encodings, e.g.
+/// `豈` (U+F900) → `豈` (U+8C48), `樂` (U+F914) → `樂` (U+6A02). NFC performs the
+/// same singleton mapping, but also recomposes everything else in the text;
+/// this stage applies only the ideograph mapping.
+///
+/// The dozen code points in U+FA0E–FA29 that are themselves unified
+/// ideographs (e.g. `﨎`) have no mapping and are preserved.
+///
+/// 1:1 mapping → zero-copy when no compatibility ideographs are present.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NormalizeCjkCompatibility;
+
+#[inline(always)]
+fn is_cjk_compatibility_ideograph(c: char) -> bool {
+    matches!(c, '\u{F900}'..='\u{FAFF}' | '\u{2F800}'..='\u{2FA1F}')
+}
+
+/// The unified ideograph for `c`, or `c` itself
+#[inline(always)]
+fn unified_form(c: char) -> char {
+    if !is_cjk_compatibility_ideograph(c) {
+        return c;
+    }
+    // Every mapping in these blocks is a canonical singleton
+    ICU4X_NFD
+        .normalize_iter(std::iter::once(c))
+        .next()
+        .unwrap_or(c)
+}
+
+impl Stage for NormalizeCjkCompatibility {
+    fn name(&self) -> &'static str {
+        "normalize_cjk_compatibility"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(|c| unified_form(c) != c))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        // Both blocks and their targets are 3 or 4 bytes; the supplement can shrink
+        let mut out = String::with_capacity(text.len());
+        out.extend(text.chars().map(unified_form));
+        Ok(Cow::Owned(out))
+    }
+
+    fn as_char_mapper(&self, _ctx: &Context) -> Option<&dyn CharMapper> {
+        Some(self)
+    }
+}
+
+impl CharMapper for NormalizeCjkCompatibility {
+    #[inline(always)]
+    fn map_char(&self, c: char, _ctx: &Context) -> char {
+        unified_form(c)
+    }
+}
+
+impl StaticFusableStage for NormalizeCjkCompatibility {
+    type Adapter<'a, I>
+        = NormalizeCjkCompatibilityAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizeCjkCompatibilityAdapter { input }
+    }
+}
+
+pub struct NormalizeCjkCompatibilityAdapter<I> {
+    input: I,
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeCjkCompatibilityAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.next().map(unified_form)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.input.size_hint()
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeCjkCompatibilityAdapter<I> {}
+
+impl StageTestConfig for NormalizeCjkCompatibility {
+    fn one_to_one_languages() -> &'static [Lang] {
+        all_langs()
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "\u{F900}\u{F914}",
+            "音\u{F91D}楽",
+            "\u{2F800}\u{2FA1D}",
+            "\u{FA0E}\u{FA0F}",
+            "plain 日本語",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["plain 日本語", "樂", "\u{FA0E}", "hello world", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("\u{F900}", "\u{8C48}"),
+            ("\u{F914}", "\u{6A02}"),
+            ("\u{2F800}", "\u{4E3D}"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeCjkCompatibility);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JPN, KOR, NFC};
+
+    #[test]
+    fn compatibility_ideographs_match_nfc() {
+        let ctx = Context::new(KOR);
+        // Korean Hanja from KS X 1001 round-trip into the compatibility block
+        let input = "\u{F96B}\u{F9A8} 國語 \u{F9D1}";
+        let out = NormalizeCjkCompatibility
+            .apply(Cow::Borrowed(input), &ctx)
+            .unwrap();
+        assert_eq!(out, NFC.apply(Cow::Borrowed(input), &ctx).unwrap());
+        assert!(!out.chars().any(is_cjk_compatibility_ideograph));
+    }
+
+    #[test]
+    fn other_text_is_not_recomposed() {
+        let ctx = Context::new(JPN);
+        // NFC would compose the decomposed が; this stage must not
+        let input = "\u{304B}\u{3099}\u{F900}";
+        let out = NormalizeCjkCompatibility
+            .apply(Cow::Borrowed(input), &ctx)
+            .unwrap();
+        assert_eq!(out, "\u{304B}\u{3099}\u{8C48}");
+        assert!(
+            !NormalizeCjkCompatibility
+                .needs_apply("東京都 \u{304B}\u{3099}", &ctx)
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,277 @@
+use crate::{
+    all_langs,
+    context::Context,
+    lang::Lang,
+    stage::{CharMapper, Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+    unicode::normalize_cjk_punctuation_char,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Replaces CJK punctuation with ASCII equivalents for cross-script search.
+///
+/// This stage maps punctuation from the CJK Symbols and Punctuation block
+/// (and its half-width forms) to plain ASCII:
+///
+/// | CJK                          | ASCII |
+/// |------------------------------|-------|
+/// | `、` `､`                      | `,`   |
+/// | `。` `｡`                      | `.`   |
+/// | `〈` `《`                     | `<`   |
+/// | `〉` `》`                     | `>`   |
+/// | `【` `〔` `〖` `〘` `〚`         | `[`   |
+/// | `】` `〕` `〗` `〙` `〛`         | `]`   |
+/// | `〜`                          | `~`   |
+///
+/// Spacing is left to `NormalizeWhitespace`: `　` (U+3000 IDEOGRAPHIC SPACE)
+/// is the block's only space and already in its Unicode set. `〿` (U+303F
+/// IDEOGRAPHIC HALF FILL SPACE) is kept: despite its name it is a visible
+/// symbol (General Category So), not whitespace.
+///
+/// Small Form Variants (U+FE50–FE6B, e.g. `﹐` `﹒` `﹖` `﹙` `﹫`) always map
+/// to their ASCII counterparts, as do the Vertical Forms used in vertically set
+/// text (U+FE10–FE18, e.g. `︐` `︒` `︖` `︗`). The vertical ellipsis `︙`
+/// becomes the horizontal `…`.
+///
+/// With `fold_quotes` enabled, quote brackets are folded as well:
+///
+/// | CJK                              | ASCII |
+/// |----------------------------------|-------|
+/// | `「` `」` `〝` `〞` `〟` `＂` `｢` `｣` | `"`   |
+/// | `『` `』` `＇`                     | `'`   |
+///
+/// Common presets:
+/// - `NORMALIZE_CJK_PUNCTUATION`: brackets and separators only, quotes preserved
+/// - `NORMALIZE_CJK_PUNCTUATION_AND_QUOTES`: also folds quote brackets
+///
+/// Pure 1:1 mapping → zero-copy when no CJK punctuation is present.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizeCjkPunctuation {
+    /// Fold corner brackets `「」『』` and full-width quotes `＂＇` to ASCII `"` / `'`
+    pub fold_quotes: bool,
+}
+
+/// Map CJK brackets and separators to ASCII, preserving quote brackets.
+pub const NORMALIZE_CJK_PUNCTUATION: NormalizeCjkPunctuation =
+    NormalizeCjkPunctuation { fold_quotes: false };
+
+/// Map CJK brackets and separators to ASCII and fold quote brackets to `"` / `'`.
+pub const NORMALIZE_CJK_PUNCTUATION_AND_QUOTES: NormalizeCjkPunctuation =
+    NormalizeCjkPunctuation { fold_quotes: true };
+
+impl Default for NormalizeCjkPunctuation {
+    fn default() -> Self {
+        NORMALIZE_CJK_PUNCTUATION
+    }
+}
+
+impl Stage for NormalizeCjkPunctuation {
+    fn name(&self) -> &'static str {
+        "normalize_cjk_punctuation"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text
+            .chars()
+            .any(|c| normalize_cjk_punctuation_char(c, self.fold_quotes) != c))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        // CJK punctuation is 3 bytes and ASCII is 1, so the output never grows
+        let mut out = String::with_capacity(text.len());
+        for c in text.chars() {
+            out.push(normalize_cjk_punctuation_char(c, self.fold_quotes));
+        }
+        Ok(Cow::Owned(out))
+    }
+
+    fn as_char_mapper(&self, _ctx: &Context) -> Option<&dyn CharMapper> {
+        Some(self)
+    }
+
+    fn options(&self) -> String {
+        format!("fold_quotes={}", self.fold_quotes)
+    }
+}
+
+impl CharMapper for NormalizeCjkPunctuation {
+    #[inline(always)]
+    fn map_char(&self, c: char, _ctx: &Context) -> char {
+        normalize_cjk_punctuation_char(c, self.fold_quotes)
+    }
+}
+
+impl StaticFusableStage for NormalizeCjkPunctuation {
+    type Adapter<'a, I>
+        = NormalizeCjkPunctuationAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizeCjkPunctuationAdapter {
+            input,
+            fold_quotes: self.fold_quotes,
+        }
+    }
+}
+
+pub struct NormalizeCjkPunctuationAdapter<I> {
+    input: I,
+    fold_quotes: bool,
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeCjkPunctuationAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input
+            .next()
+            .map(|c| normalize_cjk_punctuation_char(c, self.fold_quotes))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.input.size_hint()
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeCjkPunctuationAdapter<I> {}
+
+impl StageTestConfig for NormalizeCjkPunctuation {
+    fn one_to_one_languages() -> &'static [Lang] {
+        all_langs()
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "你好、世界。",
+            "「你好」",
+            "『引用』と「引用」",
+            "【注意】〔注〕",
+            "《书名》〈章〉",
+            "ｶﾀｶﾅ､ｶﾀｶﾅ｡",
+            "你好﹐世界﹒﹙注﹚",
+            "縦書き︐句読点︒︙",
+            "a︐b ︗注︘︓︔︕︖︑︙",
+            "東京\u{3000}\u{303F}大阪",
+            "半\u{303F}角",
+            "hello world",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "hello world",
+            "你好世界",
+            "日本語テキスト",
+            "東京\u{3000}\u{303F}大阪",
+            "test123",
+            "",
+        ]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("你好、世界。", "你好,世界."),
+            ("【注意】", "[注意]"),
+            ("《书名》", "<书名>"),
+            ("〔注〕", "[注]"),
+            ("ｱ､ｲ｡", "ｱ,ｲ."),
+            ("你好﹐世界", "你好,世界"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NORMALIZE_CJK_PUNCTUATION);
+        assert_stage_contract!(NORMALIZE_CJK_PUNCTUATION_AND_QUOTES);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JPN, ZHO};
+
+    #[test]
+    fn quote_folding_is_opt_in() {
+        let ctx = Context::new(ZHO);
+
+        // Default preset leaves quote brackets alone
+        assert!(
+            !NORMALIZE_CJK_PUNCTUATION
+                .needs_apply("「你好」", &ctx)
+                .unwrap()
+        );
+
+        // Quote preset folds primary and nested brackets
+        let stage = NORMALIZE_CJK_PUNCTUATION_AND_QUOTES;
+        assert_eq!(
+            stage.apply(Cow::Borrowed("「你好」"), &ctx).unwrap(),
+            "\"你好\""
+        );
+        assert_eq!(
+            stage.apply(Cow::Borrowed("「他说『好』」"), &ctx).unwrap(),
+            "\"他说'好'\""
+        );
+        assert_eq!(
+            stage.apply(Cow::Borrowed("＂全角＂＇"), &ctx).unwrap(),
+            "\"全角\"'"
+        );
+    }
+
+    #[test]
+    fn small_form_variants_map_to_ascii() {
+        let ctx = Context::new(ZHO);
+        let stage = NORMALIZE_CJK_PUNCTUATION;
+
+        assert!(stage.needs_apply("a﹐b", &ctx).unwrap());
+        assert_eq!(stage.apply(Cow::Borrowed("a﹐b"), &ctx).unwrap(), "a,b");
+        assert_eq!(
+            stage.apply(Cow::Borrowed("﹙﹖﹗﹚﹫﹩"), &ctx).unwrap(),
+            "(?!)@$"
+        );
+    }
+
+    #[test]
+    fn vertical_forms_map_to_horizontal() {
+        let ctx = Context::new(JPN);
+        let stage = NORMALIZE_CJK_PUNCTUATION;
+
+        assert!(stage.needs_apply("東京︐大阪", &ctx).unwrap());
+        assert_eq!(
+            stage.apply(Cow::Borrowed("東京︐大阪︒"), &ctx).unwrap(),
+            "東京,大阪."
+        );
+        assert_eq!(
+            stage
+                .apply(Cow::Borrowed("︗注︘︓︔︕︖︑︙"), &ctx)
+                .unwrap(),
+            "[注]:;!?,…"
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{CharMapper, Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+};
+use smallvec::SmallVec;
+use std::borrow::Cow;
+use std::iter::{FusedIterator, Peekable};
+
+/// The standard symbol for a legacy or width variant of a currency sign
+#[inline(always)]
+fn canonical(c: char) -> Option<char> {
+    Some(match c {
+        '₠' => '€', // EURO-CURRENCY SIGN, the pre-euro ECU mark
+        '＄' | '﹩' => '$',
+        '￠' => '¢',
+        '￡' => '£',
+        '￥' => '¥',
+        '￦' => '₩',
+        _ => return None,
+    })
+}
+
+/// ISO 4217 code of a currency sign that names exactly one current currency
+#[inline(always)]
+fn iso_code(c: char) -> Option<&'static str> {
+    Some(match c {
+        '€' => "EUR",
+        '£' => "GBP",
+        '₡' => "CRC",
+        '₦' => "NGN",
+        '₩' => "KRW",
+        '₪' => "ILS",
+        '₫' => "VND",
+        '₭' => "LAK",
+        '₮' => "MNT",
+        '₱' => "PHP",
+        '₲' => "PYG",
+        '₴' => "UAH",
+        '₵' => "GHS",
+        '₸' => "KZT",
+        '₹' => "INR",
+        '₺' => "TRY",
+        '₼' => "AZN",
+        '₽' => "RUB",
+        '₾' => "GEL",
+        '₿' => "BTC", // No ISO code; BTC is the common ticker
+        _ => return None,
+    })
+}
+
+/// Normalizes currency signs of the Currency Symbols block (U+20A0–20BF)
+/// and their legacy variants.
+///
+/// | Input   | `NORMALIZE_CURRENCY` | `CURRENCY_TO_CODES` |
+/// |---------|----------------------|---------------------|
+/// | `₠`     | `€`                  | `EUR`               |
+/// | `＄5`   | `$5`                 | `$5`                |
+/// | `€10`   | `€10`                | `EUR 10`            |
+/// | `10₺`   | `10₺`                | `10 TRY`            |
+/// | `¥`     | `¥`                  | `¥`                 |
+///
+/// Legacy forms fold to the standard sign: the pre-euro `₠` to `€`, and the
+/// fullwidth and small forms (`＄ ﹩ ￠ ￡ ￥ ￦`) to `$ ¢ £ ¥ ₩`. With
+/// `to_codes`, signs that name a single current currency are also written
+/// as their ISO 4217 code, set off from adjacent letters and digits by a
+/// space. Signs shared by several currencies (`$`, `¢`, `¥`, `₨`) and those
+/// of retired currencies (`₣`, `₧`, `₯`, …) are kept as signs.
+///
+/// Common presets:
+/// - `NORMALIZE_CURRENCY`: fold legacy variants to the standard sign (default)
+/// - `CURRENCY_TO_CODES`: also expand signs to ISO codes
+///
+/// Zero-copy when no such signs are present; the fused adapter buffers the
+/// code expansions.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizeCurrency {
+    pub to_codes: bool,
+}
+
+/// Fold legacy and width variants of currency signs to the standard sign.
+pub const NORMALIZE_CURRENCY: NormalizeCurrency = NormalizeCurrency { to_codes: false };
+
+/// Fold currency signs, then write them as ISO 4217 codes where unambiguous.
+pub const CURRENCY_TO_CODES: NormalizeCurrency = NormalizeCurrency { to_codes: true };
+
+impl Default for NormalizeCurrency {
+    fn default() -> Self {
+        NORMALIZE_CURRENCY
+    }
+}
+
+impl NormalizeCurrency {
+    /// The ISO code `c` expands to, under `to_codes`
+    #[inline(always)]
+    fn code(&self, c: char) -> Option<&'static str> {
+        if !self.to_codes {
+            return None;
+        }
+        iso_code(canonical(c).unwrap_or(c))
+    }
+
+    #[inline(always)]
+    fn changes(&self, c: char) -> bool {
+        !c.is_ascii() && (canonical(c).is_some() || self.code(c).is_some())
+    }
+}
+
+impl Stage for NormalizeCurrency {
+    fn name(&self) -> &'static str {
+        "normalize_currency"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(|c| self.changes(c)))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len() + 8);
+        out.extend(self.static_fused_adapter(text.chars(), ctx));
+        Ok(Cow::Owned(out))
+    }
+
+    fn as_char_mapper(&self, _ctx: &Context) -> Option<&dyn CharMapper> {
+        (!self.to_codes).then_some(self as &dyn CharMapper)
+    }
+
+    fn options(&self) -> String {
+        format!("to_codes={}", self.to_codes)
+    }
+}
+
+impl CharMapper for NormalizeCurrency {
+    #[inline(always)]
+    fn map_char(&self, c: char, _ctx: &Context) -> char {
+        canonical(c).unwrap_or(c)
+    }
+}
+
+impl StaticFusableStage for NormalizeCurrency {
+    type Adapter<'a, I>
+        = NormalizeCurrencyAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizeCurrencyAdapter {
+            input: input.peekable(),
+            stage: *self,
+            prev_alnum: false,
+            pending: SmallVec::new(),
+        }
+    }
+}
+
+pub struct NormalizeCurrencyAdapter<I: Iterator<Item = char>> {
+    input: Peekable<I>,
+    stage: NormalizeCurrency,
+    /// Last char emitted was a letter or digit
+    prev_alnum: bool,
+    /// Remaining chars of the current code expansion, stored in reverse
+    pending: SmallVec<[char; 8]>,
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeCurrencyAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = match self.pending.pop() {
+            Some(c) => c,
+            None => {
+                let c = self.input.next()?;
+                if let Some(code) = self.stage.code(c) {
+                    if self.input.peek().is_some_and(|n| n.is_alphanumeric()) {
+                        self.pending.push(' ');
+                    }
+                    self.pending.extend(code.chars().rev());
+                    if self.prev_alnum {
+                        self.pending.push(' ');
+                    }
+                    self.pending.pop().unwrap_or(c)
+                } else {
+                    canonical(c).unwrap_or(c)
+                }
+            }
+        };
+        self.prev_alnum = c.is_alphanumeric();
+        Some(c)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, _) = self.input.size_hint();
+        (lower + self.pending.len(), None) // Expansions can grow the text
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeCurrencyAdapter<I> {}
+
+impl StageTestConfig for NormalizeCurrency {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "₠100",
+            "＄5 and ﹩6",
+            "￠￡￥￦",
+            "€10, £20, ₹30",
+            "₠100 or ￡80, not ＄",
+            "10₺ (₿0.1), ¥500",
+            "₣ ₧ ₨ ₯",
+            "price: $5",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["€10", "$5", "£20 ¥30", "₹ ₺ ₿", "₣ ₧ ₨", "hello world", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("₠", "€"),
+            ("₠100", "€100"),
+            ("＄5", "$5"),
+            ("﹩", "$"),
+            ("￡20", "£20"),
+            ("￦", "₩"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+    use crate::testing::stage_contract::fused_path_equivalent_to_apply;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeCurrency::default());
+        // Shares the samples; only the expected transforms differ
+        fused_path_equivalent_to_apply(CURRENCY_TO_CODES);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(stage: NormalizeCurrency, text: &str) -> String {
+        let ctx = Context::default();
+        stage.apply(Cow::Borrowed(text), &ctx).unwrap().into_owned()
+    }
+
+    #[test]
+    fn legacy_euro_mark_folds_and_expands_to_its_code() {
+        let input = "₠100 or ￡80, not ＄";
+        assert_eq!(run(NORMALIZE_CURRENCY, input), "€100 or £80, not $");
+        assert_eq!(run(CURRENCY_TO_CODES, input), "EUR 100 or GBP 80, not $");
+
+        let out = run(CURRENCY_TO_CODES, "10₺ (₿0.1), ¥500");
+        assert_eq!(out, "10 TRY (BTC 0.1), ¥500");
+    }
+}
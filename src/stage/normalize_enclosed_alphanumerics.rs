@@ -0,0 +1,180 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{
+        Stage, StageError, StaticFusableStage,
+        normalization::{ExpandCharsAdapter, ICU4X_NFKC, nfkc_expand},
+    },
+    testing::stage_contract::StageTestConfig,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+const NUMBERS: [&str; 21] = [
+    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16",
+    "17", "18", "19", "20",
+];
+
+/// Folds Enclosed Alphanumerics (U+2460–24FF) and the Dingbats circled
+/// digits (U+2776–2793) to plain ASCII.
+///
+/// Numbered lists and formatted text use enclosed forms a search for the
+/// plain digits or letters should still match:
+///
+/// - `①` → `1`, `⑳` → `20`, `⓪` → `0` (circled numbers)
+/// - `⑴` → `(1)`, `⒜` → `(a)`, `⒈` → `1.` (parenthesized and full stop)
+/// - `Ⓐ` → `A`, `ⓩ` → `z` (circled letters)
+/// - `⓫` → `11`, `⓵` → `1`, `⓿` → `0` (negative and double circled)
+/// - `❶` → `1`, `➀` → `1`, `➓` → `10` (Dingbats)
+///
+/// Most of the block uses its NFKC compatibility forms. The negative and
+/// double circled numbers and the Dingbats digits have none in Unicode and
+/// are mapped to their numbers here. The rest of the text is untouched.
+///
+/// Zero-copy when no enclosed alphanumerics are present; the fused adapter
+/// buffers multi-char expansions.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NormalizeEnclosedAlphanumerics;
+
+/// The number written by a negative, double circled or Dingbats digit
+#[inline(always)]
+fn enclosed_number(c: char) -> Option<&'static str> {
+    let cp = c as usize;
+    Some(match c {
+        '\u{24EB}'..='\u{24F4}' => NUMBERS[cp - 0x24EB + 11],
+        '\u{24F5}'..='\u{24FE}' => NUMBERS[cp - 0x24F5 + 1],
+        '\u{24FF}' => NUMBERS[0],
+        '\u{2776}'..='\u{2793}' => NUMBERS[(cp - 0x2776) % 10 + 1],
+        _ => return None,
+    })
+}
+
+/// `true` when `c` is an enclosed alphanumeric with a plain form
+#[inline(always)]
+fn folds(c: char) -> bool {
+    match c {
+        '\u{2460}'..='\u{24EA}' => !ICU4X_NFKC.is_normalized(c.encode_utf8(&mut [0; 4])),
+        _ => enclosed_number(c).is_some(),
+    }
+}
+
+/// Push the plain form of a folding `c` through `push`
+#[inline(always)]
+fn expand(c: char, push: &mut dyn FnMut(char)) {
+    match enclosed_number(c) {
+        Some(number) => number.chars().for_each(push),
+        None => nfkc_expand(c, push),
+    }
+}
+
+impl Stage for NormalizeEnclosedAlphanumerics {
+    fn name(&self) -> &'static str {
+        "normalize_enclosed_alphanumerics"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(folds))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        for c in text.chars() {
+            if folds(c) {
+                expand(c, &mut |c| out.push(c));
+            } else {
+                out.push(c);
+            }
+        }
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for NormalizeEnclosedAlphanumerics {
+    type Adapter<'a, I>
+        = ExpandCharsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        ExpandCharsAdapter::new(input, folds, expand)
+    }
+}
+
+impl StageTestConfig for NormalizeEnclosedAlphanumerics {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "① first ② second",
+            "⑴⑵ ⒜⒝ ⒈⒉",
+            "ⒶⒷⒸ ⓐⓑⓒ ⓪",
+            "⓫⓴ ⓵⓾ ⓿",
+            "❶❷❸ ➀➁ ➊➓",
+            "➀➁➂ ➊➋➌",
+            "❤ ➔",
+            "plain 123",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["plain 123", "(1) 1.", "❤ ➔", "hello world", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("①", "1"),
+            ("⑳", "20"),
+            ("⑴", "(1)"),
+            ("Ⓐ", "A"),
+            ("⓫", "11"),
+            ("❶❷❸", "123"),
+            ("➉", "10"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeEnclosedAlphanumerics);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dingbat_digits_become_ascii() {
+        let stage = NormalizeEnclosedAlphanumerics;
+        let ctx = Context::default();
+
+        for input in ["❶❷❸", "➀➁➂", "➊➋➌"] {
+            assert_eq!(stage.apply(Cow::Borrowed(input), &ctx).unwrap(), "123");
+        }
+        // Neighbouring dingbats are not digits
+        assert!(!stage.needs_apply("❤ ➔ ❵", &ctx).unwrap());
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{
+        Stage, StageError, StaticFusableStage,
+        normalization::{ExpandCharsAdapter, ICU4X_NFKC},
+    },
+    testing::stage_contract::StageTestConfig,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Folds Enclosed CJK Letters and Months (U+3200–32FF) to their base text.
+///
+/// Circled and parenthesized CJK are presentation variants; for search they
+/// should match the plain characters they enclose:
+///
+/// - `㊗` → `祝`, `㊤` → `上` (circled ideographs)
+/// - `㈱` → `(株)`, `㈠` → `(一)` (parenthesized ideographs)
+/// - `㋐` → `ア`, `㉮` → `가` (circled kana / Hangul)
+/// - `㋀` → `1月`, `㉑` → `21`, `㋏` → `LTD` (months, numbers, abbreviations)
+///
+/// Mappings are the NFKC compatibility forms of the block, applied to these
+/// characters only – the rest of the text is untouched. Symbols with no
+/// compatibility form (e.g. `㉈`, `㉿`) are preserved.
+///
+/// Zero-copy when no enclosed CJK characters are present; the fused adapter
+/// buffers multi-char expansions.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NormalizeEnclosedCjk;
+
+#[inline(always)]
+fn is_enclosed_cjk(c: char) -> bool {
+    matches!(c, '\u{3200}'..='\u{32FF}')
+}
+
+/// `true` when `c` is an enclosed CJK char with a compatibility form
+#[inline(always)]
+fn folds(c: char) -> bool {
+    is_enclosed_cjk(c) && !ICU4X_NFKC.is_normalized(c.encode_utf8(&mut [0; 4]))
+}
+
+impl Stage for NormalizeEnclosedCjk {
+    fn name(&self) -> &'static str {
+        "normalize_enclosed_cjk"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(folds))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len() + (text.len() >> 2));
+        for c in text.chars() {
+            if folds(c) {
+                out.extend(ICU4X_NFKC.normalize_iter(std::iter::once(c)));
+            } else {
+                out.push(c);
+            }
+        }
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for NormalizeEnclosedCjk {
+    type Adapter<'a, I>
+        = ExpandCharsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        ExpandCharsAdapter::nfkc(input, folds)
+    }
+}
+
+impl StageTestConfig for NormalizeEnclosedCjk {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "㊗ 合格",
+            "株式会社 ㈱",
+            "㈱山田商事 ㊗開店 ㋋",
+            "㋀から㋋まで",
+            "㋐㋑㋒",
+            "㉮㉯ ㈀",
+            "㉈㉿",
+            "plain 日本語",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["plain 日本語", "(株)", "hello world", "㉈", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("㈱", "(株)"),
+            ("㊗", "祝"),
+            ("㊤㊦", "上下"),
+            ("㋀", "1月"),
+            ("㋐", "ア"),
+            ("㉑", "21"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeEnclosedCjk);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JPN, KOR};
+
+    #[test]
+    fn folds_enclosed_forms_in_context() {
+        let stage = NormalizeEnclosedCjk;
+        let ctx = Context::new(JPN);
+
+        let input = "㈱山田商事 ㊗開店 ㋋";
+        let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "(株)山田商事 祝開店 12月");
+    }
+
+    #[test]
+    fn circled_hangul_syllables_compose() {
+        let ctx = Context::new(KOR);
+        let out = NormalizeEnclosedCjk
+            .apply(Cow::Borrowed("㉮㉻"), &ctx)
+            .unwrap();
+        assert_eq!(out, "가하");
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+    unicode::normalize_punctuation_char,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// What the stage does with a char of U+2000–206F
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Keep,
+    Replace(char),
+    Remove,
+}
+
+#[inline(always)]
+fn action(c: char) -> Action {
+    match c {
+        '\u{2000}'..='\u{200A}' | '\u{2028}' | '\u{2029}' | '\u{202F}' | '\u{205F}' => {
+            Action::Replace(' ')
+        }
+        '\u{2061}'..='\u{2064}' => Action::Remove,
+        '\u{2010}'..='\u{205E}' => match normalize_punctuation_char(c) {
+            mapped if mapped != c => Action::Replace(mapped),
+            _ => Action::Keep,
+        },
+        _ => Action::Keep,
+    }
+}
+
+/// Normalizes the General Punctuation block (U+2000–206F) in one pass.
+///
+/// - Space variants become an ASCII space: en/em quads and spaces, thin and
+///   hair spaces (U+2000–200A), the narrow no-break space (U+202F), the medium
+///   mathematical space (U+205F) and the line/paragraph separators
+///   (U+2028–2029).
+/// - The invisible operators (U+2061–2064: function application, times,
+///   separator, plus) are removed. They only matter to math renderers.
+/// - Typographic punctuation of the block gets the ASCII form of
+///   `NormalizePunctuation`: `‘’` → `'`, `“”` → `"`, `–—` → `-`, `…` → `.`,
+///   `•` → `*`, `‹›` → `<>`.
+///
+/// Everything else is preserved. Zero-width spaces, joiners and bidi
+/// controls are `StripFormatControls`' job; `※`, `‰`, `⁂` and the like
+/// carry meaning and stay.
+///
+/// The mappings are the ones `NormalizeWhitespace` (with `normalize_unicode`)
+/// and `NormalizePunctuation` apply to this block, so running those stages
+/// afterwards finds nothing left to do here: their pre-scans come back clean
+/// for text that only had General Punctuation to fix.
+///
+/// Zero-copy when nothing in the block needs normalizing. Fully fusable.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NormalizeGeneralPunctuation;
+
+impl Stage for NormalizeGeneralPunctuation {
+    fn name(&self) -> &'static str {
+        "normalize_general_punctuation"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(|c| action(c) != Action::Keep))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(self.static_fused_adapter(text.chars(), ctx));
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for NormalizeGeneralPunctuation {
+    type Adapter<'a, I>
+        = NormalizeGeneralPunctuationAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizeGeneralPunctuationAdapter { input }
+    }
+}
+
+pub struct NormalizeGeneralPunctuationAdapter<I> {
+    input: I,
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeGeneralPunctuationAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.find_map(|c| match action(c) {
+            Action::Keep => Some(c),
+            Action::Replace(to) => Some(to),
+            Action::Remove => None,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.input.size_hint();
+        (0, upper) // Can only shrink
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeGeneralPunctuationAdapter<I> {}
+
+impl StageTestConfig for NormalizeGeneralPunctuation {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "em\u{2003}space and thin\u{2009}space",
+            "10\u{202F}000 m\u{205F}x",
+            "f\u{2061}(x) = 2\u{2062}x \u{2064}1",
+            "“quoted” — ‘single’ … • ‹x›",
+            "line\u{2028}para\u{2029}end",
+            "※ 30‰ ⁂",
+            "x\u{2062}y\u{2005}=\u{2009}“\u{2061}sin\u{2064}” \u{2014} ※\u{200A}…",
+            "zero\u{200B}width",
+            "plain text",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["plain text", "※ 30‰ ⁂", "zero\u{200B}width", "« »", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("a\u{2003}b", "a b"),
+            ("10\u{202F}000", "10 000"),
+            ("f\u{2061}(x)", "f(x)"),
+            ("2\u{2062}x", "2x"),
+            ("“hi” – ok…", "\"hi\" - ok."),
+            ("a\u{2028}b", "a b"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeGeneralPunctuation);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NormalizePunctuation, stage::normalize_whitespace::NormalizeWhitespace};
+
+    #[test]
+    fn whole_block_in_one_pass() {
+        let ctx = Context::default();
+        let input = "x\u{2062}y\u{2005}=\u{2009}“\u{2061}sin\u{2064}” \u{2014} ※\u{200A}…";
+
+        let out = NormalizeGeneralPunctuation
+            .apply(Cow::Borrowed(input), &ctx)
+            .unwrap();
+        assert_eq!(out, "xy = \"sin\" - ※ .");
+
+        // Nothing is left for the whitespace and punctuation stages
+        let ws = NormalizeWhitespace {
+            collapse: false,
+            trim: false,
+            normalize_unicode: true,
+            replacement_char: ' ',
+        };
+        assert!(!ws.needs_apply(&out, &ctx).unwrap());
+        assert!(!NormalizePunctuation.needs_apply(&out, &ctx).unwrap());
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+};
+use std::borrow::Cow;
+use std::iter::{FusedIterator, Peekable};
+
+const KEYCAP: char = '\u{20E3}';
+const VS16: char = '\u{FE0F}';
+
+/// Chars that form a keycap emoji with U+20E3
+#[inline(always)]
+fn is_keycap_base(c: char) -> bool {
+    c.is_ascii_digit() || c == '#' || c == '*'
+}
+
+/// `true` when a keycap mark right after `before` completes a keycap sequence
+#[inline(always)]
+fn ends_with_keycap_base(before: &str) -> bool {
+    before
+        .strip_suffix(VS16)
+        .unwrap_or(before)
+        .ends_with(is_keycap_base)
+}
+
+/// Reduces emoji keycap sequences to their bare key.
+///
+/// A keycap emoji is a digit, `#` or `*`, an optional VARIATION SELECTOR-16
+/// and U+20E3 COMBINING ENCLOSING KEYCAP. Chats and social posts use them
+/// for numbers (`1️⃣2️⃣3️⃣`, `#️⃣tag`), and a search for the digits should
+/// still match:
+///
+/// - `1️⃣2️⃣` → `12`
+/// - `#️⃣` → `#`, `*⃣` → `*`
+///
+/// Only complete sequences change. A keycap mark on any other base, and a
+/// VS16 after a digit without a keycap mark, are left alone.
+///
+/// Zero-copy when no keycap sequences are present.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NormalizeKeycaps;
+
+impl Stage for NormalizeKeycaps {
+    fn name(&self) -> &'static str {
+        "normalize_keycaps"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text
+            .match_indices(KEYCAP)
+            .any(|(i, _)| ends_with_keycap_base(&text[..i])))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(self.static_fused_adapter(text.chars(), ctx));
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for NormalizeKeycaps {
+    type Adapter<'a, I>
+        = NormalizeKeycapsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizeKeycapsAdapter {
+            input: input.peekable(),
+            pending: None,
+        }
+    }
+}
+
+pub struct NormalizeKeycapsAdapter<I: Iterator<Item = char>> {
+    input: Peekable<I>,
+    /// A VS16 consumed while looking for a keycap mark that did not follow
+    pending: Option<char>,
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeKeycapsAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.pending.take() {
+            return Some(c);
+        }
+        let c = self.input.next()?;
+        if !is_keycap_base(c) {
+            return Some(c);
+        }
+        if self.input.next_if_eq(&VS16).is_some() && self.input.peek() != Some(&KEYCAP) {
+            self.pending = Some(VS16);
+        } else {
+            self.input.next_if_eq(&KEYCAP);
+        }
+        Some(c)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.input.size_hint();
+        let pending = usize::from(self.pending.is_some());
+        // A sequence shrinks to a third at most; never grows
+        (
+            lower.div_ceil(3) + pending,
+            upper.and_then(|u| u.checked_add(pending)),
+        )
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeKeycapsAdapter<I> {}
+
+impl StageTestConfig for NormalizeKeycaps {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "1\u{FE0F}\u{20E3}2\u{FE0F}\u{20E3}",
+            "#\u{FE0F}\u{20E3}tag *\u{20E3}",
+            "room 4\u{20E3}0\u{FE0F}\u{20E3}",
+            "call 1\u{FE0F}\u{20E3}2\u{FE0F}\u{20E3} now",
+            "a\u{20E3} \u{20E3}",
+            "7\u{FE0F} plain",
+            "plain 123",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "plain 123",
+            "a\u{20E3}",
+            "7\u{FE0F}",
+            "\u{20E3}",
+            "hello world",
+            "",
+        ]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("1\u{FE0F}\u{20E3}2\u{FE0F}\u{20E3}", "12"),
+            ("3\u{20E3}", "3"),
+            ("#\u{FE0F}\u{20E3}tag", "#tag"),
+            ("*\u{20E3}", "*"),
+            ("9\u{FE0F}9\u{FE0F}\u{20E3}", "9\u{FE0F}9"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeKeycaps);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keycap_digits_become_plain_digits() {
+        let stage = NormalizeKeycaps;
+        let ctx = Context::default();
+        let input = "call 1\u{FE0F}\u{20E3}2\u{FE0F}\u{20E3} now";
+
+        let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "call 12 now");
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{CharMapper, Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+    unicode::{letterlike_expansion, letterlike_to_letter},
+};
+use smallvec::SmallVec;
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Normalizes the Letterlike Symbols block (U+2100–214F).
+///
+/// Styled letters always fold to the plain letter: `ℝ` → `R`, `ℓ` → `l`,
+/// `ℌ` → `H`, `ⅆ` → `d`. The Ohm, Kelvin and Angstrom signs become `Ω`, `K`
+/// and `Å`.
+///
+/// With `expand`, symbols that abbreviate a word are also spelled out:
+///
+/// - `№` → `No.`, `℡` → `TEL`, `℻` → `FAX`
+/// - `℅` → `c/o`, `℀` → `a/c`, `℁` → `a/s`, `℆` → `c/u`
+/// - `™` → `TM`, `℠` → `SM`
+/// - `℃` → `°C`, `℉` → `°F`
+///
+/// Without it those symbols are kept as they are, and the stage is a 1:1
+/// mapping. Other symbols of the block (`℗`, `℮`, `⅍`, …) are always
+/// preserved.
+///
+/// Common presets:
+/// - `NORMALIZE_LETTERLIKE_SYMBOLS`: fold letters and expand symbols (default)
+/// - `FOLD_LETTERLIKE_LETTERS`: fold letters only
+///
+/// Zero-copy when no letterlike symbols are present; the fused adapter
+/// buffers multi-char expansions.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizeLetterlikeSymbols {
+    pub expand: bool,
+}
+
+/// Fold styled letters and spell out abbreviation symbols.
+pub const NORMALIZE_LETTERLIKE_SYMBOLS: NormalizeLetterlikeSymbols =
+    NormalizeLetterlikeSymbols { expand: true };
+
+/// Fold styled letters, leaving `№`, `™` and the other symbols unchanged.
+pub const FOLD_LETTERLIKE_LETTERS: NormalizeLetterlikeSymbols =
+    NormalizeLetterlikeSymbols { expand: false };
+
+impl Default for NormalizeLetterlikeSymbols {
+    fn default() -> Self {
+        NORMALIZE_LETTERLIKE_SYMBOLS
+    }
+}
+
+impl NormalizeLetterlikeSymbols {
+    #[inline(always)]
+    fn expansion(&self, c: char) -> Option<&'static str> {
+        if self.expand {
+            letterlike_expansion(c)
+        } else {
+            None
+        }
+    }
+}
+
+impl Stage for NormalizeLetterlikeSymbols {
+    fn name(&self) -> &'static str {
+        "normalize_letterlike_symbols"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text
+            .chars()
+            .any(|c| letterlike_to_letter(c).is_some() || self.expansion(c).is_some()))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(self.static_fused_adapter(text.chars(), ctx));
+        Ok(Cow::Owned(out))
+    }
+
+    fn as_char_mapper(&self, _ctx: &Context) -> Option<&dyn CharMapper> {
+        if self.expand { None } else { Some(self) }
+    }
+
+    fn options(&self) -> String {
+        format!("expand={}", self.expand)
+    }
+}
+
+impl CharMapper for NormalizeLetterlikeSymbols {
+    #[inline(always)]
+    fn map_char(&self, c: char, _ctx: &Context) -> char {
+        letterlike_to_letter(c).unwrap_or(c)
+    }
+}
+
+impl StaticFusableStage for NormalizeLetterlikeSymbols {
+    type Adapter<'a, I>
+        = NormalizeLetterlikeSymbolsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizeLetterlikeSymbolsAdapter {
+            input,
+            stage: *self,
+            pending: SmallVec::new(),
+        }
+    }
+}
+
+pub struct NormalizeLetterlikeSymbolsAdapter<I> {
+    input: I,
+    stage: NormalizeLetterlikeSymbols,
+    /// Remaining chars of the current expansion, stored in reverse
+    pending: SmallVec<[char; 8]>,
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeLetterlikeSymbolsAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.pending.pop() {
+            return Some(c);
+        }
+        let c = self.input.next()?;
+        if let Some(to) = self.stage.expansion(c) {
+            self.pending.extend(to.chars().rev());
+            return self.pending.pop();
+        }
+        Some(letterlike_to_letter(c).unwrap_or(c))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, _) = self.input.size_hint();
+        (lower + self.pending.len(), None) // Expansions can grow the text
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeLetterlikeSymbolsAdapter<I> {}
+
+impl StageTestConfig for NormalizeLetterlikeSymbols {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "Invoice № 42",
+            "Room № 12, ℍ-wing",
+            "ACME™ c/o ℅ Jane",
+            "x ∈ ℝ, n ∈ ℕ, 5 ℓ",
+            "18 ℃ = 64.4 ℉",
+            "\u{2126} \u{212A} \u{212B}",
+            "℗ ℮ ⅍",
+            "plain No. 5",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["plain No. 5", "℗ ℮", "°C", "hello world", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("№", "No."),
+            ("№ 7", "No. 7"),
+            ("℅", "c/o"),
+            ("℀", "a/c"),
+            ("™", "TM"),
+            ("ℝ", "R"),
+            ("5 ℓ", "5 l"),
+            ("\u{212A}", "K"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+    use crate::testing::stage_contract::fused_path_equivalent_to_apply;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeLetterlikeSymbols::default());
+        // Shares the samples; only the expected transforms differ
+        fused_path_equivalent_to_apply(FOLD_LETTERLIKE_LETTERS);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numero_sign_expands_only_in_expand_mode() {
+        let ctx = Context::default();
+        let input = "Room № 12, ℍ-wing";
+
+        let expanded = NORMALIZE_LETTERLIKE_SYMBOLS
+            .apply(Cow::Borrowed(input), &ctx)
+            .unwrap();
+        assert_eq!(expanded, "Room No. 12, H-wing");
+
+        let folded = FOLD_LETTERLIKE_LETTERS
+            .apply(Cow::Borrowed(input), &ctx)
+            .unwrap();
+        assert_eq!(folded, "Room № 12, H-wing");
+        assert!(!FOLD_LETTERLIKE_LETTERS.needs_apply("№ ™ ℅", &ctx).unwrap());
+        assert!(FOLD_LETTERLIKE_LETTERS.as_char_mapper(&ctx).is_some());
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+    unicode::fold_modifier_letter,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Folds Spacing Modifier Letters (U+02B0–02FF) to ASCII for search.
+///
+/// Phonetic and transliterated text uses these where plain ASCII is typed:
+///
+/// | Input                          | Output         |
+/// |--------------------------------|----------------|
+/// | `ʹ` `ʻ` `ʼ` `ʽ` `ʾ` `ʿ` `ˊ`    | `'`            |
+/// | `ʺ` `˝`                        | `"`            |
+/// | `ˋ` `ˆ` `˜`                    | `` ` `` `^` `~`|
+/// | `ː` (length mark)              | `:`            |
+/// | `ʰ` `ʲ` `ʳ` `ʷ` `ʸ` `ˡ` `ˢ` `ˣ` | base letter    |
+/// | `ˈ` `ˌ` `ˑ` (stress, half-long)| removed        |
+///
+/// So `Hawaiʻi` matches `Hawai'i` and `ˈtʰɔːt` becomes `thɔ:t`. IPA letters
+/// outside this block (`ɔ`, `ʃ`, …) and tone letters are left alone.
+///
+/// `NormalizePunctuation` does not touch this block, so the two stages can be
+/// combined without overlap.
+///
+/// Zero-copy when no handled modifier letters are present.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NormalizeModifierLetters;
+
+#[inline(always)]
+fn map_modifier_letter(c: char) -> Option<char> {
+    fold_modifier_letter(c).unwrap_or(Some(c))
+}
+
+impl Stage for NormalizeModifierLetters {
+    fn name(&self) -> &'static str {
+        "normalize_modifier_letters"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(|c| fold_modifier_letter(c).is_some()))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(text.chars().filter_map(map_modifier_letter));
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for NormalizeModifierLetters {
+    type Adapter<'a, I>
+        = NormalizeModifierLettersAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizeModifierLettersAdapter { input }
+    }
+}
+
+pub struct NormalizeModifierLettersAdapter<I> {
+    input: I,
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeModifierLettersAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.by_ref().find_map(map_modifier_letter)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.input.size_hint().1)
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeModifierLettersAdapter<I> {}
+
+impl StageTestConfig for NormalizeModifierLetters {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "Hawaiʻi",
+            "ˈtʰɔːt",
+            "Qurʾān ʿarabī",
+            "tsʼa ˌsekənˈdɛri",
+            "ˌɪntəˈnæʃənəl tsʼa bʲe",
+            "clean text",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["clean text", "ʃɪp θɪŋ", "Hawai'i", "日本語", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("Hawaiʻi", "Hawai'i"),
+            ("ʹxʺ", "'x\""),
+            ("kʷʰ", "kwh"),
+            ("aːˑ", "a:"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeModifierLetters);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ENG;
+
+    #[test]
+    fn folds_ipa_transcription() {
+        let ctx = Context::new(ENG);
+        let cases = [
+            ("ˈtʰɔːt", "thɔ:t"),
+            ("ˌɪntəˈnæʃənəl", "ɪntənæʃənəl"),
+            ("Qurʾān ʿarabī", "Qur'ān 'arabī"),
+            ("tsʼa bʲe", "ts'a bje"),
+        ];
+
+        for (input, expected) in cases {
+            let out = NormalizeModifierLetters.apply(Cow::Borrowed(input), &ctx);
+            assert_eq!(out.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn leaves_other_ipa_and_tone_letters_alone() {
+        let ctx = Context::new(ENG);
+        // ʃ θ ŋ are IPA Extensions; ˥ ˩ are tone letters in this block
+        assert!(
+            !NormalizeModifierLetters
+                .needs_apply("ʃɪp θɪŋ ma˥˩", &ctx)
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{
+    MON,
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+    unicode::is_mongolian_fvs,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// MONGOLIAN VOWEL SEPARATOR
+const MVS: char = '\u{180E}';
+
+/// Removes Mongolian shaping controls that are noise for search.
+///
+/// - Free variation selectors FVS1–FVS4 (U+180B–180D, U+180F) only pick a
+///   glyph variant of the preceding letter.
+/// - The vowel separator MVS (U+180E) only selects the detached final form of
+///   a following `a`/`e`; the letters themselves are unchanged.
+///
+/// Stripping both makes visually different spellings of the same word match,
+/// e.g. `ᠭ᠋ᠠ` and `ᠭᠠ`. Mongolian letters and punctuation are preserved.
+///
+/// Zero-copy when no Mongolian controls are present. Fully fusable filter.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NormalizeMongolian;
+
+#[inline(always)]
+fn is_mongolian_control(c: char) -> bool {
+    c == MVS || is_mongolian_fvs(c)
+}
+
+impl Stage for NormalizeMongolian {
+    fn name(&self) -> &'static str {
+        "normalize_mongolian"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(is_mongolian_control))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(text.chars().filter(|&c| !is_mongolian_control(c)));
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for NormalizeMongolian {
+    type Adapter<'a, I>
+        = NormalizeMongolianAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizeMongolianAdapter { input }
+    }
+}
+
+pub struct NormalizeMongolianAdapter<I> {
+    input: I,
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeMongolianAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.find(|&c| !is_mongolian_control(c))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.input.size_hint();
+        (0, upper) // Can only shrink
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeMongolianAdapter<I> {}
+
+impl StageTestConfig for NormalizeMongolian {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(lang: Lang) -> &'static [&'static str] {
+        match lang {
+            MON => &[
+                "ᠮᠣᠩᠭᠣᠯ ᠪᠢᠴᠢᠭ",
+                "ᠭ\u{180B}ᠠ",
+                "ᠬᠠᠷ\u{180E}ᠠ",
+                "ᠠ\u{180C}ᠠ\u{180D}ᠠ\u{180F}",
+                "",
+            ],
+            _ => &["hello", "ᠭ\u{180B}ᠠ", "テスト", ""],
+        }
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["ᠮᠣᠩᠭᠣᠯ ᠪᠢᠴᠢᠭ", "hello world", "test123", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("ᠭ\u{180B}ᠠ", "ᠭᠠ"),
+            ("ᠬᠠᠷ\u{180E}ᠠ", "ᠬᠠᠷᠠ"),
+            ("ᠠ\u{180C}ᠠ\u{180D}ᠠ\u{180F}", "ᠠᠠᠠ"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeMongolian);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variant_spellings_match_after_stripping() {
+        let ctx = Context::new(MON);
+        let stage = NormalizeMongolian;
+
+        let plain = "ᠮᠣᠩᠭᠣᠯ";
+        let with_fvs = "ᠮᠣ\u{180B}ᠩᠭ\u{180C}ᠣᠯ";
+        assert!(!stage.needs_apply(plain, &ctx).unwrap());
+        assert_eq!(stage.apply(Cow::Borrowed(with_fvs), &ctx).unwrap(), plain);
+    }
+}
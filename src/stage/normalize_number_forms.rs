@@ -0,0 +1,353 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+};
+use smallvec::SmallVec;
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+const ROMAN_UPPER: [&str; 16] = [
+    "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII", "L", "C", "D", "M",
+];
+const ROMAN_LOWER: [&str; 16] = [
+    "i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix", "x", "xi", "xii", "l", "c", "d", "m",
+];
+const ROMAN_VALUES: [&str; 16] = [
+    "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "50", "100", "500", "1000",
+];
+
+/// Vulgar fractions of the block plus the three Latin-1 ones
+#[inline(always)]
+fn fraction(c: char) -> Option<&'static str> {
+    Some(match c {
+        '¼' => "1/4",
+        '½' => "1/2",
+        '¾' => "3/4",
+        '⅐' => "1/7",
+        '⅑' => "1/9",
+        '⅒' => "1/10",
+        '⅓' => "1/3",
+        '⅔' => "2/3",
+        '⅕' => "1/5",
+        '⅖' => "2/5",
+        '⅗' => "3/5",
+        '⅘' => "4/5",
+        '⅙' => "1/6",
+        '⅚' => "5/6",
+        '⅛' => "1/8",
+        '⅜' => "3/8",
+        '⅝' => "5/8",
+        '⅞' => "7/8",
+        '⅟' => "1/",
+        '↉' => "0/3",
+        _ => return None,
+    })
+}
+
+/// Roman numeral `c` as `(ASCII letters, value)`; archaic forms have no letters
+#[inline(always)]
+fn roman(c: char) -> Option<(Option<&'static str>, &'static str)> {
+    let cp = c as usize;
+    Some(match c {
+        'Ⅰ'..='Ⅿ' => (Some(ROMAN_UPPER[cp - 0x2160]), ROMAN_VALUES[cp - 0x2160]),
+        'ⅰ'..='ⅿ' => (Some(ROMAN_LOWER[cp - 0x2170]), ROMAN_VALUES[cp - 0x2170]),
+        'ↀ' => (None, "1000"),
+        'ↁ' => (None, "5000"),
+        'ↂ' => (None, "10000"),
+        'ↅ' => (None, "6"),
+        'ↆ' => (None, "50"),
+        'ↇ' => (None, "50000"),
+        'ↈ' => (None, "100000"),
+        _ => return None,
+    })
+}
+
+/// How one family of number forms is rewritten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberFormMode {
+    /// Leave the characters as they are
+    Keep,
+    /// Spell out in ASCII: `½` → `1/2`, `Ⅻ` → `XII`, `ⅻ` → `xii`
+    Ascii,
+    /// Write the numeric value in ASCII digits: `Ⅻ` → `12`, `ↁ` → `5000`.
+    /// A fraction's value is already written in digits, so fractions become
+    /// `1/2` exactly as with `Ascii`.
+    Value,
+}
+
+/// Normalizes the Number Forms block (U+2150–218F): vulgar fractions and
+/// Roman numerals.
+///
+/// | Input  | `NORMALIZE_NUMBER_FORMS` | `NUMBER_FORMS_TO_VALUES` |
+/// |--------|--------------------------|--------------------------|
+/// | `½`    | `1/2`                    | `1/2`                    |
+/// | `1½`   | `1 1/2`                  | `1 1/2`                  |
+/// | `Ⅻ`    | `XII`                    | `12`                     |
+/// | `ⅳ`    | `iv`                     | `4`                      |
+/// | `ↈ`    | `ↈ`                      | `100000`                 |
+///
+/// Fractions and Roman numerals are configured separately. The Latin-1
+/// fractions `¼ ½ ¾` are treated like the rest. A space is inserted between
+/// a digit and an expanded fraction, so `1½` reads `1 1/2` and not `11/2`.
+/// The archaic Roman numerals (`ↀ`, `ↁ`, `ↅ`, …) have no ASCII spelling and
+/// only change under `Value`.
+///
+/// Out of scope, and always preserved in every mode: the letters `Ↄ ↄ`
+/// (Claudian antisigma), and the turned digits `↊ ↋` (U+218A/U+218B), which
+/// are the dozenal digits ten and eleven. A dozenal digit has no decimal
+/// spelling on its own – in `1↊` it counts 12 + 10 – so writing `10` or `11`
+/// in its place would change the number.
+///
+/// Common presets:
+/// - `NORMALIZE_NUMBER_FORMS`: both families to ASCII (default)
+/// - `NUMBER_FORMS_TO_VALUES`: both families to their numeric values
+///
+/// Zero-copy when no number forms are present; the fused adapter buffers
+/// multi-char expansions.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizeNumberForms {
+    pub fractions: NumberFormMode,
+    pub roman: NumberFormMode,
+}
+
+/// Spell fractions and Roman numerals out in ASCII.
+pub const NORMALIZE_NUMBER_FORMS: NormalizeNumberForms = NormalizeNumberForms {
+    fractions: NumberFormMode::Ascii,
+    roman: NumberFormMode::Ascii,
+};
+
+/// Replace fractions and Roman numerals with their values in ASCII digits.
+pub const NUMBER_FORMS_TO_VALUES: NormalizeNumberForms = NormalizeNumberForms {
+    fractions: NumberFormMode::Value,
+    roman: NumberFormMode::Value,
+};
+
+impl Default for NormalizeNumberForms {
+    fn default() -> Self {
+        NORMALIZE_NUMBER_FORMS
+    }
+}
+
+impl NormalizeNumberForms {
+    /// The replacement for `c`, and whether it is a fraction
+    #[inline(always)]
+    fn expansion(&self, c: char) -> Option<(&'static str, bool)> {
+        if c.is_ascii() {
+            return None;
+        }
+        if self.fractions != NumberFormMode::Keep
+            && let Some(to) = fraction(c)
+        {
+            return Some((to, true));
+        }
+        let (letters, value) = roman(c)?;
+        match self.roman {
+            NumberFormMode::Keep => None,
+            NumberFormMode::Ascii => letters.map(|to| (to, false)),
+            NumberFormMode::Value => Some((value, false)),
+        }
+    }
+}
+
+impl Stage for NormalizeNumberForms {
+    fn name(&self) -> &'static str {
+        "normalize_number_forms"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(|c| self.expansion(c).is_some()))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len() + 8);
+        out.extend(self.static_fused_adapter(text.chars(), ctx));
+        Ok(Cow::Owned(out))
+    }
+
+    fn options(&self) -> String {
+        let mode = |m| match m {
+            NumberFormMode::Keep => "keep",
+            NumberFormMode::Ascii => "ascii",
+            NumberFormMode::Value => "value",
+        };
+        format!(
+            "fractions={},roman={}",
+            mode(self.fractions),
+            mode(self.roman)
+        )
+    }
+}
+
+impl StaticFusableStage for NormalizeNumberForms {
+    type Adapter<'a, I>
+        = NormalizeNumberFormsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizeNumberFormsAdapter {
+            input,
+            stage: *self,
+            prev_digit: false,
+            pending: SmallVec::new(),
+        }
+    }
+}
+
+pub struct NormalizeNumberFormsAdapter<I> {
+    input: I,
+    stage: NormalizeNumberForms,
+    /// Last char emitted was an ASCII digit
+    prev_digit: bool,
+    /// Remaining chars of the current expansion, stored in reverse
+    pending: SmallVec<[char; 8]>,
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeNumberFormsAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = match self.pending.pop() {
+            Some(c) => c,
+            None => {
+                let c = self.input.next()?;
+                if let Some((to, is_fraction)) = self.stage.expansion(c) {
+                    self.pending.extend(to.chars().rev());
+                    if is_fraction && self.prev_digit {
+                        self.pending.push(' ');
+                    }
+                    self.pending.pop().unwrap_or(c)
+                } else {
+                    c
+                }
+            }
+        };
+        self.prev_digit = c.is_ascii_digit();
+        Some(c)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, _) = self.input.size_hint();
+        (lower + self.pending.len(), None) // Expansions can grow the text
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeNumberFormsAdapter<I> {}
+
+impl StageTestConfig for NormalizeNumberForms {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "½ cup",
+            "1½ cups and 2¾",
+            "⅐⅑⅒⅓⅔⅕⅖⅗⅘⅙⅚⅛⅜⅝⅞⅟↉",
+            "Chapter Ⅻ, page ⅳ",
+            "Ⅻ. Add 1½ cups, then ¼ more (step ⅳ)",
+            "Ⅻ ½ ↈ",
+            "ⅯⅯⅩⅩⅣ",
+            "ↀↁↂↅↆↇↈ",
+            "Ↄↄ↊↋",
+            "plain 1/2",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["plain 1/2", "XII", "ↁ", "Ↄↄ↊↋", "hello world", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("½", "1/2"),
+            ("1½", "1 1/2"),
+            ("⅞", "7/8"),
+            ("Ⅻ", "XII"),
+            ("ⅳ", "iv"),
+            ("ⅯⅮ", "MD"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+    use crate::testing::stage_contract::fused_path_equivalent_to_apply;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeNumberForms::default());
+        // Shares the samples; only the expected transforms differ
+        fused_path_equivalent_to_apply(NUMBER_FORMS_TO_VALUES);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(stage: NormalizeNumberForms, text: &str) -> String {
+        let ctx = Context::default();
+        stage.apply(Cow::Borrowed(text), &ctx).unwrap().into_owned()
+    }
+
+    #[test]
+    fn fractions_and_roman_numerals_in_one_input() {
+        let input = "Ⅻ. Add 1½ cups, then ¼ more (step ⅳ)";
+        assert_eq!(
+            run(NORMALIZE_NUMBER_FORMS, input),
+            "XII. Add 1 1/2 cups, then 1/4 more (step iv)"
+        );
+        assert_eq!(
+            run(NUMBER_FORMS_TO_VALUES, input),
+            "12. Add 1 1/2 cups, then 1/4 more (step 4)"
+        );
+    }
+
+    #[test]
+    fn families_are_configured_independently() {
+        let fractions_only = NormalizeNumberForms {
+            fractions: NumberFormMode::Ascii,
+            roman: NumberFormMode::Keep,
+        };
+        assert_eq!(run(fractions_only, "Ⅻ ½"), "Ⅻ 1/2");
+        assert!(
+            !fractions_only
+                .needs_apply("Ⅻ ↁ", &Context::default())
+                .unwrap()
+        );
+
+        let roman_values = NormalizeNumberForms {
+            fractions: NumberFormMode::Keep,
+            roman: NumberFormMode::Value,
+        };
+        assert_eq!(run(roman_values, "Ⅻ ½ ↈ"), "12 ½ 100000");
+
+        // Dozenal digits keep their place value only as digits, so no mode maps them
+        for stage in [NORMALIZE_NUMBER_FORMS, NUMBER_FORMS_TO_VALUES] {
+            assert!(!stage.needs_apply("1↊ ↋", &Context::default()).unwrap());
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{
+    all_langs,
+    context::Context,
+    lang::Lang,
+    stage::{CharMapper, Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+    unicode::phonetic_extension_to_ascii,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Folds Phonetic Extensions (U+1D00–1D7F) to ASCII letters for search.
+///
+/// Dictionaries and phonetic transcriptions use small capitals and modifier
+/// letters from this block where a reader sees plain letters:
+///
+/// - `ᴀ` → `a`, `ᴛ` → `t`, `ᴢ` → `z` (small capitals)
+/// - `ᴬ` → `A`, `ᵇ` → `b`, `ᵢ` → `i` (modifier and subscript letters)
+/// - `ᵬ` → `b`, `ᵽ` → `p`, `ᴉ` → `i` (tilde, stroke, turned forms)
+///
+/// Only letters with a clear Latin base are folded. Digraphs (`ᴁ`, `ᵫ`),
+/// IPA symbols (`ᴈ`, `ᵊ`) and the Greek and Cyrillic small capitals are
+/// preserved.
+///
+/// 1:1 mapping → zero-copy when no foldable phonetic extensions are present.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NormalizePhoneticExtensions;
+
+#[inline(always)]
+fn fold(c: char) -> char {
+    phonetic_extension_to_ascii(c).unwrap_or(c)
+}
+
+impl Stage for NormalizePhoneticExtensions {
+    fn name(&self) -> &'static str {
+        "normalize_phonetic_extensions"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text
+            .chars()
+            .any(|c| phonetic_extension_to_ascii(c).is_some()))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(text.chars().map(fold));
+        Ok(Cow::Owned(out))
+    }
+
+    fn as_char_mapper(&self, _ctx: &Context) -> Option<&dyn CharMapper> {
+        Some(self)
+    }
+}
+
+impl CharMapper for NormalizePhoneticExtensions {
+    #[inline(always)]
+    fn map_char(&self, c: char, _ctx: &Context) -> char {
+        fold(c)
+    }
+}
+
+impl StaticFusableStage for NormalizePhoneticExtensions {
+    type Adapter<'a, I>
+        = NormalizePhoneticExtensionsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizePhoneticExtensionsAdapter { input }
+    }
+}
+
+pub struct NormalizePhoneticExtensionsAdapter<I> {
+    input: I,
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizePhoneticExtensionsAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.next().map(fold)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.input.size_hint()
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizePhoneticExtensionsAdapter<I> {}
+
+impl StageTestConfig for NormalizePhoneticExtensions {
+    fn one_to_one_languages() -> &'static [Lang] {
+        all_langs()
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "ᴀʙᴄᴅᴇ ᴛᴇxᴛ",
+            "ᴅᴏᴍᴀɪɴ ᴏꜰ ᴜsᴇ, ʙ",
+            "ᴬᴮᴰᴱ ᵃᵇᵈᵉ",
+            "ᵢᵣᵤᵥ",
+            "ᵬᵭᵮᵯᵰᵱᵲᵴᵵᵶ ᵽᵾ",
+            "ᴁ ᵫ ᴈ ᵊ ᴦ ᴫ",
+            "plain text",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["plain text", "ᴁ", "ᵫ", "ᵊ", "ᴦ", "hello world", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("ᴀ", "a"),
+            ("ᴛᴇᴋ", "tek"),
+            ("ᴬᴮ", "AB"),
+            ("xᵢ", "xi"),
+            ("ᵽ", "p"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizePhoneticExtensions);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ENG;
+
+    #[test]
+    fn small_capitals_fold_to_ascii() {
+        let ctx = Context::new(ENG);
+        let stage = NormalizePhoneticExtensions;
+
+        // ɪ ɴ ʙ are IPA Extensions and ꜰ is Latin Extended-D: all stay
+        let input = "ᴅᴏᴍᴀɪɴ ᴏꜰ ᴜsᴇ, ʙ";
+        let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "domaɪɴ oꜰ use, ʙ");
+    }
+}
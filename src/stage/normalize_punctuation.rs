@@ -2,7 +2,7 @@ use crate::{
     all_langs,
     context::Context,
     lang::Lang,
-    stage::{Stage, StageError, StaticFusableStage},
+    stage::{CharMapper, Stage, StageError, StaticFusableStage},
     testing::stage_contract::StageTestConfig,
     unicode::normalize_punctuation_char,
 };
@@ -51,6 +51,17 @@ impl Stage for NormalizePunctuation {
 
         Ok(Cow::Owned(out))
     }
+
+    fn as_char_mapper(&self, _ctx: &Context) -> Option<&dyn CharMapper> {
+        Some(self)
+    }
+}
+
+impl CharMapper for NormalizePunctuation {
+    #[inline(always)]
+    fn map_char(&self, c: char, _ctx: &Context) -> char {
+        normalize_punctuation_char(c)
+    }
 }
 
 impl StaticFusableStage for NormalizePunctuation {
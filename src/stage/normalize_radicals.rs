@@ -0,0 +1,175 @@
+use crate::{
+    all_langs,
+    context::Context,
+    lang::Lang,
+    stage::{CharMapper, Stage, StageError, StaticFusableStage, normalization::ICU4X_NFKC},
+    testing::stage_contract::StageTestConfig,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Folds Kangxi radicals (U+2F00–2FD5) to the unified ideographs they depict.
+///
+/// The radicals render like ideographs but are separate code points, so text
+/// copied from dictionaries and PDF extractions misses in search: `⽊` is
+/// not `木`. Each Kangxi radical has exactly one unified ideograph as its NFKC
+/// form, and the mapping is applied to these characters only:
+///
+/// - `⽊` → `木`, `⽔` → `水`, `⾦` → `金`, `⿕` → `龠`
+/// - `⺟` → `母`, `⻳` → `龟` (the two CJK Radicals Supplement forms with a
+///   compatibility mapping)
+///
+/// The other supplement forms (`⺅`, `⺡`, …) are radical variants with no
+/// standalone ideograph in NFKC and are preserved, like real ideographs.
+///
+/// 1:1 mapping → zero-copy when no radicals are present.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NormalizeRadicals;
+
+#[inline(always)]
+fn is_radical(c: char) -> bool {
+    matches!(c, '\u{2F00}'..='\u{2FD5}' | '\u{2E9F}' | '\u{2EF3}')
+}
+
+#[inline(always)]
+fn ideograph(c: char) -> char {
+    if !is_radical(c) {
+        return c;
+    }
+    ICU4X_NFKC
+        .normalize_iter(std::iter::once(c))
+        .next()
+        .unwrap_or(c)
+}
+
+impl Stage for NormalizeRadicals {
+    fn name(&self) -> &'static str {
+        "normalize_radicals"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(is_radical))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(text.chars().map(ideograph));
+        Ok(Cow::Owned(out))
+    }
+
+    fn as_char_mapper(&self, _ctx: &Context) -> Option<&dyn CharMapper> {
+        Some(self)
+    }
+}
+
+impl CharMapper for NormalizeRadicals {
+    #[inline(always)]
+    fn map_char(&self, c: char, _ctx: &Context) -> char {
+        ideograph(c)
+    }
+}
+
+impl StaticFusableStage for NormalizeRadicals {
+    type Adapter<'a, I>
+        = NormalizeRadicalsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizeRadicalsAdapter { input }
+    }
+}
+
+pub struct NormalizeRadicalsAdapter<I> {
+    input: I,
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeRadicalsAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.next().map(ideograph)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.input.size_hint()
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeRadicalsAdapter<I> {}
+
+impl StageTestConfig for NormalizeRadicals {
+    fn one_to_one_languages() -> &'static [Lang] {
+        all_langs()
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "⽊⽕⼟⾦⽔",
+            "⼀⼆⼈⼤⼥⼦",
+            "⿕ ⺟ ⻳",
+            "⽊曜⽇ 木曜日",
+            "⺅⺡ 木林",
+            "日本語テキスト",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["木林森", "⺅⺡", "日本語", "hello world", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("⽊", "木"),
+            ("⽔⽕", "水火"),
+            ("⾦曜日", "金曜日"),
+            ("⺟", "母"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeRadicals);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::JPN;
+
+    #[test]
+    fn kangxi_radicals_become_ideographs() {
+        let ctx = Context::new(JPN);
+        let stage = NormalizeRadicals;
+
+        // Radicals ⽊ ⽇ mixed with the real ideographs 木 日
+        let input = "⽊曜⽇ 木曜日";
+        let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "木曜日 木曜日");
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{
+        Stage, StageError, StaticFusableStage,
+        normalization::{ExpandCharsAdapter, ICU4X_NFKC},
+    },
+    testing::stage_contract::StageTestConfig,
+};
+use memchr::memchr_iter;
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Folds the Enclosed Ideographic Supplement (U+1F200–1F2FF) to the
+/// characters it encloses.
+///
+/// Squared and bracketed CJK show up in signage, broadcast captions and as
+/// emoji; for search they should match the plain text:
+///
+/// - `🈂` → `サ`, `🈁` → `ココ`, `🈀` → `ほか` (squared kana)
+/// - `🈚` → `無`, `🈯` → `指`, `🈵` → `満` (squared ideographs)
+/// - `🉀` → `〔本〕`, `🉈` → `〔敗〕` (tortoise-shell bracketed ideographs)
+/// - `🉐` → `得`, `🉑` → `可` (circled ideographs)
+///
+/// Mappings are the NFKC compatibility forms of the block, applied to these
+/// characters only – the rest of the text is untouched. The Chinese folk
+/// religion symbols (`🉠`–`🉥`) have no compatibility form and are preserved.
+///
+/// The whole block is 4-byte UTF-8 led by `F0 9F 88`–`F0 9F 8B`, so the
+/// pre-scan skips text without those lead bytes without decoding it.
+/// Zero-copy when no enclosed ideographs are present; the fused adapter
+/// buffers multi-char expansions.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NormalizeSquaredCjk;
+
+#[inline(always)]
+fn is_enclosed_ideographic_supplement(c: char) -> bool {
+    matches!(c, '\u{1F200}'..='\u{1F2FF}')
+}
+
+/// `true` when `c` is an enclosed ideograph with a compatibility form
+#[inline(always)]
+fn folds(c: char) -> bool {
+    is_enclosed_ideographic_supplement(c) && !ICU4X_NFKC.is_normalized(c.encode_utf8(&mut [0; 4]))
+}
+
+impl Stage for NormalizeSquaredCjk {
+    fn name(&self) -> &'static str {
+        "normalize_squared_cjk"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        let bytes = text.as_bytes();
+        Ok(memchr_iter(0xF0, bytes).any(|i| {
+            matches!(bytes.get(i + 1..i + 3), Some([0x9F, 0x88..=0x8B]))
+                && text[i..].chars().next().is_some_and(folds)
+        }))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        for c in text.chars() {
+            if folds(c) {
+                out.extend(ICU4X_NFKC.normalize_iter(std::iter::once(c)));
+            } else {
+                out.push(c);
+            }
+        }
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for NormalizeSquaredCjk {
+    type Adapter<'a, I>
+        = ExpandCharsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        ExpandCharsAdapter::nfkc(input, folds)
+    }
+}
+
+impl StageTestConfig for NormalizeSquaredCjk {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "🈂 サービス",
+            "🈁🈀",
+            "🈚🈯🈲🈳🈴🈵🈶🈷🈸🈹🈺",
+            "🉀🉁🉂🉃🉄🉅🉆🉇🉈",
+            "🉐🉑",
+            "🉠🉡🉢🉣🉤🉥",
+            "😀 emoji 🈚",
+            "plain 日本語",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["plain 日本語", "😀🎉", "🉠", "〔本〕", "hello world", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("🈂", "サ"),
+            ("🈁", "ココ"),
+            ("🈚", "無"),
+            ("🉀", "〔本〕"),
+            ("🉐", "得"),
+            ("😀🈵", "😀満"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeSquaredCjk);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::JPN;
+
+    #[test]
+    fn squared_signage_folds_to_base_characters() {
+        let stage = NormalizeSquaredCjk;
+        let ctx = Context::new(JPN);
+
+        // Parking sign: free (無料) and vacant (空) squares next to an emoji
+        let input = "🅿️ 🈚料 🈳あり";
+        let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "🅿️ 無料 空あり");
+        assert!(!stage.needs_apply(&out, &ctx).unwrap());
+
+        let fused: String = stage.static_fused_adapter(input.chars(), &ctx).collect();
+        assert_eq!(fused, out);
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    all_langs,
+    context::Context,
+    lang::Lang,
+    stage::{CharMapper, Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+    unicode::super_subscript_to_baseline,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Folds superscript and subscript characters to the baseline.
+///
+/// Covers the Superscripts and Subscripts block (U+2070–209F) and the Latin-1
+/// `¹²³`: digits, signs, parentheses and letters. Chemistry and math text
+/// then matches what people type:
+///
+/// - `Ca²⁺` → `Ca2+`, `SO₄²⁻` → `SO42-`, `H₂O` → `H2O`
+/// - `x⁽ⁿ⁾` → `x(n)`, `a₍₁₎` → `a(1)`
+/// - `ₔ` → `ə`, the one target outside ASCII
+///
+/// Flattening loses the exponent: `10⁶` becomes `106`. Run `NormalizeUnits`
+/// first where numbers matter; it rewrites `10⁶` as `10^6`.
+///
+/// 1:1 mapping → zero-copy when no superscripts or subscripts are present.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NormalizeSuperscripts;
+
+#[inline(always)]
+fn baseline(c: char) -> char {
+    super_subscript_to_baseline(c).unwrap_or(c)
+}
+
+impl Stage for NormalizeSuperscripts {
+    fn name(&self) -> &'static str {
+        "normalize_superscripts"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text
+            .chars()
+            .any(|c| super_subscript_to_baseline(c).is_some()))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(text.chars().map(baseline));
+        Ok(Cow::Owned(out))
+    }
+
+    fn as_char_mapper(&self, _ctx: &Context) -> Option<&dyn CharMapper> {
+        Some(self)
+    }
+}
+
+impl CharMapper for NormalizeSuperscripts {
+    #[inline(always)]
+    fn map_char(&self, c: char, _ctx: &Context) -> char {
+        baseline(c)
+    }
+}
+
+impl StaticFusableStage for NormalizeSuperscripts {
+    type Adapter<'a, I>
+        = NormalizeSuperscriptsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizeSuperscriptsAdapter { input }
+    }
+}
+
+pub struct NormalizeSuperscriptsAdapter<I> {
+    input: I,
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeSuperscriptsAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.next().map(baseline)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.input.size_hint()
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeSuperscriptsAdapter<I> {}
+
+impl StageTestConfig for NormalizeSuperscripts {
+    fn one_to_one_languages() -> &'static [Lang] {
+        all_langs()
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "Ca²⁺ and SO₄²⁻",
+            "H₂O",
+            "x⁽ⁿ⁾ = a₍₁₎",
+            "Ca²⁺ + CO₃²⁻ → CaCO₃",
+            "⁰¹²³⁴⁵⁶⁷⁸⁹⁺⁻⁼⁽⁾ⁱⁿ",
+            "₀₁₂₃₄₅₆₇₈₉₊₋₌₍₎ₐₑₒₓₔₕₖₗₘₙₚₛₜ",
+            "plain 2+2",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["plain 2+2", "Ca2+", "ʰ", "hello world", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("Ca²⁺", "Ca2+"),
+            ("H₂O", "H2O"),
+            ("x⁽²⁾", "x(2)"),
+            ("eⁱⁿ", "ein"),
+            ("₍ₔ₎", "(ə)"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeSuperscripts);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ENG;
+
+    #[test]
+    fn ion_charges_and_formulas_reach_the_baseline() {
+        let ctx = Context::new(ENG);
+        let stage = NormalizeSuperscripts;
+
+        let input = "Ca²⁺ + CO₃²⁻ → CaCO₃";
+        let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "Ca2+ + CO32- → CaCO3");
+    }
+}
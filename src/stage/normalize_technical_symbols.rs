@@ -0,0 +1,257 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+};
+use smallvec::SmallVec;
+use std::borrow::Cow;
+use std::iter::{FusedIterator, Peekable};
+
+/// Key name of a Miscellaneous Technical (U+2300–23FF) keyboard or UI symbol
+#[inline(always)]
+fn key_name(c: char) -> Option<&'static str> {
+    Some(match c {
+        '\u{2303}' => "Ctrl",      // ⌃ UP ARROWHEAD (Control key)
+        '\u{2318}' => "Cmd",       // ⌘ PLACE OF INTEREST SIGN (Command key)
+        '\u{2324}' => "Enter",     // ⌤ UP ARROWHEAD BETWEEN TWO HORIZONTAL BARS
+        '\u{2325}' => "Option",    // ⌥ OPTION KEY
+        '\u{2326}' => "Delete",    // ⌦ ERASE TO THE RIGHT
+        '\u{2327}' => "Clear",     // ⌧ X IN A RECTANGLE BOX
+        '\u{2328}' => "Keyboard",  // ⌨ KEYBOARD
+        '\u{232B}' => "Backspace", // ⌫ ERASE TO THE LEFT
+        '\u{2380}' => "Insert",    // ⎀ INSERTION SYMBOL
+        '\u{2387}' => "Alt",       // ⎇ ALTERNATIVE KEY SYMBOL
+        '\u{238B}' => "Esc",       // ⎋ BROKEN CIRCLE WITH NORTHWEST ARROW
+        '\u{23CE}' => "Return",    // ⏎ RETURN SYMBOL
+        '\u{23CF}' => "Eject",     // ⏏ EJECT SYMBOL
+        '\u{23FB}'..='\u{23FE}' => "Power",
+        _ => return None,
+    })
+}
+
+/// What happens to a keyboard or UI symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TechnicalSymbolMode {
+    /// Drop the symbol
+    Remove,
+    /// Write the key it stands for: `⌘` → `Cmd`, `⏎` → `Return`
+    Name,
+}
+
+/// Normalizes the keyboard and UI symbols of the Miscellaneous Technical
+/// block (U+2300–23FF) that scraped interface text is full of.
+///
+/// | Input          | `STRIP_TECHNICAL_SYMBOLS` | `NAME_TECHNICAL_SYMBOLS` |
+/// |----------------|---------------------------|--------------------------|
+/// | `⌘C to copy`   | `C to copy`               | `Cmd C to copy`          |
+/// | `Press ⏎`      | `Press `                  | `Press Return`           |
+/// | `⌫ Delete`     | ` Delete`                 | `Backspace Delete`       |
+///
+/// Only an explicit subset is touched: the modifier and editing keys
+/// `⌃ ⌘ ⌥ ⎇ ⌫ ⌦ ⌧ ⎀ ⏎ ⌤ ⎋`, `⏏ ⌨` and the power symbols `⏻ ⏼ ⏽ ⏾`. The
+/// rest of the block – APL symbols, brackets, dentistry and media controls –
+/// is preserved. A key name is set off from adjacent letters and digits by a
+/// space; removal leaves the surrounding spaces as they are, so follow with
+/// a whitespace stage if needed.
+///
+/// Common presets:
+/// - `STRIP_TECHNICAL_SYMBOLS`: remove the symbols (default)
+/// - `NAME_TECHNICAL_SYMBOLS`: replace them with key names
+///
+/// Zero-copy when no such symbols are present; the fused adapter buffers
+/// key names.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizeTechnicalSymbols {
+    pub mode: TechnicalSymbolMode,
+}
+
+/// Remove keyboard and UI symbols.
+pub const STRIP_TECHNICAL_SYMBOLS: NormalizeTechnicalSymbols = NormalizeTechnicalSymbols {
+    mode: TechnicalSymbolMode::Remove,
+};
+
+/// Replace keyboard and UI symbols with the names of their keys.
+pub const NAME_TECHNICAL_SYMBOLS: NormalizeTechnicalSymbols = NormalizeTechnicalSymbols {
+    mode: TechnicalSymbolMode::Name,
+};
+
+impl Default for NormalizeTechnicalSymbols {
+    fn default() -> Self {
+        STRIP_TECHNICAL_SYMBOLS
+    }
+}
+
+impl Stage for NormalizeTechnicalSymbols {
+    fn name(&self) -> &'static str {
+        "normalize_technical_symbols"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(|c| key_name(c).is_some()))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len() + 8);
+        out.extend(self.static_fused_adapter(text.chars(), ctx));
+        Ok(Cow::Owned(out))
+    }
+
+    fn options(&self) -> String {
+        match self.mode {
+            TechnicalSymbolMode::Remove => "mode=remove".to_owned(),
+            TechnicalSymbolMode::Name => "mode=name".to_owned(),
+        }
+    }
+}
+
+impl StaticFusableStage for NormalizeTechnicalSymbols {
+    type Adapter<'a, I>
+        = NormalizeTechnicalSymbolsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizeTechnicalSymbolsAdapter {
+            input: input.peekable(),
+            mode: self.mode,
+            prev_alnum: false,
+            pending: SmallVec::new(),
+        }
+    }
+}
+
+pub struct NormalizeTechnicalSymbolsAdapter<I: Iterator<Item = char>> {
+    input: Peekable<I>,
+    mode: TechnicalSymbolMode,
+    /// Last char emitted was a letter or digit
+    prev_alnum: bool,
+    /// Remaining chars of the current key name, stored in reverse
+    pending: SmallVec<[char; 12]>,
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeTechnicalSymbolsAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = loop {
+            if let Some(c) = self.pending.pop() {
+                break c;
+            }
+            let c = self.input.next()?;
+            let Some(name) = key_name(c) else {
+                break c;
+            };
+            if self.mode == TechnicalSymbolMode::Remove {
+                continue;
+            }
+            if self.input.peek().is_some_and(|n| n.is_alphanumeric()) {
+                self.pending.push(' ');
+            }
+            self.pending.extend(name.chars().rev());
+            if self.prev_alnum {
+                self.pending.push(' ');
+            }
+        };
+        self.prev_alnum = c.is_alphanumeric();
+        Some(c)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, _) = self.input.size_hint();
+        match self.mode {
+            TechnicalSymbolMode::Remove => (self.pending.len(), None),
+            TechnicalSymbolMode::Name => (lower + self.pending.len(), None),
+        }
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeTechnicalSymbolsAdapter<I> {}
+
+impl StageTestConfig for NormalizeTechnicalSymbols {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "⌘C to copy",
+            "Press ⏎ to continue",
+            "⌃⌥⌫ ⌦ ⎋",
+            "⏻ power, ⏏ eject",
+            "⌈x⌉ ⌊y⌋ ⍺",
+            "Save with ⌘S, undo with ⌫, confirm ⏎ ⌈draft⌉",
+            "⌃⌥X",
+            "plain text",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["plain text", "⌈x⌉ ⌊y⌋", "⍺⍵", "⏩ ⏸", "Cmd C", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("⌘C", "C"),
+            ("Press ⏎", "Press "),
+            ("⌫", ""),
+            ("a⌃⌥b", "ab"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+    use crate::testing::stage_contract::fused_path_equivalent_to_apply;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeTechnicalSymbols::default());
+        // Shares the samples; only the expected transforms differ
+        fused_path_equivalent_to_apply(NAME_TECHNICAL_SYMBOLS);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(stage: NormalizeTechnicalSymbols, text: &str) -> String {
+        let ctx = Context::default();
+        stage.apply(Cow::Borrowed(text), &ctx).unwrap().into_owned()
+    }
+
+    #[test]
+    fn key_symbols_are_removed_or_named_in_mixed_text() {
+        let input = "Save with ⌘S, undo with ⌫, confirm ⏎ ⌈draft⌉";
+        assert_eq!(
+            run(STRIP_TECHNICAL_SYMBOLS, input),
+            "Save with S, undo with , confirm  ⌈draft⌉"
+        );
+        assert_eq!(
+            run(NAME_TECHNICAL_SYMBOLS, input),
+            "Save with Cmd S, undo with Backspace, confirm Return ⌈draft⌉"
+        );
+        assert_eq!(run(NAME_TECHNICAL_SYMBOLS, "⌃⌥X"), "Ctrl Option X");
+    }
+}
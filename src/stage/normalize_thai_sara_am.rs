@@ -0,0 +1,284 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+};
+use smallvec::SmallVec;
+use std::borrow::Cow;
+use std::iter::{FusedIterator, Peekable};
+
+/// THAI CHARACTER NIKHAHIT
+const NIKHAHIT: char = '\u{0E4D}';
+/// THAI CHARACTER SARA AA
+const SARA_AA: char = '\u{0E32}';
+/// THAI CHARACTER SARA AM
+const SARA_AM: char = '\u{0E33}';
+
+/// Thai tone marks MAI EK … MAI CHATTAWA (U+0E48–0E4B)
+#[inline(always)]
+fn is_thai_tone_mark(c: char) -> bool {
+    matches!(c, '\u{0E48}'..='\u{0E4B}')
+}
+
+/// Makes Thai Sara Am (`ำ`, U+0E33) spelled one way throughout.
+///
+/// Sara Am has a compatibility decomposition to nikhahit + sara aa (`ํา`), and
+/// both spellings occur in the wild – often with the tone mark typed on either
+/// side of the nikhahit. `น้ำ` (water) is seen as:
+///
+/// | Input           | `COMPOSE_SARA_AM` | `DECOMPOSE_SARA_AM` |
+/// |-----------------|-------------------|---------------------|
+/// | `น` `้` `ำ`     | `น` `้` `ำ`       | `น` `้` `ํ` `า`     |
+/// | `น` `้` `ํ` `า` | `น` `้` `ำ`       | `น` `้` `ํ` `า`     |
+/// | `น` `ํ` `้` `า` | `น` `้` `ำ`       | `น` `้` `ํ` `า`     |
+///
+/// The tone mark always ends up directly after the consonant, which is the
+/// order NFKC produces. A lone nikhahit not followed by sara aa is preserved.
+///
+/// Common presets:
+/// - `COMPOSE_SARA_AM`: fold to the precomposed `ำ` (default)
+/// - `DECOMPOSE_SARA_AM`: fold to `ํา`, matching NFKC output
+///
+/// Zero-copy when no Sara Am variants are present.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizeThaiSaraAm {
+    /// `true` → precomposed `ำ`; `false` → nikhahit + sara aa
+    pub compose: bool,
+}
+
+/// Fold every Sara Am spelling to the precomposed U+0E33.
+pub const COMPOSE_SARA_AM: NormalizeThaiSaraAm = NormalizeThaiSaraAm { compose: true };
+
+/// Fold every Sara Am spelling to U+0E4D U+0E32.
+pub const DECOMPOSE_SARA_AM: NormalizeThaiSaraAm = NormalizeThaiSaraAm { compose: false };
+
+impl Default for NormalizeThaiSaraAm {
+    fn default() -> Self {
+        COMPOSE_SARA_AM
+    }
+}
+
+impl NormalizeThaiSaraAm {
+    fn changes(&self, text: &str) -> bool {
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            if c == SARA_AM && !self.compose {
+                return true;
+            }
+            if c == NIKHAHIT {
+                let mut rest = chars.clone();
+                match rest.next() {
+                    Some(SARA_AA) if self.compose => return true,
+                    Some(t) if is_thai_tone_mark(t) && rest.next() == Some(SARA_AA) => {
+                        return true;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        false
+    }
+}
+
+impl Stage for NormalizeThaiSaraAm {
+    fn name(&self) -> &'static str {
+        "normalize_thai_sara_am"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(self.changes(text))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len() + 8);
+        out.extend(self.static_fused_adapter(text.chars(), ctx));
+        Ok(Cow::Owned(out))
+    }
+
+    fn options(&self) -> String {
+        format!("compose={}", self.compose)
+    }
+}
+
+impl StaticFusableStage for NormalizeThaiSaraAm {
+    type Adapter<'a, I>
+        = NormalizeThaiSaraAmAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizeThaiSaraAmAdapter {
+            input: input.peekable(),
+            compose: self.compose,
+            pending: SmallVec::new(),
+        }
+    }
+}
+
+pub struct NormalizeThaiSaraAmAdapter<I: Iterator<Item = char>> {
+    input: Peekable<I>,
+    compose: bool,
+    /// Chars still to emit, stored in reverse
+    pending: SmallVec<[char; 2]>,
+}
+
+impl<I: Iterator<Item = char>> NormalizeThaiSaraAmAdapter<I> {
+    /// Queue the chosen Sara Am spelling after the char being returned
+    #[inline(always)]
+    fn push_sara_am(&mut self) {
+        if self.compose {
+            self.pending.push(SARA_AM);
+        } else {
+            self.pending.push(SARA_AA);
+            self.pending.push(NIKHAHIT);
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeThaiSaraAmAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.pending.pop() {
+            return Some(c);
+        }
+        match self.input.next()? {
+            SARA_AM if !self.compose => {
+                self.pending.push(SARA_AA);
+                Some(NIKHAHIT)
+            }
+            NIKHAHIT => {
+                if self.compose && self.input.next_if_eq(&SARA_AA).is_some() {
+                    return Some(SARA_AM);
+                }
+                let Some(tone) = self.input.next_if(|&t| is_thai_tone_mark(t)) else {
+                    return Some(NIKHAHIT);
+                };
+                if self.input.next_if_eq(&SARA_AA).is_some() {
+                    // Nikhahit + tone + sara aa: the tone moves in front of the vowel
+                    self.push_sara_am();
+                    Some(tone)
+                } else {
+                    self.pending.push(tone);
+                    Some(NIKHAHIT)
+                }
+            }
+            c => Some(c),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.input.size_hint();
+        // Composing shrinks by half at most; decomposing at most doubles
+        (
+            lower / 2,
+            upper.and_then(|u| u.checked_mul(2)?.checked_add(self.pending.len())),
+        )
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeThaiSaraAmAdapter<I> {}
+
+impl StageTestConfig for NormalizeThaiSaraAm {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "น้ำ",
+            "น\u{0E49}\u{0E4D}\u{0E32}",
+            "น\u{0E4D}\u{0E49}\u{0E32}",
+            "กำลัง ทํางาน",
+            "ก\u{0E4D}\u{0E48}ข \u{0E4D}",
+            "สวัสดี",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["สวัสดี", "hello world", "ภาษาไทย", ""]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(COMPOSE_SARA_AM);
+        assert_stage_contract!(DECOMPOSE_SARA_AM);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::THA;
+
+    // น้ำ (water) as typed: precomposed, tone first, nikhahit first
+    const WATER: [&str; 3] = [
+        "น\u{0E49}\u{0E33}",
+        "น\u{0E49}\u{0E4D}\u{0E32}",
+        "น\u{0E4D}\u{0E49}\u{0E32}",
+    ];
+
+    #[test]
+    fn every_spelling_folds_to_the_same_form() {
+        let ctx = Context::new(THA);
+        let cases = [
+            (COMPOSE_SARA_AM, "น\u{0E49}\u{0E33}"),
+            (DECOMPOSE_SARA_AM, "น\u{0E49}\u{0E4D}\u{0E32}"),
+        ];
+
+        for (stage, expected) in cases {
+            for input in WATER {
+                let out = if stage.needs_apply(input, &ctx).unwrap() {
+                    stage.apply(Cow::Borrowed(input), &ctx).unwrap()
+                } else {
+                    Cow::Borrowed(input)
+                };
+                assert_eq!(out, expected, "{stage:?} on {input:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn decomposed_form_matches_nfkc() {
+        use icu_normalizer::ComposingNormalizer;
+
+        let ctx = Context::new(THA);
+        let nfkc = ComposingNormalizer::new_nfkc();
+        let word = "กำลังทำงาน";
+        let out = DECOMPOSE_SARA_AM.apply(Cow::Borrowed(word), &ctx).unwrap();
+        assert_eq!(out, nfkc.normalize(word));
+    }
+
+    #[test]
+    fn lone_nikhahit_is_preserved() {
+        let ctx = Context::new(THA);
+        for input in ["\u{0E4D}", "ก\u{0E4D}\u{0E48}", "ก\u{0E4D}\u{0E48}ข"] {
+            assert!(!COMPOSE_SARA_AM.needs_apply(input, &ctx).unwrap());
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage, StaticIdentityAdapter},
+    testing::stage_contract::StageTestConfig,
+    unicode::superscript_numeric_to_ascii,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Mass, length, volume, time, frequency, electrical, energy, pressure, data
+/// and temperature symbols recognized after a number. Matching is case-sensitive.
+///
+/// Bare `s`, `A`, `B` and `K` are left out on purpose: after a number they are
+/// more often a suffix or a label (`1990s`, `4K`, `3B`) than a unit.
+static UNITS: &[&str] = &[
+    "kg", "g", "mg", "µg", "μg", "km", "m", "cm", "mm", "µm", "μm", "nm", "l", "L", "ml", "mL",
+    "h", "min", "ms", "µs", "μs", "ns", "Hz", "kHz", "MHz", "GHz", "mA", "V", "mV", "kV", "W",
+    "kW", "MW", "Wh", "kWh", "J", "kJ", "cal", "kcal", "Pa", "hPa", "kPa", "MPa", "bar", "KB",
+    "kB", "MB", "GB", "TB", "KiB", "MiB", "GiB", "TiB", "°C", "°F",
+];
+
+/// Spaces accepted between a number and its unit
+#[inline(always)]
+fn is_unit_space(c: char) -> bool {
+    matches!(c, ' ' | '\u{00A0}' | '\u{2009}' | '\u{202F}')
+}
+
+/// Byte length of the unit symbol at the start of `rest`, if one ends there at
+/// a word boundary. The longest match wins (`mm` over `m`).
+fn unit_len(rest: &str) -> Option<usize> {
+    UNITS
+        .iter()
+        .filter(|unit| rest.starts_with(**unit))
+        .map(|unit| unit.len())
+        .filter(|&len| {
+            // A trailing exponent (`m²`) still ends the symbol
+            rest[len..]
+                .chars()
+                .next()
+                .is_none_or(|c| !c.is_alphanumeric() || superscript_numeric_to_ascii(c).is_some())
+        })
+        .max()
+}
+
+/// Canonicalizes the spacing between a number and its unit, and superscript
+/// exponents after a number.
+///
+/// Technical text spells the same quantity many ways; this stage picks one:
+///
+/// | Input          | `SPACE_UNITS`  | `JOIN_UNITS` |
+/// |----------------|----------------|--------------|
+/// | `5kg`          | `5 kg`         | `5kg`        |
+/// | `5 kg`         | `5 kg`         | `5kg`        |
+/// | `5`NBSP`kg`    | `5 kg`         | `5kg`        |
+/// | `10⁶`          | `10^6`         | `10^6`       |
+/// | `1.5×10⁻³m`    | `1.5×10^-3 m`  | `1.5×10^-3m` |
+///
+/// Any run of ASCII spaces, no-break spaces (U+00A0), thin spaces (U+2009) or
+/// narrow no-break spaces (U+202F) between a digit and a known unit symbol
+/// becomes a single ASCII space, or is removed. Units are matched
+/// case-sensitively against a fixed list of SI and data units and must end at
+/// a word boundary, so `5kgs` and `mp3` are left alone.
+///
+/// Superscript digits and signs directly after a digit become `^` plus ASCII
+/// (`10⁶` → `10^6`). Superscripts elsewhere (`m²`, `x²`) are preserved. Run
+/// this stage before `NormalizeSuperscripts`, which would turn `10⁶` into
+/// `106`.
+///
+/// Common presets:
+/// - `SPACE_UNITS`: one space between number and unit, as SI recommends (default)
+/// - `JOIN_UNITS`: no space between number and unit
+///
+/// Zero-copy when nothing changes. Not fusable: a unit is only recognized after
+/// looking past the spaces to the end of the symbol.
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizeUnits {
+    /// `true` → `5 kg`; `false` → `5kg`
+    pub insert_space: bool,
+}
+
+/// Write exactly one ASCII space between a number and its unit.
+pub const SPACE_UNITS: NormalizeUnits = NormalizeUnits { insert_space: true };
+
+/// Write numbers and their units with nothing in between.
+pub const JOIN_UNITS: NormalizeUnits = NormalizeUnits {
+    insert_space: false,
+};
+
+impl Default for NormalizeUnits {
+    fn default() -> Self {
+        SPACE_UNITS
+    }
+}
+
+impl NormalizeUnits {
+    /// Rewrite `text` into `out`. Without `out`, only report whether anything
+    /// would change, stopping at the first change.
+    fn rewrite(&self, text: &str, mut out: Option<&mut String>) -> bool {
+        let gap = if self.insert_space { " " } else { "" };
+        let mut changed = false;
+        let mut after_digit = false;
+        let mut i = 0;
+
+        while let Some(c) = text[i..].chars().next() {
+            if after_digit {
+                // 10⁶ → 10^6; the number continues, so a unit may follow
+                let exp_end = text[i..]
+                    .char_indices()
+                    .find(|&(_, e)| superscript_numeric_to_ascii(e).is_none())
+                    .map_or(text.len(), |(j, _)| i + j);
+                let exponent = &text[i..exp_end];
+                if exponent
+                    .chars()
+                    .filter_map(superscript_numeric_to_ascii)
+                    .any(|e| e.is_ascii_digit())
+                {
+                    changed = true;
+                    let Some(out) = out.as_deref_mut() else {
+                        return true;
+                    };
+                    out.push('^');
+                    out.extend(exponent.chars().filter_map(superscript_numeric_to_ascii));
+                    i = exp_end;
+                    continue;
+                }
+
+                let gap_end = text[i..]
+                    .char_indices()
+                    .find(|&(_, g)| !is_unit_space(g))
+                    .map_or(text.len(), |(j, _)| i + j);
+                if let Some(len) = unit_len(&text[gap_end..]) {
+                    if &text[i..gap_end] != gap {
+                        changed = true;
+                        if out.is_none() {
+                            return true;
+                        }
+                    }
+                    if let Some(out) = out.as_deref_mut() {
+                        out.push_str(gap);
+                        out.push_str(&text[gap_end..gap_end + len]);
+                    }
+                    i = gap_end + len;
+                    after_digit = false;
+                    continue;
+                }
+            }
+
+            if let Some(out) = out.as_deref_mut() {
+                out.push(c);
+            }
+            after_digit = c.is_ascii_digit();
+            i += c.len_utf8();
+        }
+        changed
+    }
+}
+
+impl Stage for NormalizeUnits {
+    fn name(&self) -> &'static str {
+        "normalize_units"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if !text.bytes().any(|b| b.is_ascii_digit()) {
+            return Ok(false);
+        }
+        Ok(self.rewrite(text, None))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len() + 8);
+        self.rewrite(&text, Some(&mut out));
+        Ok(Cow::Owned(out))
+    }
+
+    fn options(&self) -> String {
+        format!("insert_space={}", self.insert_space)
+    }
+}
+
+impl StaticFusableStage for NormalizeUnits {
+    type Adapter<'a, I>
+        = StaticIdentityAdapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        StaticIdentityAdapter::new(input)
+    }
+}
+
+impl StageTestConfig for NormalizeUnits {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "5kg",
+            "5 kg",
+            "5\u{00A0}kg and 3\u{202F}mm",
+            "1.5×10⁶ Hz",
+            "10⁻³m",
+            "25°C",
+            "the 1990s on 4K mp3",
+            "5  m²",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "5 kg",
+            "10^6 W",
+            "the 1990s",
+            "4K",
+            "x² m²",
+            "hello world",
+            "",
+        ]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("5kg", "5 kg"),
+            ("5\u{00A0}kg", "5 kg"),
+            ("3  mm", "3 mm"),
+            ("10⁶", "10^6"),
+            ("1.5×10⁻³m", "1.5×10^-3 m"),
+            ("25°C", "25 °C"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(NormalizeUnits::default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(stage: NormalizeUnits, text: &str) -> String {
+        let ctx = Context::default();
+        if !stage.needs_apply(text, &ctx).unwrap() {
+            return text.to_owned();
+        }
+        stage.apply(Cow::Borrowed(text), &ctx).unwrap().into_owned()
+    }
+
+    #[test]
+    fn spacing_follows_the_configured_mode() {
+        assert_eq!(run(SPACE_UNITS, "5kg"), "5 kg");
+        assert_eq!(run(SPACE_UNITS, "5 kg"), "5 kg");
+        assert_eq!(run(JOIN_UNITS, "5 kg"), "5kg");
+        assert_eq!(run(JOIN_UNITS, "5\u{202F}kg"), "5kg");
+        assert_eq!(run(JOIN_UNITS, "5kg"), "5kg");
+        assert_eq!(run(SPACE_UNITS, "2.4GHz, 16GB"), "2.4 GHz, 16 GB");
+    }
+
+    #[test]
+    fn exponents_become_caret_notation_in_both_modes() {
+        for stage in [SPACE_UNITS, JOIN_UNITS] {
+            assert_eq!(run(stage, "10⁶"), "10^6");
+            assert_eq!(run(stage, "6.02×10²³"), "6.02×10^23");
+        }
+        assert_eq!(run(SPACE_UNITS, "10⁻³m"), "10^-3 m");
+        assert_eq!(run(JOIN_UNITS, "10⁻³ m"), "10^-3m");
+    }
+
+    #[test]
+    fn non_units_and_unit_exponents_are_left_alone() {
+        for text in ["the 1990s", "4K", "mp3", "5kgs", "3 apples", "5 m²"] {
+            assert_eq!(run(SPACE_UNITS, text), text);
+        }
+        assert_eq!(run(JOIN_UNITS, "5 m²"), "5m²");
+    }
+}
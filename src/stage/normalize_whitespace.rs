@@ -213,6 +213,13 @@ impl Stage for NormalizeWhitespace {
         // Canonical path: handles all whitespace, all configurations, one pass, one allocation
         Ok(self.apply_full(text))
     }
+
+    fn options(&self) -> String {
+        format!(
+            "collapse={},trim={},normalize_unicode={},replacement_char=U+{:04X}",
+            self.collapse, self.trim, self.normalize_unicode, self.replacement_char as u32
+        )
+    }
 }
 
 impl NormalizeWhitespace {
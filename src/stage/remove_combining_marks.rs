@@ -0,0 +1,169 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+    unicode::is_combining_diacritical_mark,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Removes every combining mark of the Combining Diacritical Marks blocks.
+///
+/// All four blocks are covered, not only the base one:
+///
+/// - Combining Diacritical Marks (U+0300–036F): `◌́`, `◌̈`, `◌̧`
+/// - Extended (U+1AB0–1AFF): German dialectology and Scots marks (`◌᪰`, `◌᫁`)
+/// - Supplement (U+1DC0–1DFF): medieval superscript letters and Uralic marks
+///   (`◌ᷓ`, `◌᷎`)
+/// - for Symbols (U+20D0–20FF): enclosing circles, keycap frames (`◌⃝`, `◌⃗`)
+///
+/// Unlike [`RemoveDiacritics`](crate::RemoveDiacritics) this is not
+/// language-aware and does not decompose: precomposed letters such as `é` are
+/// left as they are. Put [`NFD`](crate::NFD) in front to strip those too.
+/// Marks of other scripts (Hebrew points, Arabic harakat, kana voicing marks)
+/// are preserved.
+///
+/// Zero-copy when no such marks are present. Fully fusable filter.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RemoveCombiningMarks;
+
+impl Stage for RemoveCombiningMarks {
+    fn name(&self) -> &'static str {
+        "remove_combining_marks"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(is_combining_diacritical_mark))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(text.chars().filter(|&c| !is_combining_diacritical_mark(c)));
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for RemoveCombiningMarks {
+    type Adapter<'a, I>
+        = RemoveCombiningMarksAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        RemoveCombiningMarksAdapter { input }
+    }
+}
+
+pub struct RemoveCombiningMarksAdapter<I> {
+    input: I,
+}
+
+impl<I: Iterator<Item = char>> Iterator for RemoveCombiningMarksAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.find(|&c| !is_combining_diacritical_mark(c))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.input.size_hint();
+        (0, upper) // Can only shrink
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for RemoveCombiningMarksAdapter<I> {}
+
+impl StageTestConfig for RemoveCombiningMarks {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "cafe\u{0301}",
+            "a\u{1AB0}b\u{1AC1}",
+            "u\u{1DD3}e\u{1DCE}",
+            "1\u{20E3} \u{20DD}",
+            "\u{05E9}\u{05C1}",
+            "n\u{0303}o",
+            "n\u{1AB2}o",
+            "n\u{1DC1}o",
+            "n\u{20D7}o",
+            "n\u{0301}\u{1DFE}o",
+            "café",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "hello world",
+            "café",
+            "\u{05E9}\u{05C1}",
+            "が",
+            "日本語",
+            "",
+        ]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("e\u{0301}", "e"),
+            ("a\u{1AB0}", "a"),
+            ("o\u{1DC4}", "o"),
+            ("1\u{20E3}", "1"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(RemoveCombiningMarks);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marks_of_every_diacritical_block_are_removed() {
+        let ctx = Context::default();
+        let stage = RemoveCombiningMarks;
+
+        // One mark from each block, then a Supplement mark stacked on a base one
+        for input in [
+            "n\u{0303}o",
+            "n\u{1AB2}o",
+            "n\u{1DC1}o",
+            "n\u{20D7}o",
+            "n\u{0301}\u{1DFE}o",
+        ] {
+            let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+            assert_eq!(out, "no", "{input:?}");
+        }
+    }
+}
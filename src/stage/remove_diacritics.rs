@@ -2,7 +2,7 @@ use crate::{
     ARA, CES, FRA, POL, SLK, VIE,
     context::Context,
     lang::{Lang, LangEntry},
-    stage::{Stage, StageError, StaticFusableStage},
+    stage::{CharMapper, Stage, StageError, StaticFusableStage, normalization::ICU4X_NFD},
     testing::stage_contract::StageTestConfig,
 };
 use std::borrow::Cow;
@@ -26,6 +26,46 @@ use std::iter::FusedIterator;
 #[derive(Debug, Default, Clone, Copy)]
 pub struct RemoveDiacritics;
 
+impl RemoveDiacritics {
+    /// The language-independent variant, see [`UniversalRemoveDiacritics`].
+    pub const fn universal() -> UniversalRemoveDiacritics {
+        UniversalRemoveDiacritics
+    }
+}
+
+/// Base letter of a Latin-1 Supplement (U+00C0–00FF), Latin Extended-A
+/// (U+0100–017F) or Latin Extended Additional (U+1E00–1EFF) letter.
+///
+/// These are the accented letters whose canonical decomposition starts with
+/// an ASCII letter (`à` → `a`, `ū` → `u`, `ặ` → `a`, `ḥ` → `h`). The long s
+/// forms fold to `s` and `ỿ` (y with loop) to `y`; letters without a
+/// decomposition (`ß`, `æ`, `ø`, `đ`, `ł`, `ẞ`, the Middle Welsh `Ỻ` `Ỽ`)
+/// are letters of their own and have no base.
+#[inline(always)]
+fn latin_base(c: char) -> Option<char> {
+    match c {
+        '\u{1E9B}'..='\u{1E9D}' => Some('s'),
+        'Ỿ' => Some('Y'),
+        'ỿ' => Some('y'),
+        '\u{00C0}'..='\u{017F}' | '\u{1E00}'..='\u{1EFF}' => ICU4X_NFD
+            .normalize_iter(std::iter::once(c))
+            .next()
+            .filter(|base| *base != c && base.is_ascii_alphabetic()),
+        _ => None,
+    }
+}
+
+/// The base `c` maps to: the language's own rules first, then (`universal`)
+/// the Latin blocks – Latin-1 and Extended-A only when the language has no
+/// accent rules of its own, which then decide what counts as a letter
+#[inline(always)]
+fn base_of(lang: &LangEntry, universal: bool, c: char) -> Option<char> {
+    lang.find_pre_composed_to_base_map(c).or_else(|| {
+        let own_rules = c < '\u{0180}' && lang.has_pre_composed_to_base_map();
+        (universal && !own_rules).then(|| latin_base(c)).flatten()
+    })
+}
+
 impl Stage for RemoveDiacritics {
     fn name(&self) -> &'static str {
         "remove_diacritics"
@@ -51,6 +91,18 @@ impl Stage for RemoveDiacritics {
         }
         Ok(Cow::Owned(out))
     }
+
+    fn as_char_mapper(&self, ctx: &Context) -> Option<&dyn CharMapper> {
+        // Dropping spacing diacritics (Arabic harakat) is not 1:1
+        (!ctx.lang_entry.has_spacing_diacritics()).then_some(self as &dyn CharMapper)
+    }
+}
+
+impl CharMapper for RemoveDiacritics {
+    #[inline(always)]
+    fn map_char(&self, c: char, ctx: &Context) -> char {
+        ctx.lang_entry.find_pre_composed_to_base_map(c).unwrap_or(c)
+    }
 }
 
 impl StaticFusableStage for RemoveDiacritics {
@@ -72,6 +124,7 @@ impl StaticFusableStage for RemoveDiacritics {
         RemoveDiacriticsAdapter {
             input,
             lang: &ctx.lang_entry,
+            universal: false,
         }
     }
 }
@@ -79,6 +132,8 @@ impl StaticFusableStage for RemoveDiacritics {
 pub struct RemoveDiacriticsAdapter<'a, I> {
     input: I,
     lang: &'a LangEntry,
+    /// Also fold the Latin blocks (`UniversalRemoveDiacritics`)
+    universal: bool,
 }
 
 impl<'a, I: Iterator<Item = char>> Iterator for RemoveDiacriticsAdapter<'a, I> {
@@ -90,7 +145,7 @@ impl<'a, I: Iterator<Item = char>> Iterator for RemoveDiacriticsAdapter<'a, I> {
             let c = self.input.next()?;
 
             // 1. Try 1:1 mapping (é -> e)
-            if let Some(base) = self.lang.find_pre_composed_to_base_map(c) {
+            if let Some(base) = base_of(self.lang, self.universal, c) {
                 return Some(base);
             }
 
@@ -173,6 +228,121 @@ impl StageTestConfig for RemoveDiacritics {
     }
 }
 
+/// `RemoveDiacritics` that also strips accents a language's own rules miss.
+///
+/// Runs the language's rules first, then folds accented Latin letters to
+/// their ASCII base:
+///
+/// - Latin Extended Additional (U+1E00–1EFF) in every language: `Ặ` → `A`,
+///   `ờ` → `o`, `ḥ` → `h`, `ẁ` → `w`
+/// - Latin-1 Supplement and Latin Extended-A (U+00C0–017F) in languages
+///   without accent rules of their own, such as English: `à` → `a`,
+///   `ū` → `u`, `č` → `c`
+///
+/// In English, Vietnamese names and transliterated Arabic or Sanskrit
+/// (`Hà Nội`, `Muḥammad ibn Mūsā`, `Kṛṣṇa`) then match their plain
+/// spelling. Where the language has its own rules they decide Latin-1 and
+/// Extended-A, so `ñ` stays in Spanish. Letters without a decomposition
+/// (`ß`, `æ`, `ø`, `ł`) are kept.
+///
+/// Built with `RemoveDiacritics::universal()`.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UniversalRemoveDiacritics;
+
+impl Stage for UniversalRemoveDiacritics {
+    fn name(&self) -> &'static str {
+        "remove_diacritics_universal"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        let entry = ctx.lang_entry;
+        Ok(text.chars().any(|c| {
+            base_of(&entry, true, c).is_some_and(|base| base != c) || entry.is_spacing_diacritic(c)
+        }))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(self.static_fused_adapter(text.chars(), ctx));
+        Ok(Cow::Owned(out))
+    }
+
+    fn as_char_mapper(&self, ctx: &Context) -> Option<&dyn CharMapper> {
+        (!ctx.lang_entry.has_spacing_diacritics()).then_some(self as &dyn CharMapper)
+    }
+}
+
+impl CharMapper for UniversalRemoveDiacritics {
+    #[inline(always)]
+    fn map_char(&self, c: char, ctx: &Context) -> char {
+        base_of(&ctx.lang_entry, true, c).unwrap_or(c)
+    }
+}
+
+impl StaticFusableStage for UniversalRemoveDiacritics {
+    type Adapter<'a, I>
+        = RemoveDiacriticsAdapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        RemoveDiacriticsAdapter {
+            input,
+            lang: &ctx.lang_entry,
+            universal: true,
+        }
+    }
+}
+
+impl StageTestConfig for UniversalRemoveDiacritics {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "Hà Nội, Việt Nam",
+            "Muḥammad ibn Mūsā",
+            "Hà Nội, Việt Nam Muḥammad ibn Mūsā",
+            "Kṛṣṇa ḍhaṃ",
+            "Ẁales ẞ ẟ Ỻ ỿ ẛ",
+            "Nguyễn Thị Ánh, Muḥammad, Ẁ ẞ",
+            "Hà Nội, Việt Nam Muḥammad ibn Mūsā, Dvořák, Straße Øre",
+            "plain text",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["plain text", "ẞ", "Ỻ", "hello world", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("Ặ", "A"),
+            ("ḥ", "h"),
+            ("Ẁales", "Wales"),
+            ("Kṛṣṇa", "Krsna"),
+            ("Nộiờ", "Noio"),
+        ]
+    }
+}
+
 #[cfg(test)]
 mod contract_tests {
     use super::*;
@@ -181,6 +351,7 @@ mod contract_tests {
     #[test]
     fn universal_contract_compliance() {
         assert_stage_contract!(RemoveDiacritics);
+        assert_stage_contract!(RemoveDiacritics::universal());
     }
 }
 
@@ -391,4 +562,42 @@ mod tests {
         let eng = Context::new(ENG);
         assert!(!stage.needs_apply("café", &eng).unwrap());
     }
+
+    #[test]
+    fn universal_strips_latin_extended_additional_in_any_language() {
+        let stage = RemoveDiacritics::universal();
+        let input = "Nguyễn Thị Ánh, Muḥammad, Ẁ ẞ";
+
+        for (lang, expected) in [
+            (ENG, "Nguyen Thi Anh, Muhammad, W ẞ"),
+            (FRA, "Nguyen Thi Ánh, Muhammad, W ẞ"),
+            (crate::DEU, "Nguyen Thi Anh, Muhammad, W ẞ"),
+        ] {
+            let out = stage.apply(Cow::Borrowed(input), &Context::new(lang));
+            assert_eq!(out.unwrap(), expected, "{lang:?}");
+        }
+        // The plain stage leaves the block alone outside Vietnamese
+        assert!(
+            !RemoveDiacritics
+                .needs_apply("Muḥammad", &Context::new(ENG))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn universal_strips_latin1_and_extended_a_without_own_rules() {
+        let stage = RemoveDiacritics::universal();
+        let input = "Hà Nội, Việt Nam Muḥammad ibn Mūsā, Dvořák, Straße Øre";
+
+        let eng = Context::new(ENG);
+        let out = stage.apply(Cow::Borrowed(input), &eng).unwrap();
+        assert_eq!(
+            out,
+            "Ha Noi, Viet Nam Muhammad ibn Musa, Dvorak, Straße Øre"
+        );
+
+        // Spanish rules keep ñ, Extended Additional still folds
+        let spa = Context::new(SPA);
+        assert_eq!(stage.apply(Cow::Borrowed("Año Ḥ"), &spa).unwrap(), "Año H");
+    }
 }
@@ -28,7 +28,9 @@ use crate::{
 ///   In Devanagari (Hindi), mandatory conjuncts (e.g., involving र य व ह) are preserved
 ///   and not broken — other Indic scripts use the generic virama rule.
 ///
-/// Whitespace and joiners (ZWJ/ZWNJ) are transparent.
+/// Whitespace resets boundary detection. Joiners (ZWJ/ZWNJ) are transparent
+/// where the language writes them inside words ([`LangEntry::is_word_char`]);
+/// elsewhere they are stray format controls and reset it like whitespace.
 ///
 /// # Examples
 ///
@@ -102,8 +104,8 @@ impl Stage for SegmentWords {
         let mut prev_is_virama = false;
 
         for curr in text.chars() {
-            // 1. Whitespace/ZWSP resets state and passes through
-            if is_any_whitespace(curr) || curr == zwsp() {
+            // 1. Whitespace/ZWSP/stray joiners reset state and pass through
+            if resets_boundary(curr, &entry) {
                 out.push(curr);
                 prev_char = None;
                 prev_class = None;
@@ -111,8 +113,8 @@ impl Stage for SegmentWords {
                 continue;
             }
 
-            // 2. Joiners pass through but don't reset state
-            if curr == '\u{200D}' || curr == '\u{200C}' {
+            // 2. In-word joiners pass through but don't reset state
+            if is_joiner(curr) {
                 out.push(curr);
                 continue;
             }
@@ -208,8 +210,8 @@ impl<'a, I: Iterator<Item = char>> Iterator for SegmentWordsAdapter<'a, I> {
                 }
             };
 
-            // 2. Whitespace/ZWSP resets boundary detection state
-            if is_any_whitespace(curr) || curr == zwsp() {
+            // 2. Whitespace/ZWSP/stray joiners reset boundary detection state
+            if resets_boundary(curr, self.lang) {
                 if let Some(prev) = self.prev_char.take() {
                     self.prev_class = None;
                     self.prev_is_virama = false;
@@ -219,10 +221,12 @@ impl<'a, I: Iterator<Item = char>> Iterator for SegmentWordsAdapter<'a, I> {
                 return Some(curr);
             }
 
-            // 3. Joiners are transparent: emit but don't reset script state
-            if curr == '\u{200D}' || curr == '\u{200C}' {
-                if self.prev_char.is_some() {
-                    continue;
+            // 3. In-word joiners are transparent: emit in order but keep the
+            //    script state of the char before them
+            if is_joiner(curr) {
+                if let Some(prev) = self.prev_char {
+                    self.prev_char = Some(curr);
+                    return Some(prev);
                 }
                 return Some(curr);
             }
@@ -308,19 +312,35 @@ fn check_boundary_with_classes(
     }
 }
 
+#[inline(always)]
+fn is_joiner(c: char) -> bool {
+    matches!(c, '\u{200C}' | '\u{200D}')
+}
+
+/// Whitespace, ZWSP and joiners the language doesn't write inside words
+#[inline(always)]
+fn resets_boundary(c: char, lang: &LangEntry) -> bool {
+    is_any_whitespace(c) || c == zwsp() || (is_joiner(c) && !lang.is_word_char(c))
+}
+
 #[inline]
 fn needs_segmentation(text: &str, lang: &LangEntry) -> bool {
     let mut prev_class: Option<CharClass> = None;
     let mut prev_char: Option<char> = None;
 
     for curr in text.chars() {
-        // Whitespace/ZWSP resets boundary detection
-        if curr == ' ' || curr == zwsp() {
+        // Whitespace/ZWSP/stray joiners reset boundary detection
+        if resets_boundary(curr, lang) {
             prev_class = None;
             prev_char = None;
             continue;
         }
 
+        // In-word joiners are transparent
+        if is_joiner(curr) {
+            continue;
+        }
+
         let curr_class = classify(curr);
 
         // Virama + consonant → needs ZWSP (Indic scripts)
@@ -356,9 +376,23 @@ impl StageTestConfig for SegmentWords {
         match lang {
             ENG => &["Hello world", "123 !@#", "", " "],
             ZHO => &["你好世界", "Hello世界", "AI+区块链", "中华人民共和国"],
-            JPN => &["こんにちは世界", "Rustは最高", "東京2025年", "人工知能"],
+            JPN => &[
+                "こんにちは世界",
+                "Rustは最高",
+                "東京2025年",
+                "人工知能",
+                "Rust\u{200D}は",
+            ],
             KOR => &["안녕하세요세계", "Hello안녕하세요", "서울2025년"],
-            HIN => &["पत्नी", "विद्वत्", "विद्वत्त्व", "Helloपत्नी", "रामायण"],
+            HIN => &[
+                "पत्नी",
+                "विद्वत्",
+                "विद्वत्त्व",
+                "Helloपत्नी",
+                "रामायण",
+                "पत्\u{200D}नी",
+                "Hello\u{200C}पत्नी",
+            ],
             _ => &["Hello World 123", " déjà-vu ", "TEST", ""],
         }
     }
@@ -413,7 +447,7 @@ mod tests {
         context::Context,
         lang::{
             Lang,
-            data::{JPN, KHM, KOR, LAO, MYA, THA, ZHO},
+            data::{JPN, KHB, KHM, KOR, LAO, MYA, NOD, THA, ZHO},
         },
     };
     use std::borrow::Cow;
@@ -561,6 +595,31 @@ mod tests {
         run_cases(KHM, &[("Helloសួស្តី", "Hello សួស្តី"), ("សួស្តីWorld", "សួស្តី World")]);
     }
 
+    #[test]
+    fn test_tai_tham() {
+        // The sakot keeps the stacked consonant inside the word
+        run_cases(
+            NOD,
+            &[
+                ("Helloᨲᩫ᩠ᩅ", "Hello ᨲᩫ᩠ᩅ"),
+                ("ᨲᩫ᩠ᩅWorld", "ᨲᩫ᩠ᩅ World"),
+                ("ᨲᩫ᩠ᩅᨾᩮᩬᩥᨦ", "ᨲᩫ᩠ᩅᨾᩮᩬᩥᨦ"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_new_tai_lue() {
+        run_cases(
+            KHB,
+            &[
+                ("Helloᦟᦲᧉ", "Hello ᦟᦲᧉ"),
+                ("ᦟᦲᧉWorld", "ᦟᦲᧉ World"),
+                ("ᦟᦲᧉᦺᦑᦟᦹᧉ", "ᦟᦲᧉᦺᦑᦟᦹᧉ"),
+            ],
+        );
+    }
+
     #[test]
     fn test_hindi() {
         run_cases(
@@ -581,6 +640,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn joiners_are_transparent_only_inside_words() {
+        // Hindi writes ZWJ/ZWNJ inside words: they keep the state around them
+        run_cases(
+            HIN,
+            &[
+                ("पत्\u{200D}नी", "पत्\u{200D}\u{200B}नी"),
+                ("Hello\u{200C}पत्नी", "Hello\u{200C} पत्\u{200B}नी"),
+            ],
+        );
+        // Japanese doesn't: a stray joiner separates like whitespace
+        run_cases(
+            JPN,
+            &[
+                ("Rust\u{200D}は", "Rust\u{200D}は"),
+                ("東\u{200D}京", "東\u{200D}京"),
+            ],
+        );
+    }
+
     #[test]
     fn test_tamil() {
         run_cases(
@@ -0,0 +1,386 @@
+use crate::{
+    DEU, FRA, POL, TUR,
+    context::Context,
+    lang::{Lang, LangEntry},
+    stage::{
+        Stage, StageError, StaticFusableStage, StaticIdentityAdapter,
+        normalization::{ICU4X_NFC, ICU4X_NFD},
+    },
+    testing::stage_contract::StageTestConfig,
+    unicode::{CharClass, classify, is_combining_half_mark, is_combining_mark, is_extended_latin},
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Produces slugs: `"Café del Mar!"` → `"cafe-del-mar"`.
+///
+/// A convenience stage equivalent to chaining, in order:
+///
+/// 0. NFC – so a decomposed `"Cafe\u{301}"` slugs like the precomposed form
+/// 1. `Transliterate` – the language's multi-char expansions (`Ü` → `ue` in German)
+/// 2. `RemoveDiacritics` – the language's base-letter map and spacing diacritics
+/// 3. `CaseFold` – the language's fold map (`ß` → `ss` in German), then
+///    locale-aware lowercasing (`İ` → `i` in Turkish)
+/// 4. Latin fallback – any remaining accented Latin letter is reduced to its
+///    base letters (`é` → `e` in English), and letters without a decomposition
+///    are spelled out (`ß` → `ss`, `æ` → `ae`, `ø` → `o`, `ǆ` → `dz`). Turkish
+///    dotless `ı` has no ASCII spelling that keeps it apart from `i`, so it is
+///    kept
+/// 5. Token joining – every run of non-alphanumeric characters becomes a single
+///    `-`, with no leading or trailing hyphen
+///
+/// Letters of other scripts (Cyrillic, CJK, Indic, …) are kept as word
+/// characters, as are combining marks directly after such a letter. Marks after
+/// a Latin letter or with no letter under them are dropped, and so are the
+/// invisible emoji controls – variation selectors, ZWJ and the keycap mark
+/// U+20E3 – so `1️⃣` becomes `1`.
+///
+/// Latin text yields pure ASCII slugs that are URL-safe as they are. Other
+/// scripts are kept, not romanized: `"Привет мир"` → `"привет-мир"` is an IRI
+/// path segment and must be percent-encoded before it goes into an ASCII URL.
+///
+/// Not fusable: token joining needs lookahead and step 1 may expand.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Slugify;
+
+/// Part of a word (kept) vs separator (collapsed into `-`)
+#[inline(always)]
+fn is_slug_char(c: char) -> bool {
+    c.is_alphanumeric()
+        || (!c.is_ascii() && matches!(classify(c), CharClass::Indic | CharClass::SEAsian))
+}
+
+/// Emoji presentation controls: never part of a slug, and never a separator
+#[inline(always)]
+fn is_invisible_control(c: char) -> bool {
+    matches!(c, '\u{200D}' | '\u{20E3}' | '\u{FE00}'..='\u{FE0F}' | '\u{E0100}'..='\u{E01EF}')
+}
+
+/// ASCII spelling of a lowercase Latin letter that has no decomposition
+#[inline(always)]
+fn latin_spelling(c: char) -> Option<&'static str> {
+    Some(match c {
+        'ß' => "ss",
+        'æ' => "ae",
+        'ø' => "o",
+        'œ' => "oe",
+        'đ' | 'ð' => "d",
+        'ħ' => "h",
+        'ł' => "l",
+        'þ' => "th",
+        'ĳ' => "ij",
+        'ǆ' | 'ǳ' => "dz",
+        'ǉ' => "lj",
+        'ǌ' => "nj",
+        _ => return None,
+    })
+}
+
+#[inline(always)]
+fn is_latin_decomposable(c: char) -> bool {
+    is_extended_latin(c) || matches!(c, '\u{1E00}'..='\u{1EFF}')
+}
+
+/// Joins word chars with single hyphens; separators are only written lazily
+struct SlugWriter<'o> {
+    out: &'o mut String,
+    pending_hyphen: bool,
+    /// The last char written is a kept non-Latin letter, so a mark may attach to it
+    after_base: bool,
+}
+
+impl SlugWriter<'_> {
+    #[inline(always)]
+    fn push(&mut self, c: char) {
+        if c.is_ascii() {
+            // Fast path below
+        } else if is_invisible_control(c) {
+            return;
+        } else if is_combining_mark(c) {
+            if self.after_base {
+                self.out.push(c);
+            }
+            return;
+        }
+        if !is_slug_char(c) {
+            self.pending_hyphen = !self.out.is_empty();
+            self.after_base = false;
+            return;
+        }
+        if self.pending_hyphen {
+            self.out.push('-');
+            self.pending_hyphen = false;
+        }
+        self.out.push(c);
+        self.after_base = !c.is_ascii() && !is_latin_decomposable(c);
+    }
+
+    #[inline(always)]
+    fn push_lowered(&mut self, c: char, entry: &LangEntry) {
+        if entry.is_spacing_diacritic(c) || is_combining_half_mark(c) {
+            return;
+        }
+        if let Some(folded) = entry.find_fold_map(c) {
+            for f in folded.chars() {
+                self.push_latin(entry.apply_lowercase(f));
+            }
+        } else {
+            self.push_latin(entry.apply_lowercase(c));
+        }
+    }
+
+    #[inline(always)]
+    fn push_latin(&mut self, lower: char) {
+        if let Some(spelling) = latin_spelling(lower) {
+            for s in spelling.chars() {
+                self.push(s);
+            }
+        } else if is_latin_decomposable(lower) {
+            for d in ICU4X_NFD.normalize_iter(std::iter::once(lower)) {
+                if !is_combining_mark(d) {
+                    self.push(d);
+                }
+            }
+        } else {
+            self.push(lower);
+        }
+    }
+}
+
+/// `c` survives `slugify_into` unchanged (marks and separators aside)
+#[inline(always)]
+fn is_settled(c: char, entry: &LangEntry) -> bool {
+    is_slug_char(c)
+        && entry.find_transliterate_map(c).is_none()
+        && entry.find_pre_composed_to_base_map(c).is_none()
+        && entry.find_fold_map(c).is_none()
+        && !entry.is_spacing_diacritic(c)
+        && entry.apply_lowercase(c) == c
+        && latin_spelling(c).is_none()
+        && !(is_latin_decomposable(c)
+            && ICU4X_NFD
+                .normalize_iter(std::iter::once(c))
+                .any(is_combining_mark))
+}
+
+fn slugify_into(text: &str, entry: &LangEntry, out: &mut String) {
+    let mut writer = SlugWriter {
+        out,
+        pending_hyphen: false,
+        after_base: false,
+    };
+    for c in ICU4X_NFC.normalize(text).chars() {
+        if let Some(expansion) = entry.find_transliterate_map(c) {
+            for e in expansion.chars() {
+                writer.push_lowered(e, entry);
+            }
+        } else {
+            let base = entry.find_pre_composed_to_base_map(c).unwrap_or(c);
+            writer.push_lowered(base, entry);
+        }
+    }
+}
+
+impl Stage for Slugify {
+    fn name(&self) -> &'static str {
+        "slugify"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            // Already a slug: [a-z0-9]+ joined by single hyphens
+            let bytes = text.as_bytes();
+            let well_formed = bytes
+                .iter()
+                .all(|&b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+                && !text.starts_with('-')
+                && !text.ends_with('-')
+                && !text.contains("--");
+            return Ok(!well_formed);
+        }
+        if text.starts_with('-') || text.ends_with('-') || text.contains("--") {
+            return Ok(true);
+        }
+        if !ICU4X_NFC.is_normalized(text) {
+            return Ok(true);
+        }
+        // Mirrors `SlugWriter::push` without writing: a mark is kept only after a
+        // kept non-Latin letter
+        let entry = &ctx.lang_entry;
+        let mut after_base = false;
+        for c in text.chars() {
+            if c.is_ascii() {
+                if !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-') {
+                    return Ok(true);
+                }
+                after_base = false;
+            } else if is_combining_mark(c) {
+                if !after_base
+                    || is_invisible_control(c)
+                    || is_combining_half_mark(c)
+                    || entry.is_spacing_diacritic(c)
+                    || entry.find_transliterate_map(c).is_some()
+                    || entry.find_pre_composed_to_base_map(c).is_some()
+                {
+                    return Ok(true);
+                }
+            } else if is_invisible_control(c) || !is_settled(c, entry) {
+                return Ok(true);
+            } else {
+                after_base = !is_latin_decomposable(c);
+            }
+        }
+        Ok(false)
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        slugify_into(&text, &ctx.lang_entry, &mut out);
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for Slugify {
+    type Adapter<'a, I>
+        = StaticIdentityAdapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        StaticIdentityAdapter::new(input)
+    }
+}
+
+impl StageTestConfig for Slugify {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(lang: Lang) -> &'static [&'static str] {
+        match lang {
+            FRA => &["Café del Mar!", "Crème brûlée", "L'Œuvre", "déjà-vu"],
+            DEU => &["Grüße aus Köln", "Straße 5"],
+            TUR => &["İSTANBUL Boğazı", "Işık"],
+            POL => &["Łódź 2024", "Zażółć gęślą jaźń"],
+            _ => &[
+                "Hello, World!",
+                "  --edge--  ",
+                "Привет мир",
+                "1️⃣ #️⃣ *⃣",
+                "Straße ÆØÅ ǅ",
+                "Cafe\u{301} del Mar!",
+                "И\u{0306}ога q\u{0301}",
+                "привет-мир",
+                "already-a-slug",
+                "",
+            ],
+        }
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["already-a-slug", "cafe-del-mar", "abc123", ""]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::testing::stage_contract::{
+        fused_path_equivalent_to_apply, needs_apply_is_accurate, no_panic_on_mixed_scripts,
+        stage_is_idempotent, zero_copy_when_no_changes,
+    };
+
+    #[test]
+    fn universal_contract_compliance() {
+        // Not `assert_stage_contract!`: rewriting plain ASCII ("a b" → "a-b") is
+        // the point of this stage, so the ASCII pass-through contract cannot hold.
+        zero_copy_when_no_changes(Slugify);
+        fused_path_equivalent_to_apply(Slugify);
+        stage_is_idempotent(Slugify);
+        needs_apply_is_accurate(Slugify);
+        no_panic_on_mixed_scripts(Slugify);
+
+        assert!(!Slugify.needs_apply("", &Context::default()).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ENG;
+
+    fn slug(text: &str, lang: Lang) -> String {
+        Slugify
+            .apply(Cow::Borrowed(text), &Context::new(lang))
+            .unwrap()
+            .into_owned()
+    }
+
+    #[test]
+    fn builds_ascii_slugs_from_latin_text() {
+        assert_eq!(slug("Café del Mar!", FRA), "cafe-del-mar");
+        assert_eq!(slug("Café del Mar!", ENG), "cafe-del-mar");
+        assert_eq!(slug("  --Hello,   World!--  ", ENG), "hello-world");
+    }
+
+    #[test]
+    fn drops_combining_half_marks() {
+        assert_eq!(slug("T\u{FE20}S\u{FE21}ar", ENG), "tsar");
+    }
+
+    #[test]
+    fn uses_language_maps() {
+        assert_eq!(slug("Grüße aus Köln", DEU), "gruesse-aus-koeln");
+        assert_eq!(slug("İSTANBUL Işık", TUR), "istanbul-ısık"); // ş decomposes, ı has no base form
+        assert_eq!(slug("Łódź", POL), "lodz");
+        assert_eq!(slug("L'Œuvre", FRA), "l-oeuvre");
+    }
+
+    #[test]
+    fn spells_out_latin_letters_without_a_base() {
+        assert_eq!(slug("Straße ÆØÅ ǅ", ENG), "strasse-aeoa-dz");
+        assert_eq!(
+            slug("Ljubljana ǈubljana Þór", ENG),
+            "ljubljana-ljubljana-thor"
+        );
+    }
+
+    #[test]
+    fn drops_emoji_controls_and_unattached_marks() {
+        assert_eq!(slug("1️⃣ #️⃣ *⃣", ENG), "1");
+        assert_eq!(slug("\u{0301}a \u{0301} b\u{FE0F}", ENG), "a-b");
+        assert_eq!(slug("И\u{0306}ога", ENG), "йога"); // Composed first
+        assert_eq!(slug("q\u{0301}x\u{0323}", ENG), "qx"); // No precomposed form
+        assert_eq!(slug("ж\u{0301}", ENG), "ж\u{0301}"); // Mark on a kept letter
+    }
+
+    #[test]
+    fn decomposed_input_slugs_like_precomposed() {
+        assert_eq!(slug("Cafe\u{301} del Mar!", ENG), "cafe-del-mar");
+        assert_eq!(slug("Cafe\u{301} del Mar!", FRA), "cafe-del-mar");
+        let ctx = Context::new(ENG);
+        assert!(Slugify.needs_apply("cafe\u{301}", &ctx).unwrap());
+        assert!(!Slugify.needs_apply("привет-мир", &ctx).unwrap());
+        assert!(
+            !Slugify
+                .needs_apply("ж\u{0301}-ısık", &Context::new(TUR))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn keeps_other_scripts_as_words() {
+        assert_eq!(slug("Привет, мир!", ENG), "привет-мир");
+        assert_eq!(slug("नमस्ते दुनिया", ENG), "नमस्ते-दुनिया");
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+};
+use memchr::memchr_iter;
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Removes Braille Patterns (U+2800–28FF).
+///
+/// Outside of actual braille documents these show up as noise in scraped
+/// text: dot-matrix "ASCII art" (`⣿⣿⡿⠋`), spinner frames from terminal logs
+/// (`⠋⠙⠹`), and `⠀` (U+2800 BRAILLE PATTERN BLANK) used as an invisible name
+/// or padding. None of it is searchable. Real braille content should not go
+/// through this stage.
+///
+/// Zero-copy when no braille patterns are present; the pre-scan only looks
+/// at `E2 A0..=A3` lead bytes. Fully fusable filter.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StripBraille;
+
+#[inline(always)]
+fn is_braille(c: char) -> bool {
+    matches!(c, '\u{2800}'..='\u{28FF}')
+}
+
+impl Stage for StripBraille {
+    fn name(&self) -> &'static str {
+        "strip_braille"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        // Every pattern encodes as E2 A0..=A3 xx
+        let bytes = text.as_bytes();
+        Ok(memchr_iter(0xE2, bytes).any(|i| matches!(bytes.get(i + 1), Some(0xA0..=0xA3))))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(text.chars().filter(|&c| !is_braille(c)));
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for StripBraille {
+    type Adapter<'a, I>
+        = StripBrailleAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        StripBrailleAdapter { input }
+    }
+}
+
+pub struct StripBrailleAdapter<I> {
+    input: I,
+}
+
+impl<I: Iterator<Item = char>> Iterator for StripBrailleAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.find(|&c| !is_braille(c))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.input.size_hint();
+        (0, upper) // Can only shrink
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for StripBrailleAdapter<I> {}
+
+impl StageTestConfig for StripBraille {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "⣿⣿⡿⠋ art",
+            "⠋ loading ⠙⠹",
+            "name\u{2800}",
+            "⠋ Building… ⣿⣿ done – 100%",
+            "\u{28FF}\u{2800}",
+            "– ‘quoted’ ☃",
+            "plain text",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["plain text", "– ‘quoted’ ☃", "①②③", "hello world", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[("⣿⣿ art", " art"), ("a\u{2800}b", "ab"), ("\u{28FF}", "")]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(StripBraille);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ENG;
+
+    #[test]
+    fn braille_noise_is_stripped_from_mixed_text() {
+        let ctx = Context::new(ENG);
+        let stage = StripBraille;
+
+        let input = "⠋ Building… ⣿⣿ done – 100%";
+        let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, " Building…  done – 100%");
+        // Other E2-led chars (…, –, ‘) do not trip the pre-scan
+        assert!(!stage.needs_apply(&out, &ctx).unwrap());
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+    unicode::is_combining_half_mark,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Removes Combining Half Marks (U+FE20–FE2F).
+///
+/// Half marks draw one diacritic across two letters: the left half follows the
+/// first base and the right half the second, e.g. the ligature tie in ALA-LC
+/// romanization (`t︠s︡` for ц) or double macrons in medieval editions. Modern
+/// search treats them as noise, and they defeat matching against the same text
+/// without them.
+///
+/// Only the half marks are removed; their base characters are kept, so `t︠s︡`
+/// becomes `ts`.
+///
+/// Zero-copy when no half marks are present. Fully fusable filter.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StripCombiningHalfMarks;
+
+impl Stage for StripCombiningHalfMarks {
+    fn name(&self) -> &'static str {
+        "strip_combining_half_marks"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(is_combining_half_mark))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(text.chars().filter(|&c| !is_combining_half_mark(c)));
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for StripCombiningHalfMarks {
+    type Adapter<'a, I>
+        = StripCombiningHalfMarksAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        StripCombiningHalfMarksAdapter { input }
+    }
+}
+
+pub struct StripCombiningHalfMarksAdapter<I> {
+    input: I,
+}
+
+impl<I: Iterator<Item = char>> Iterator for StripCombiningHalfMarksAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.find(|&c| !is_combining_half_mark(c))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.input.size_hint();
+        (0, upper) // Can only shrink
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for StripCombiningHalfMarksAdapter<I> {}
+
+impl StageTestConfig for StripCombiningHalfMarks {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "t\u{FE20}s\u{FE21}",
+            "I\u{FE20}A\u{FE21}kov",
+            "a\u{FE22}b\u{FE23}c\u{FE2F}",
+            "I\u{FE20}A\u{FE21}kovle\u{0301}v",
+            "café",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["hello world", "café", "e\u{0301}", "日本語", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[("t\u{FE20}s\u{FE21}", "ts"), ("o\u{FE24}o\u{FE25}", "oo")]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(StripCombiningHalfMarks);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RUS;
+
+    #[test]
+    fn half_marks_are_removed_and_bases_kept() {
+        let ctx = Context::new(RUS);
+        let stage = StripCombiningHalfMarks;
+
+        // ALA-LC romanization of Яковлев with ligature ties, plus an acute
+        let input = "I\u{FE20}A\u{FE21}kovle\u{0301}v";
+        let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "IAkovle\u{0301}v");
+        assert!(!stage.needs_apply("Iakovle\u{0301}v", &ctx).unwrap());
+    }
+}
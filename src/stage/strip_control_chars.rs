@@ -1,7 +1,7 @@
 use crate::{
     context::Context,
     lang::Lang,
-    stage::{Stage, StageError, StaticFusableStage},
+    stage::{CharMapper, Stage, StageError, StaticFusableStage},
     testing::stage_contract::StageTestConfig,
     unicode::is_control,
 };
@@ -116,20 +116,244 @@ impl StageTestConfig for StripControlChars {
     }
 }
 
+/// What to do with a control character (General Category Cc).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlCharPolicy {
+    /// Drop the character entirely (same behavior as `StripControlChars`)
+    Remove,
+    /// Substitute the character, preserving character positions.
+    ///
+    /// The replacement should not itself be a control character.
+    Replace(char),
+}
+
+/// Removes or replaces Unicode control characters (General Category Cc).
+///
+/// Configurable sibling of `StripControlChars`: with `ControlCharPolicy::Replace`
+/// every C0/C1 control is substituted one-for-one, so character offsets of the
+/// surrounding text stay stable. Useful when downstream systems index by position
+/// or when the presence of corrupted bytes must remain visible.
+///
+/// The policy is a single option: `policy=remove`, `policy=replace` (U+FFFD)
+/// or `policy=replace:<char>`, where `<char>` is a char or `U+XXXX`.
+///
+/// Common presets:
+/// - `REPLACE_CONTROL_CHARS`: replace every Cc with U+FFFD REPLACEMENT CHARACTER
+///
+/// Zero-copy when no Cc characters are present.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizeControlChars {
+    pub policy: ControlCharPolicy,
+}
+
+/// Replace every control character with U+FFFD, preserving text length in chars.
+pub const REPLACE_CONTROL_CHARS: NormalizeControlChars = NormalizeControlChars {
+    policy: ControlCharPolicy::Replace('\u{FFFD}'),
+};
+
+impl Default for NormalizeControlChars {
+    fn default() -> Self {
+        REPLACE_CONTROL_CHARS
+    }
+}
+
+impl NormalizeControlChars {
+    // A control used as its own replacement is a no-op, not a change
+    #[inline(always)]
+    fn changes(&self, c: char) -> bool {
+        is_control(c) && self.policy != ControlCharPolicy::Replace(c)
+    }
+}
+
+impl Stage for NormalizeControlChars {
+    fn name(&self) -> &'static str {
+        "normalize_control_chars"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        Ok(text.chars().any(|c| self.changes(c)))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        for c in text.chars() {
+            if !is_control(c) {
+                out.push(c);
+            } else if let ControlCharPolicy::Replace(r) = self.policy {
+                out.push(r);
+            }
+        }
+        Ok(Cow::Owned(out))
+    }
+
+    fn as_char_mapper(&self, _ctx: &Context) -> Option<&dyn CharMapper> {
+        match self.policy {
+            ControlCharPolicy::Replace(_) => Some(self),
+            ControlCharPolicy::Remove => None,
+        }
+    }
+
+    fn options(&self) -> String {
+        match self.policy {
+            ControlCharPolicy::Remove => "policy=remove".to_owned(),
+            ControlCharPolicy::Replace(c) => format!("policy=replace:U+{:04X}", c as u32),
+        }
+    }
+}
+
+impl CharMapper for NormalizeControlChars {
+    #[inline(always)]
+    fn map_char(&self, c: char, _ctx: &Context) -> char {
+        match self.policy {
+            ControlCharPolicy::Replace(r) if is_control(c) => r,
+            _ => c,
+        }
+    }
+}
+
+impl StaticFusableStage for NormalizeControlChars {
+    type Adapter<'a, I>
+        = NormalizeControlCharsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        NormalizeControlCharsAdapter {
+            input,
+            policy: self.policy,
+        }
+    }
+}
+
+pub struct NormalizeControlCharsAdapter<I> {
+    input: I,
+    policy: ControlCharPolicy,
+}
+
+impl<I: Iterator<Item = char>> Iterator for NormalizeControlCharsAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.policy {
+            ControlCharPolicy::Remove => self.input.find(|&c| !is_control(c)),
+            ControlCharPolicy::Replace(r) => {
+                self.input.next().map(|c| if is_control(c) { r } else { c })
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.policy {
+            ControlCharPolicy::Remove => (0, self.input.size_hint().1),
+            ControlCharPolicy::Replace(_) => self.input.size_hint(),
+        }
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for NormalizeControlCharsAdapter<I> {}
+
+impl StageTestConfig for NormalizeControlChars {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "hello\u{0001}world\u{007F}",
+            "clean text only",
+            "\u{001F}start\u{0085}middle\u{009F}end",
+            "bell\u{0007}",
+            "a\u{0000}b\u{001B}c\u{009F}",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "clean text only",
+            "hello world",
+            "test123",
+            "already \u{FFFD}",
+            "",
+        ]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("hello\u{0007}world", "hello\u{FFFD}world"),
+            ("\u{0000}start", "\u{FFFD}start"),
+            ("a\u{007F}b\u{0085}c", "a\u{FFFD}b\u{FFFD}c"),
+        ]
+    }
+}
+
 #[cfg(test)]
 mod contract_tests {
     use super::*;
     use crate::assert_stage_contract;
+    use crate::testing::stage_contract::fused_path_equivalent_to_apply;
 
     #[test]
     fn universal_contract_compliance() {
         assert_stage_contract!(StripControlChars);
+        assert_stage_contract!(REPLACE_CONTROL_CHARS);
+        // Shares the samples; only the expected transforms differ
+        for policy in [ControlCharPolicy::Remove, ControlCharPolicy::Replace('?')] {
+            fused_path_equivalent_to_apply(NormalizeControlChars { policy });
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::unicode::is_control;
+    use super::*;
+    use crate::{ENG, unicode::is_control};
+
+    #[test]
+    fn replace_policy_preserves_char_positions() {
+        let ctx = Context::new(ENG);
+        let input = "ring\u{0007}the bell";
+        let out = REPLACE_CONTROL_CHARS
+            .apply(Cow::Borrowed(input), &ctx)
+            .unwrap();
+
+        assert_eq!(out, "ring\u{FFFD}the bell");
+        assert_eq!(out.chars().count(), input.chars().count());
+        assert_eq!(out.chars().position(|c| c == '\u{FFFD}'), Some(4));
+    }
+
+    #[test]
+    fn policies_remove_or_replace() {
+        let ctx = Context::new(ENG);
+        let input = "a\u{0000}b\u{001B}c\u{009F}";
+        let cases = [
+            (ControlCharPolicy::Remove, "abc"),
+            (ControlCharPolicy::Replace('?'), "a?b?c?"),
+            (
+                ControlCharPolicy::Replace('\u{FFFD}'),
+                "a\u{FFFD}b\u{FFFD}c\u{FFFD}",
+            ),
+        ];
+
+        for (policy, expected) in cases {
+            let stage = NormalizeControlChars { policy };
+            assert_eq!(stage.apply(Cow::Borrowed(input), &ctx).unwrap(), expected);
+        }
+    }
 
     #[test]
     fn test_cc_vs_cf_boundary() {
@@ -3,10 +3,25 @@ use crate::{
     lang::Lang,
     stage::{Stage, StageError, StaticFusableStage},
     testing::stage_contract::StageTestConfig,
-    unicode::{contains_format_controls, is_format_control},
+    unicode::{is_combining_mark, is_format_control},
 };
 use std::borrow::Cow;
-use std::iter::FusedIterator;
+use std::iter::{FusedIterator, Peekable};
+
+/// COMBINING GRAPHEME JOINER
+const CGJ: char = '\u{034F}';
+
+/// HANGUL FILLER and HALFWIDTH HANGUL FILLER
+#[inline(always)]
+const fn is_hangul_filler(c: char) -> bool {
+    matches!(c, '\u{3164}' | '\u{FFA0}')
+}
+
+/// Dropped wherever it appears: a format control or a Hangul filler
+#[inline(always)]
+const fn is_invisible(c: char) -> bool {
+    is_format_control(c) || is_hangul_filler(c)
+}
 
 /// Removes Unicode format control characters (General Category Cf).
 ///
@@ -16,6 +31,17 @@ use std::iter::FusedIterator;
 /// - Bidirectional marks/overrides (LRM, RLM, LRE, etc.)
 /// - Byte Order Mark (BOM, U+FEFF)
 /// - Word joiner and invisible operators
+/// - Stray combining grapheme joiners (CGJ, U+034F)
+/// - Hangul fillers (U+3164, U+FFA0)
+///
+/// CGJ is only meaningful directly before a combining mark, where it blocks
+/// canonical reordering (e.g. Hebrew point order). There it is **preserved**, so
+/// canonical equivalence is unchanged; anywhere else it is invisible noise and
+/// removed.
+///
+/// The Hangul fillers are letters (Lo), not Cf, but render as blank space and
+/// are a common way to pad nicknames or hide text. The conjoining jamo fillers
+/// U+115F/U+1160 are kept: they are parts of composed syllable sequences.
 ///
 /// These characters can disrupt tokenization, search matching, or cause security issues
 /// in user-generated content. General control characters (Cc) are **preserved** — use
@@ -35,17 +61,31 @@ impl Stage for StripFormatControls {
     #[inline(always)]
     fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
         // Fast, predictable scan — 99%+ of real text has no Cf
-        Ok(contains_format_controls(text))
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if is_invisible(c) {
+                return Ok(true);
+            }
+            // Anything dropped between a CGJ and its mark is a change too
+            if c == CGJ
+                && (chars.peek().is_some_and(|&n| is_invisible(n) || n == CGJ)
+                    || is_stray_cgj(&mut chars))
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
     }
 
     fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
         // Direct allocation path: avoid iterator abstraction for single-stage execution
         let mut out = String::with_capacity(text.len());
-        for c in text.chars() {
-            if !is_format_control(c) {
-                out.push(c);
-            }
-        }
+        out.extend(StripFormatControlsAdapter {
+            input: text.chars().peekable(),
+        });
         Ok(Cow::Owned(out))
     }
 }
@@ -66,12 +106,28 @@ impl StaticFusableStage for StripFormatControls {
     where
         I: FusedIterator<Item = char> + 'a,
     {
-        StripFormatControlsAdapter { input }
+        StripFormatControlsAdapter {
+            input: input.peekable(),
+        }
     }
 }
 
-pub struct StripFormatControlsAdapter<I> {
-    input: I,
+/// Called right after a CGJ: `true` when it does not guard a combining mark.
+///
+/// Format controls, fillers and further CGJs in between are consumed – they are
+/// dropped anyway, so a run of CGJs collapses to at most one in a single pass and
+/// the decision stays stable across repeated passes. CGJ itself falls in the
+/// combining range, so it is excluded from the lookahead.
+#[inline]
+fn is_stray_cgj<I: Iterator<Item = char>>(rest: &mut Peekable<I>) -> bool {
+    while rest.next_if(|&c| is_invisible(c) || c == CGJ).is_some() {}
+    !rest
+        .peek()
+        .is_some_and(|&c| c != CGJ && is_combining_mark(c))
+}
+
+pub struct StripFormatControlsAdapter<I: Iterator<Item = char>> {
+    input: Peekable<I>,
 }
 
 impl<I: Iterator<Item = char>> Iterator for StripFormatControlsAdapter<I> {
@@ -79,8 +135,13 @@ impl<I: Iterator<Item = char>> Iterator for StripFormatControlsAdapter<I> {
 
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
-        // Skips characters until is_format_control(c) returns false
-        self.input.find(|&c| !is_format_control(c))
+        loop {
+            let c = self.input.next()?;
+            if is_invisible(c) || (c == CGJ && is_stray_cgj(&mut self.input)) {
+                continue;
+            }
+            return Some(c);
+        }
     }
 
     #[inline]
@@ -99,18 +160,32 @@ impl StageTestConfig for StripFormatControls {
 
     fn samples(_lang: Lang) -> &'static [&'static str] {
         &[
-            "hello\u{200B}world",       // ZWSP
-            "\u{FEFF}bommed",           // BOM
-            "Arabic\u{200F}text",       // RLM
-            "a\u{2066}b\u{2069}c",      // LRI/PDI
-            "text\u{200D}join\u{200C}", // ZWJ/ZWNJ
-            "word\u{2060}joiner",       // Word joiner
+            "hello\u{200B}world",        // ZWSP
+            "\u{FEFF}bommed",            // BOM
+            "Arabic\u{200F}text",        // RLM
+            "a\u{2066}b\u{2069}c",       // LRI/PDI
+            "text\u{200D}join\u{200C}",  // ZWJ/ZWNJ
+            "word\u{2060}joiner",        // Word joiner
+            "a\u{034F}b",                // Stray CGJ
+            "a\u{034F}\u{0301}",         // CGJ guarding a mark
+            "a\u{034F}\u{034F}b",        // Doubled stray CGJ
+            "a\u{034F}\u{034F}\u{0301}", // Doubled CGJ guarding a mark
+            "a\u{034F}\u{200D}\u{0301}", // ZWJ between CGJ and its mark
+            "안녕\u{3164}하세요",        // Hangul filler
+            "\u{FFA0}\u{FFA0}pad",       // Halfwidth Hangul filler
+            "\u{3164}안녕\u{3164}하세요\u{FFA0}",
             "clean text",
         ]
     }
 
     fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
-        &["clean text", "hello world", "test123", ""]
+        &[
+            "clean text",
+            "hello world",
+            "test123",
+            "a\u{034F}\u{0301}",
+            "",
+        ]
     }
 
     fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
@@ -120,6 +195,11 @@ impl StageTestConfig for StripFormatControls {
             ("a\u{200E}b", "ab"),
             ("join\u{200D}me", "joinme"),
             ("no\u{2060}break", "nobreak"),
+            ("stray\u{034F}cgj\u{034F}", "straycgj"),
+            ("a\u{034F}\u{034F}b", "ab"),
+            ("a\u{034F}\u{034F}\u{0301}", "a\u{034F}\u{0301}"),
+            ("\u{3164}name", "name"),
+            ("pad\u{FFA0}", "pad"),
         ]
     }
 }
@@ -138,7 +218,7 @@ mod contract_tests {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::lang::data::ENG;
+    use crate::lang::data::{ENG, KOR};
 
     #[test]
     fn test_non_latin_scripts() {
@@ -170,4 +250,40 @@ mod tests {
             "Привет مرحبا"
         );
     }
+
+    #[test]
+    fn cgj_is_removed_only_when_stray() {
+        let stage = StripFormatControls;
+        let ctx = Context::new(ENG);
+
+        assert_eq!(
+            stage.apply(Cow::Borrowed("ab\u{034F}c"), &ctx).unwrap(),
+            "abc"
+        );
+
+        // Hebrew: CGJ keeps patah before hiriq, blocking canonical reordering
+        let guarded = "\u{05D9}\u{05B7}\u{034F}\u{05B4}";
+        assert!(!stage.needs_apply(guarded, &ctx).unwrap());
+
+        // A ZWJ between CGJ and the mark is dropped, CGJ still guards the mark
+        let out = stage
+            .apply(Cow::Borrowed("a\u{034F}\u{200D}\u{0301}"), &ctx)
+            .unwrap();
+        assert_eq!(out, "a\u{034F}\u{0301}");
+        assert!(!stage.needs_apply(&out, &ctx).unwrap());
+    }
+
+    #[test]
+    fn hangul_fillers_are_removed_from_korean_text() {
+        let stage = StripFormatControls;
+        let ctx = Context::new(KOR);
+
+        let input = "\u{3164}안녕\u{3164}하세요\u{FFA0}";
+        assert!(stage.needs_apply(input, &ctx).unwrap());
+        let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "안녕하세요");
+
+        // Old Hangul written with a conjoining choseong filler is left intact
+        assert!(!stage.needs_apply("\u{115F}\u{1161}", &ctx).unwrap());
+    }
 }
@@ -0,0 +1,149 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Removes Ideographic Description Characters (U+2FF0–2FFF, U+31EF).
+///
+/// IDCs are structural operators such as `⿰` (left-to-right) and `⿱`
+/// (above-to-below) that describe how a CJK character is composed, e.g. `⿰木木`
+/// for `林`. Outside dictionaries and font tooling they are noise for search.
+///
+/// Only the operators are removed – the component characters of a description
+/// sequence are ordinary ideographs and are kept, so `⿰木木` becomes `木木`.
+///
+/// Zero-copy when no IDCs are present. Fully fusable filter.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StripIdeographicDescriptionChars;
+
+#[inline(always)]
+fn is_ideographic_description_char(c: char) -> bool {
+    matches!(c, '\u{2FF0}'..='\u{2FFF}' | '\u{31EF}')
+}
+
+impl Stage for StripIdeographicDescriptionChars {
+    fn name(&self) -> &'static str {
+        "strip_ideographic_description_chars"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(is_ideographic_description_char))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(
+            text.chars()
+                .filter(|&c| !is_ideographic_description_char(c)),
+        );
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for StripIdeographicDescriptionChars {
+    type Adapter<'a, I>
+        = StripIdeographicDescriptionCharsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        StripIdeographicDescriptionCharsAdapter { input }
+    }
+}
+
+pub struct StripIdeographicDescriptionCharsAdapter<I> {
+    input: I,
+}
+
+impl<I: Iterator<Item = char>> Iterator for StripIdeographicDescriptionCharsAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.find(|&c| !is_ideographic_description_char(c))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.input.size_hint();
+        (0, upper) // Can only shrink
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for StripIdeographicDescriptionCharsAdapter<I> {}
+
+impl StageTestConfig for StripIdeographicDescriptionChars {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "⿰木木",
+            "字形 ⿱宀子 は「字」",
+            "⿲⿳⿴⿵⿶⿷⿸⿹⿺⿻",
+            "“林”写作⿰木木，“森”写作⿱木⿰木木。",
+            "\u{2FFC}\u{2FFF}\u{31EF}",
+            "漢字",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["漢字", "林", "hello world", "⺀⻳", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("⿰木木", "木木"),
+            ("⿱⿰木木木", "木木木"),
+            ("a\u{31EF}b", "ab"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(StripIdeographicDescriptionChars);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ZHO;
+
+    #[test]
+    fn description_sequence_is_stripped_inside_prose() {
+        let ctx = Context::new(ZHO);
+        let stage = StripIdeographicDescriptionChars;
+
+        let input = "“林”写作⿰木木，“森”写作⿱木⿰木木。";
+        let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "“林”写作木木，“森”写作木木木。");
+    }
+}
@@ -96,10 +96,8 @@ impl Stage for StripMarkdown {
                     | Tag::Item
                     | Tag::Table(_)
                     | Tag::TableRow,
-                ) => {
-                    if !out.is_empty() && !out.ends_with('\n') {
-                        out.push('\n');
-                    }
+                ) if !out.is_empty() && !out.ends_with('\n') => {
+                    out.push('\n');
                 }
 
                 // BLOCK ENDS → ensure newline separation
@@ -113,10 +111,8 @@ impl Stage for StripMarkdown {
                     | TagEnd::Table
                     | TagEnd::TableHead
                     | TagEnd::TableRow,
-                ) => {
-                    if !out.ends_with('\n') {
-                        out.push('\n');
-                    }
+                ) if !out.ends_with('\n') => {
+                    out.push('\n');
                 }
 
                 // TABLE CELL HANDLING
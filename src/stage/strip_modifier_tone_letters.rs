@@ -0,0 +1,196 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Removes Modifier Tone Letters (U+A700–A71F).
+///
+/// The block holds phonetic-transcription symbols: the Chinese tone marks
+/// (`꜀`–`꜇`), the dotted and left-stem tone bars (`꜈`–`꜖`) and raised
+/// arrows and exclamation marks (`ꜛ ꜜ ꜝ`). Outside linguistic text they are
+/// noise that breaks word matches – `ma꜌` should find `ma`.
+///
+/// With `keep_tone_bars`, the tone bars U+A708–A716 are kept, so a
+/// transcription keeps its tone contours while the other symbols go. The
+/// Chao tone letters `˥˦˧˨˩` (U+02E5–02E9) live in another block and are
+/// never touched.
+///
+/// Common presets:
+/// - `STRIP_MODIFIER_TONE_LETTERS`: remove the whole block (default)
+/// - `KEEP_TONE_BARS`: remove everything but the tone bars
+///
+/// Zero-copy when no modifier tone letters are present. Fully fusable filter.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Clone, Copy)]
+pub struct StripModifierToneLetters {
+    pub keep_tone_bars: bool,
+}
+
+/// Remove every modifier tone letter.
+pub const STRIP_MODIFIER_TONE_LETTERS: StripModifierToneLetters = StripModifierToneLetters {
+    keep_tone_bars: false,
+};
+
+/// Keep the tone bars U+A708–A716 for linguistic text, remove the rest.
+pub const KEEP_TONE_BARS: StripModifierToneLetters = StripModifierToneLetters {
+    keep_tone_bars: true,
+};
+
+impl Default for StripModifierToneLetters {
+    fn default() -> Self {
+        STRIP_MODIFIER_TONE_LETTERS
+    }
+}
+
+impl StripModifierToneLetters {
+    #[inline(always)]
+    fn strips(&self, c: char) -> bool {
+        match c {
+            '\u{A708}'..='\u{A716}' => !self.keep_tone_bars,
+            '\u{A700}'..='\u{A71F}' => true,
+            _ => false,
+        }
+    }
+}
+
+impl Stage for StripModifierToneLetters {
+    fn name(&self) -> &'static str {
+        "strip_modifier_tone_letters"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(|c| self.strips(c)))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(text.chars().filter(|&c| !self.strips(c)));
+        Ok(Cow::Owned(out))
+    }
+
+    fn options(&self) -> String {
+        format!("keep_tone_bars={}", self.keep_tone_bars)
+    }
+}
+
+impl StaticFusableStage for StripModifierToneLetters {
+    type Adapter<'a, I>
+        = StripModifierToneLettersAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        StripModifierToneLettersAdapter {
+            input,
+            stage: *self,
+        }
+    }
+}
+
+pub struct StripModifierToneLettersAdapter<I> {
+    input: I,
+    stage: StripModifierToneLetters,
+}
+
+impl<I: Iterator<Item = char>> Iterator for StripModifierToneLettersAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let stage = self.stage;
+        self.input.find(|&c| !stage.strips(c))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.input.size_hint();
+        (0, upper) // Can only shrink
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for StripModifierToneLettersAdapter<I> {}
+
+impl StageTestConfig for StripModifierToneLetters {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "ma꜌ ma꜍ ma꜎",
+            "\u{A700}\u{A701}\u{A702}\u{A703}\u{A704}\u{A705}\u{A706}\u{A707}",
+            "꜈꜉꜊꜋꜌꜍꜎꜏꜐꜑꜒꜓꜔꜕꜖",
+            "bʊk꜕ ꜛhigh ꜜlow",
+            "tone ˥˩ ma˧",
+            "plain text",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["plain text", "ma˥˩", "ʃʊk", "hello world", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("ma꜌", "ma"),
+            ("꜀ba", "ba"),
+            ("ꜛhigh ꜜlow", "high low"),
+            ("\u{A71F}", ""),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(StripModifierToneLetters::default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tone_letters_are_removed_from_transcription() {
+        let ctx = Context::default();
+        // Cantonese syllables with dotted tone bars and a downstep arrow
+        let input = "si꜒ si꜔ ꜜsi꜖";
+
+        let out = STRIP_MODIFIER_TONE_LETTERS
+            .apply(Cow::Borrowed(input), &ctx)
+            .unwrap();
+        assert_eq!(out, "si si si");
+        let fused: String = STRIP_MODIFIER_TONE_LETTERS
+            .static_fused_adapter(input.chars(), &ctx)
+            .collect();
+        assert_eq!(fused, out);
+
+        let kept = KEEP_TONE_BARS.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(kept, "si꜒ si꜔ si꜖");
+        assert!(!KEEP_TONE_BARS.needs_apply("si꜒ si꜔", &ctx).unwrap());
+    }
+}
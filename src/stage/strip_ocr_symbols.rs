@@ -0,0 +1,146 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Removes Optical Character Recognition symbols (U+2440–245F).
+///
+/// The OCR block holds the OCR-A control glyphs (`⑀` hook, `⑁` chair, `⑂` fork,
+/// …) and the MICR banking symbols (`⑆` transit, `⑇` amount, `⑈` on-us, `⑉`
+/// dash). They leak into scraped cheques and scanned forms but carry nothing
+/// searchable. The unassigned tail of the block is removed as well.
+///
+/// Zero-copy when no OCR symbols are present. Fully fusable filter.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StripOcrSymbols;
+
+#[inline(always)]
+fn is_ocr_symbol(c: char) -> bool {
+    matches!(c, '\u{2440}'..='\u{245F}')
+}
+
+impl Stage for StripOcrSymbols {
+    fn name(&self) -> &'static str {
+        "strip_ocr_symbols"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(is_ocr_symbol))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(text.chars().filter(|&c| !is_ocr_symbol(c)));
+        Ok(Cow::Owned(out))
+    }
+}
+
+impl StaticFusableStage for StripOcrSymbols {
+    type Adapter<'a, I>
+        = StripOcrSymbolsAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        StripOcrSymbolsAdapter { input }
+    }
+}
+
+pub struct StripOcrSymbolsAdapter<I> {
+    input: I,
+}
+
+impl<I: Iterator<Item = char>> Iterator for StripOcrSymbolsAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.find(|&c| !is_ocr_symbol(c))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.input.size_hint();
+        (0, upper) // Can only shrink
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for StripOcrSymbolsAdapter<I> {}
+
+impl StageTestConfig for StripOcrSymbols {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "⑆021000021⑆ 123456789⑈",
+            "\u{2440}\u{2441}\u{2442}",
+            "amount ⑇00001500⑇",
+            "⑈001234⑈ ⑆021000021⑆ 987654321⑈",
+            "\u{245F}",
+            "plain text",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["plain text", "①②③", "⑴⑵", "hello world", ""]
+    }
+
+    fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("⑆021000021⑆", "021000021"),
+            ("a\u{2440}b", "ab"),
+            ("\u{244A}", ""),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(StripOcrSymbols);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ENG;
+
+    #[test]
+    fn micr_symbols_are_stripped_from_cheque_line() {
+        let ctx = Context::new(ENG);
+        let stage = StripOcrSymbols;
+
+        let input = "⑈001234⑈ ⑆021000021⑆ 987654321⑈";
+        let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "001234 021000021 987654321");
+        // Circled digits next door (U+2460) are not OCR symbols
+        assert!(!stage.needs_apply("① 021000021", &ctx).unwrap());
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{CharMapper, Stage, StageError, StaticFusableStage},
+    testing::stage_contract::StageTestConfig,
+    unicode::is_private_use,
+};
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Removes or replaces Private Use Area code points.
+///
+/// PUA characters (U+E000–F8FF and the supplementary planes U+F0000–10FFFD)
+/// have no standard meaning: in scraped or OCR'd text they are icon-font glyphs
+/// or vendor-specific symbols that render as garbage anywhere else.
+///
+/// With `replacement: None` they are dropped; with `Some(c)` every PUA char is
+/// substituted one-for-one, keeping character offsets stable.
+///
+/// Common presets:
+/// - `STRIP_PRIVATE_USE`: drop every PUA char (default)
+/// - `REPLACE_PRIVATE_USE`: replace every PUA char with U+FFFD
+///
+/// Zero-copy when no PUA characters are present.
+///
+/// This stage is eligible for static fusion in all supported languages.
+#[derive(Debug, Clone, Copy)]
+pub struct StripPrivateUse {
+    /// Substitute for each PUA char; `None` removes it
+    pub replacement: Option<char>,
+}
+
+/// Drop Private Use Area characters.
+pub const STRIP_PRIVATE_USE: StripPrivateUse = StripPrivateUse { replacement: None };
+
+/// Replace Private Use Area characters with U+FFFD REPLACEMENT CHARACTER.
+pub const REPLACE_PRIVATE_USE: StripPrivateUse = StripPrivateUse {
+    replacement: Some('\u{FFFD}'),
+};
+
+impl Default for StripPrivateUse {
+    fn default() -> Self {
+        STRIP_PRIVATE_USE
+    }
+}
+
+impl StripPrivateUse {
+    // A PUA char used as its own replacement is a no-op, not a change
+    #[inline(always)]
+    fn changes(&self, c: char) -> bool {
+        is_private_use(c) && self.replacement != Some(c)
+    }
+}
+
+#[inline(always)]
+fn map_private_use(c: char, replacement: Option<char>) -> Option<char> {
+    if is_private_use(c) {
+        replacement
+    } else {
+        Some(c)
+    }
+}
+
+impl Stage for StripPrivateUse {
+    fn name(&self) -> &'static str {
+        "strip_private_use"
+    }
+
+    #[inline(always)]
+    fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(|c| self.changes(c)))
+    }
+
+    fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
+        let mut out = String::with_capacity(text.len());
+        out.extend(
+            text.chars()
+                .filter_map(|c| map_private_use(c, self.replacement)),
+        );
+        Ok(Cow::Owned(out))
+    }
+
+    fn as_char_mapper(&self, _ctx: &Context) -> Option<&dyn CharMapper> {
+        self.replacement.map(|_| self as &dyn CharMapper)
+    }
+
+    fn options(&self) -> String {
+        self.replacement
+            .map(|c| format!("replacement=U+{:04X}", c as u32))
+            .unwrap_or_default()
+    }
+}
+
+impl CharMapper for StripPrivateUse {
+    #[inline(always)]
+    fn map_char(&self, c: char, _ctx: &Context) -> char {
+        map_private_use(c, self.replacement).unwrap_or(c)
+    }
+}
+
+impl StaticFusableStage for StripPrivateUse {
+    type Adapter<'a, I>
+        = StripPrivateUseAdapter<I>
+    where
+        I: FusedIterator<Item = char> + 'a;
+
+    #[inline(always)]
+    fn supports_static_fusion(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn static_fused_adapter<'a, I>(&self, input: I, _ctx: &'a Context) -> Self::Adapter<'a, I>
+    where
+        I: FusedIterator<Item = char> + 'a,
+    {
+        StripPrivateUseAdapter {
+            input,
+            replacement: self.replacement,
+        }
+    }
+}
+
+pub struct StripPrivateUseAdapter<I> {
+    input: I,
+    replacement: Option<char>,
+}
+
+impl<I: Iterator<Item = char>> Iterator for StripPrivateUseAdapter<I> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let replacement = self.replacement;
+        self.input
+            .by_ref()
+            .find_map(|c| map_private_use(c, replacement))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.replacement {
+            None => (0, self.input.size_hint().1),
+            Some(_) => self.input.size_hint(),
+        }
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for StripPrivateUseAdapter<I> {}
+
+impl StageTestConfig for StripPrivateUse {
+    fn one_to_one_languages() -> &'static [Lang] {
+        &[]
+    }
+
+    fn samples(_lang: Lang) -> &'static [&'static str] {
+        &[
+            "icon\u{E001} label",
+            "\u{F8FF}apple logo",
+            "plane15 \u{F0000} plane16 \u{10FFFD}",
+            "a\u{E000}b\u{100000}c",
+            "clean text",
+            "",
+        ]
+    }
+
+    fn should_pass_through(_lang: Lang) -> &'static [&'static str] {
+        &["clean text", "hello world", "日本語", "test123", ""]
+    }
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::assert_stage_contract;
+
+    #[test]
+    fn universal_contract_compliance() {
+        assert_stage_contract!(STRIP_PRIVATE_USE);
+        assert_stage_contract!(REPLACE_PRIVATE_USE);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ENG;
+
+    #[test]
+    fn removes_private_use_from_prose() {
+        let ctx = Context::new(ENG);
+        let out = STRIP_PRIVATE_USE
+            .apply(Cow::Borrowed("Call \u{E0B0}us\u{F0001} today"), &ctx)
+            .unwrap();
+        assert_eq!(out, "Call us today");
+
+        // Ordinary non-ASCII text is left alone
+        assert!(
+            !STRIP_PRIVATE_USE
+                .needs_apply("Grüße, 你好 🎉", &ctx)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn replacement_preserves_char_positions() {
+        let ctx = Context::new(ENG);
+        let input = "a\u{E000}b\u{100000}c";
+        let cases = [
+            (STRIP_PRIVATE_USE, "abc"),
+            (REPLACE_PRIVATE_USE, "a\u{FFFD}b\u{FFFD}c"),
+        ];
+
+        for (stage, expected) in cases {
+            assert_eq!(stage.apply(Cow::Borrowed(input), &ctx).unwrap(), expected);
+        }
+        // Replacing a PUA char with itself is not a change
+        let identity = StripPrivateUse {
+            replacement: Some('\u{E000}'),
+        };
+        assert!(!identity.needs_apply("\u{E000}", &ctx).unwrap());
+    }
+}
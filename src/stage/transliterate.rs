@@ -1,12 +1,27 @@
 use crate::{
-    CAT, DAN, DEU, FRA, ISL, NOR, SWE,
+    CAT, DAN, DEU, FRA, ISL, NOR, RUS, SWE,
     context::Context,
     lang::{Lang, LangEntry},
     stage::{FusedIterator, Stage, StageError, StaticFusableStage},
     testing::stage_contract::StageTestConfig,
 };
+use smallvec::SmallVec;
 use std::borrow::Cow;
 
+/// What `Transliterate` emits for a non-ASCII char its language has no
+/// mapping for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransliterateFallback {
+    /// Keep the char as it is
+    PassThrough,
+    /// Remove the char
+    Drop,
+    /// Substitute this char
+    Replace(char),
+    /// Write the code point as `\u{XXXX}` (four or more uppercase hex digits)
+    Escape,
+}
+
 /// Performs locale-aware orthographic transliteration (lossy, opt-in).
 ///
 /// This stage applies language-specific multi-character expansions using the
@@ -17,14 +32,102 @@ use std::borrow::Cow;
 /// - Nordic (Danish/Norwegian/Swedish): Å→aa, Æ→ae, Ø→oe
 /// - Icelandic: Þ→th, Ð→d, Æ→ae
 ///
-/// Rules are applied strictly per language — foreign characters are preserved.
-/// Case is preserved (maps exist for both upper and lower forms).
+/// Rules are applied strictly per language. Case is preserved (maps exist for
+/// both upper and lower forms).
+///
+/// `fallback` decides what happens to non-ASCII chars without a mapping –
+/// foreign letters, or a Cyrillic letter missing from the RUS map. By default
+/// they pass through; for ASCII-only output they can be dropped, replaced
+/// (`?`), or escaped as `\u{XXXX}` (`ü` → `\u{00FC}` outside German). The
+/// braces keep escapes unambiguous next to hex digits and for astral chars. Run
+/// `RemoveDiacritics` first so accented Latin letters reach their base
+/// instead of the fallback.
+///
+/// Some map targets are not ASCII themselves (RUS `ч` → `č`). Any fallback but
+/// `PassThrough` also applies to those chars, so `ч` becomes `\u{010D}` when
+/// escaping and the output stays stable under a second pass.
+///
+/// The fallback is a single option: `fallback=pass_through`, `fallback=drop`,
+/// `fallback=escape` or `fallback=replace:<char>`, where `<char>` is a char or
+/// `U+XXXX`.
+///
+/// Common presets:
+/// - `TRANSLITERATE`: unmapped chars pass through (default)
+/// - `TRANSLITERATE_ESCAPED`: remaining non-ASCII chars become `\u{XXXX}`, output is ASCII
 ///
 /// Zero-copy when no transliteration rules apply or no matches found.
 ///
 /// This stage is eligible for static fusion in all supported languages.
-#[derive(Debug, Default, Clone, Copy)]
-pub struct Transliterate;
+#[derive(Debug, Clone, Copy)]
+pub struct Transliterate {
+    pub fallback: TransliterateFallback,
+}
+
+/// Transliterate mapped chars, preserve everything else.
+pub const TRANSLITERATE: Transliterate = Transliterate {
+    fallback: TransliterateFallback::PassThrough,
+};
+
+/// Transliterate mapped chars and escape every non-ASCII char left over.
+pub const TRANSLITERATE_ESCAPED: Transliterate = Transliterate {
+    fallback: TransliterateFallback::Escape,
+};
+
+impl Default for Transliterate {
+    fn default() -> Self {
+        TRANSLITERATE
+    }
+}
+
+impl Transliterate {
+    /// `c` has no mapping and the fallback changes it
+    #[inline(always)]
+    fn falls_back(&self, c: char, entry: &LangEntry) -> bool {
+        match self.fallback {
+            TransliterateFallback::PassThrough => false,
+            TransliterateFallback::Replace(r) if r == c => false,
+            _ => !c.is_ascii() && !entry.is_transliterable(c),
+        }
+    }
+}
+
+/// Emit the fallback for an unmapped `c` through `push`
+#[inline]
+fn push_fallback(fallback: TransliterateFallback, c: char, mut push: impl FnMut(char)) {
+    match fallback {
+        TransliterateFallback::PassThrough => push(c),
+        TransliterateFallback::Drop => {}
+        TransliterateFallback::Replace(r) => push(r),
+        TransliterateFallback::Escape => push_escaped(c, push),
+    }
+}
+
+/// Emit a map target through `push`, its non-ASCII chars through the fallback
+#[inline]
+fn push_expansion(fallback: TransliterateFallback, target: &str, mut push: impl FnMut(char)) {
+    for c in target.chars() {
+        if c.is_ascii() {
+            push(c);
+        } else {
+            push_fallback(fallback, c, &mut push);
+        }
+    }
+}
+
+/// Emit `c` as `\u{XXXX}` (at least four uppercase hex digits) through `push`
+#[inline]
+pub(crate) fn push_escaped(c: char, mut push: impl FnMut(char)) {
+    let cp = c as u32;
+    let digits = (32 - cp.leading_zeros()).div_ceil(4).max(4);
+    push('\\');
+    push('u');
+    push('{');
+    for i in (0..digits).rev() {
+        let digit = char::from_digit((cp >> (i * 4)) & 0xF, 16).unwrap_or('0');
+        push(digit.to_ascii_uppercase());
+    }
+    push('}');
+}
 
 impl Stage for Transliterate {
     fn name(&self) -> &'static str {
@@ -35,11 +138,16 @@ impl Stage for Transliterate {
     fn needs_apply(&self, text: &str, ctx: &Context) -> Result<bool, StageError> {
         let entry = ctx.lang_entry;
 
-        if !entry.has_transliterate_map() || text.is_ascii() {
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        if self.fallback == TransliterateFallback::PassThrough && !entry.has_transliterate_map() {
             return Ok(false);
         }
 
-        Ok(text.chars().any(|c| entry.is_transliterable(c)))
+        Ok(text
+            .chars()
+            .any(|c| entry.is_transliterable(c) || self.falls_back(c, &entry)))
     }
 
     fn apply<'a>(&self, text: Cow<'a, str>, ctx: &Context) -> Result<Cow<'a, str>, StageError> {
@@ -56,13 +164,28 @@ impl Stage for Transliterate {
         let mut out = String::with_capacity(capacity);
         for c in text.chars() {
             if let Some(replacement) = entry.find_transliterate_map(c) {
-                out.push_str(replacement);
-            } else {
+                if self.fallback == TransliterateFallback::PassThrough {
+                    out.push_str(replacement);
+                } else {
+                    push_expansion(self.fallback, replacement, |c| out.push(c));
+                }
+            } else if c.is_ascii() {
                 out.push(c);
+            } else {
+                push_fallback(self.fallback, c, |c| out.push(c));
             }
         }
         Ok(Cow::Owned(out))
     }
+
+    fn options(&self) -> String {
+        match self.fallback {
+            TransliterateFallback::PassThrough => "fallback=pass_through".to_owned(),
+            TransliterateFallback::Drop => "fallback=drop".to_owned(),
+            TransliterateFallback::Replace(c) => format!("fallback=replace:U+{:04X}", c as u32),
+            TransliterateFallback::Escape => "fallback=escape".to_owned(),
+        }
+    }
 }
 
 impl StaticFusableStage for Transliterate {
@@ -85,6 +208,8 @@ impl StaticFusableStage for Transliterate {
             input,
             lang: &ctx.lang_entry,
             pending: None,
+            fallback: self.fallback,
+            fallback_pending: SmallVec::new(),
         }
     }
 }
@@ -94,6 +219,9 @@ pub struct TransliterateAdapter<'a, I> {
     lang: &'a LangEntry,
     /// Buffer for multi-character expansions (e.g. "oe", "ss")
     pending: Option<&'a str>,
+    fallback: TransliterateFallback,
+    /// Remaining chars of a fallback, stored in reverse
+    fallback_pending: SmallVec<[char; 16]>,
 }
 
 impl<'a, I: Iterator<Item = char>> Iterator for TransliterateAdapter<'a, I> {
@@ -101,7 +229,10 @@ impl<'a, I: Iterator<Item = char>> Iterator for TransliterateAdapter<'a, I> {
 
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
-        // 1. Drain pending expansion buffer first
+        // 1. Drain pending expansion buffers first
+        if let Some(c) = self.fallback_pending.pop() {
+            return Some(c);
+        }
         if let Some(pending_str) = self.pending {
             let mut chars = pending_str.chars();
             let first = chars.next().expect("Pending string should not be empty");
@@ -111,31 +242,51 @@ impl<'a, I: Iterator<Item = char>> Iterator for TransliterateAdapter<'a, I> {
             return Some(first);
         }
 
-        // 2. Pull next char from source
-        let c = self.input.next()?;
-
-        // 3. Look up in language-specific transliteration table
-        if let Some(replacement) = self.lang.find_transliterate_map(c) {
-            let mut chars = replacement.chars();
-            let first = chars
-                .next()
-                .expect("Replacement map entries must not be empty");
-            let rest = chars.as_str();
-
-            if !rest.is_empty() {
-                self.pending = Some(rest);
+        loop {
+            // 2. Pull next char from source
+            let c = self.input.next()?;
+
+            // 3. Look up in language-specific transliteration table
+            let fallback = self.fallback;
+            if let Some(replacement) = self.lang.find_transliterate_map(c) {
+                if fallback == TransliterateFallback::PassThrough || replacement.is_ascii() {
+                    let mut chars = replacement.chars();
+                    let first = chars
+                        .next()
+                        .expect("Replacement map entries must not be empty");
+                    let rest = chars.as_str();
+
+                    if !rest.is_empty() {
+                        self.pending = Some(rest);
+                    }
+                    return Some(first);
+                }
+                // Non-ASCII targets (RUS ч → č) go through the fallback as well
+                push_expansion(fallback, replacement, |c| self.fallback_pending.push(c));
+            } else if c.is_ascii() || fallback == TransliterateFallback::PassThrough {
+                // 4. Unmapped: ASCII always passes, the rest goes to the fallback
+                return Some(c);
+            } else {
+                push_fallback(fallback, c, |c| self.fallback_pending.push(c));
+            }
+            self.fallback_pending.reverse();
+            if let Some(c) = self.fallback_pending.pop() {
+                return Some(c);
             }
-            return Some(first);
         }
-
-        Some(c)
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         let (lower, upper) = self.input.size_hint();
         // Calculate pending length in characters (usually 1 or 0 for your data)
-        let pending_len = self.pending.map_or(0, |s| s.chars().count());
+        let pending_len =
+            self.pending.map_or(0, |s| s.chars().count()) + self.fallback_pending.len();
+
+        if self.fallback != TransliterateFallback::PassThrough {
+            // Drops shrink the text, escapes grow it far beyond 1:2
+            return (pending_len, None);
+        }
 
         // Lower bound: at least the remaining input + what's in pending
         let lower_bound = lower.saturating_add(pending_len);
@@ -161,6 +312,7 @@ impl StageTestConfig for Transliterate {
             DAN => &["Århus", "århus", "Øresund", "København"],
             DEU => &["Straße", "Fußgänger", "Weißwurst", "Äpfel"],
             CAT => &["Façade", "plaça", "Barça"],
+            RUS => &["мир λ", "чай", "Щука и ёж", "Ёлка", ""],
             _ => &["hello", "İstanbul", "café", ""],
         }
     }
@@ -235,7 +387,14 @@ mod contract_tests {
 
     #[test]
     fn universal_contract_compliance() {
-        assert_stage_contract!(Transliterate);
+        assert_stage_contract!(TRANSLITERATE);
+        assert_stage_contract!(TRANSLITERATE_ESCAPED);
+        assert_stage_contract!(Transliterate {
+            fallback: TransliterateFallback::Drop,
+        });
+        assert_stage_contract!(Transliterate {
+            fallback: TransliterateFallback::Replace('?'),
+        });
     }
 }
 
@@ -247,7 +406,7 @@ mod tests {
     #[test]
     fn test_language_isolation() {
         // Critical: Only applies rules for the context language
-        let stage = Transliterate;
+        let stage = TRANSLITERATE;
         let ctx = Context::new(FRA);
         let input = "ŒUVRE Århus Straße"; // French + Danish + German
         let result = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
@@ -260,4 +419,69 @@ mod tests {
         let result = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
         assert_eq!(result, "ŒUVRE aarhus Straße"); // Only Å→aa applies
     }
+
+    #[test]
+    fn unmapped_chars_follow_the_fallback() {
+        use crate::RUS;
+
+        // Greek has no RUS mapping; the Cyrillic around it does
+        let ctx = Context::new(RUS);
+        let input = "мир λ";
+        let cases = [
+            (TransliterateFallback::PassThrough, "mir λ"),
+            (TransliterateFallback::Drop, "mir "),
+            (TransliterateFallback::Replace('?'), "mir ?"),
+            (TransliterateFallback::Escape, "mir \\u{03BB}"),
+        ];
+
+        for (fallback, expected) in cases {
+            let stage = Transliterate { fallback };
+            assert_eq!(stage.apply(Cow::Borrowed(input), &ctx).unwrap(), expected);
+        }
+
+        // A language without a map still falls back, astral chars included
+        let ctx = Context::new(crate::ENG);
+        let out = TRANSLITERATE_ESCAPED
+            .apply(Cow::Borrowed("ok 😀"), &ctx)
+            .unwrap();
+        assert_eq!(out, "ok \\u{1F600}");
+        assert!(!TRANSLITERATE_ESCAPED.needs_apply(&out, &ctx).unwrap());
+        assert!(!TRANSLITERATE.needs_apply("λ 😀", &ctx).unwrap());
+    }
+
+    #[test]
+    fn non_ascii_map_targets_follow_the_fallback() {
+        let ctx = Context::new(RUS);
+        let cases = [
+            (TransliterateFallback::PassThrough, "čaj Ščuka"),
+            (TransliterateFallback::Drop, "aj uka"),
+            (TransliterateFallback::Replace('?'), "?aj ??uka"),
+            (
+                TransliterateFallback::Escape,
+                "\\u{010D}aj \\u{0160}\\u{010D}uka",
+            ),
+        ];
+
+        for (fallback, expected) in cases {
+            let stage = Transliterate { fallback };
+            let out = stage.apply(Cow::Borrowed("чай Щука"), &ctx).unwrap();
+            assert_eq!(out, expected, "{fallback:?}");
+        }
+    }
+
+    #[test]
+    fn escapes_stay_decodable_next_to_hex_digits() {
+        let ctx = Context::new(crate::ENG);
+        let escape = |text| {
+            TRANSLITERATE_ESCAPED
+                .apply(Cow::Borrowed(text), &ctx)
+                .unwrap()
+        };
+
+        // Astral char: not `\u1F51` followed by `F`
+        assert_eq!(escape("🔟"), "\\u{1F51F}");
+        // Hex digit right after an escape stays outside the braces
+        assert_eq!(escape("ü1"), "\\u{00FC}1");
+        assert_eq!(escape("λB"), "\\u{03BB}B");
+    }
 }
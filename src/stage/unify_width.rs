@@ -4,12 +4,17 @@ use crate::{
     lang::Lang,
     stage::{Stage, StageError, StaticFusableStage},
     testing::stage_contract::StageTestConfig,
-    unicode::{fullwidth_to_halfwidth, is_fullwidth},
+    unicode::{
+        compose_kana_voicing, fullwidth_to_halfwidth, halfwidth_katakana_to_fullwidth,
+        is_fullwidth, is_halfwidth_katakana, is_line_variant, is_width_variant_symbol,
+        line_variant_to_standard, width_variant_symbol_to_standard,
+    },
 };
 use std::borrow::Cow;
-use std::iter::FusedIterator;
+use std::iter::{FusedIterator, Peekable};
 
-/// Converts full-width (wide) ASCII forms to half-width (narrow) equivalents.
+/// Unifies character width: full-width ASCII becomes narrow, half-width katakana
+/// becomes wide.
 ///
 /// This stage maps full-width Latin letters, digits, punctuation, and ideographic
 /// space (U+3000) to their standard ASCII counterparts:
@@ -19,14 +24,71 @@ use std::iter::FusedIterator;
 /// - Full-width punctuation → ASCII equivalents
 /// - `　` (U+3000) → ` ` (U+0020)
 ///
+/// Half-width katakana (U+FF61–FF9F) are widened to standard katakana. A
+/// following voiced/semi-voiced sound mark is composed with its base rather than
+/// mapped separately (`ﾊﾟ` → `パ`, not `ハ゜`); a mark with no composable base
+/// becomes the spacing `゛`/`゜`.
+///
+/// The width variants of symbols (U+FFE0–FFEE) map to their standard forms:
+///
+/// - `￠￡￢￣￤￥￦` → `¢£¬¯¦¥₩`
+/// - `￨` → `│`, `￩￪￫￬` → `←↑→↓`, `￭` → `■`, `￮` → `○`
+///
+/// The low line and overline variants of the compatibility and small forms
+/// are unified the same way. With `＿` (U+FF3F) among the full-width ASCII,
+/// every underscore variant becomes `_`:
+///
+/// - `︳︴﹍﹎﹏` → `_` (vertical, dashed, centreline and wavy low lines)
+/// - `﹉﹊﹋﹌` → `‾` (overline variants; U+203E itself is kept, it has no
+///   ASCII equivalent)
+///
 /// Essential for CJK ↔ Latin search equivalence and consistent tokenization.
 ///
-/// Pure 1:1 mapping → zero-copy when no full-width characters present
-/// and maximum static fusion performance.
+/// With `keep_halfwidth_kana`, half-width katakana, their sound marks and the
+/// half-width CJK punctuation (U+FF61–FF9F) are left untouched, for pipelines
+/// feeding legacy systems that expect them. A half-width mark after a wide
+/// base is then preserved as well (`ハﾟ` stays `ハﾟ`). Latin, digits and
+/// symbols are still unified.
+///
+/// Common presets:
+/// - `UNIFY_WIDTH`: unify both directions (default)
+/// - `UNIFY_WIDTH_KEEP_KANA`: narrow full-width ASCII, keep half-width kana
+///
+/// 1:1 mapping except for composed sound marks (`UNIFY_WIDTH` only;
+/// `UNIFY_WIDTH_KEEP_KANA` is purely 1:1) → zero-copy when no full-width or
+/// half-width characters are present and maximum static fusion performance.
 ///
 /// This stage is eligible for static fusion in all supported languages.
-#[derive(Debug, Default, Clone, Copy)]
-pub struct UnifyWidth;
+#[derive(Debug, Clone, Copy)]
+pub struct UnifyWidth {
+    pub keep_halfwidth_kana: bool,
+}
+
+/// Narrow full-width ASCII and widen half-width katakana.
+pub const UNIFY_WIDTH: UnifyWidth = UnifyWidth {
+    keep_halfwidth_kana: false,
+};
+
+/// Narrow full-width ASCII and symbols, leaving half-width katakana as is.
+pub const UNIFY_WIDTH_KEEP_KANA: UnifyWidth = UnifyWidth {
+    keep_halfwidth_kana: true,
+};
+
+impl Default for UnifyWidth {
+    fn default() -> Self {
+        UNIFY_WIDTH
+    }
+}
+
+impl UnifyWidth {
+    #[inline(always)]
+    fn changes(&self, c: char) -> bool {
+        is_fullwidth(c)
+            || is_width_variant_symbol(c)
+            || is_line_variant(c)
+            || (!self.keep_halfwidth_kana && is_halfwidth_katakana(c))
+    }
+}
 
 impl Stage for UnifyWidth {
     fn name(&self) -> &'static str {
@@ -36,17 +98,25 @@ impl Stage for UnifyWidth {
     #[inline(always)]
     fn needs_apply(&self, text: &str, _ctx: &Context) -> Result<bool, StageError> {
         // Extremely fast scan — full-width chars are rare in most text
-        Ok(text.chars().any(is_fullwidth))
+        if text.is_ascii() {
+            return Ok(false);
+        }
+        Ok(text.chars().any(|c| self.changes(c)))
     }
 
     fn apply<'a>(&self, text: Cow<'a, str>, _ctx: &Context) -> Result<Cow<'a, str>, StageError> {
-        // Direct allocation path: 1:1 mapping means capacity matches exactly
+        // Every form mapped here is 3 bytes wide on both sides, or shrinks
         let mut out = String::with_capacity(text.len());
-        for c in text.chars() {
-            out.push(fullwidth_to_halfwidth(c));
-        }
+        out.extend(UnifyWidthAdapter {
+            input: text.chars().peekable(),
+            keep_halfwidth_kana: self.keep_halfwidth_kana,
+        });
         Ok(Cow::Owned(out))
     }
+
+    fn options(&self) -> String {
+        format!("keep_halfwidth_kana={}", self.keep_halfwidth_kana)
+    }
 }
 
 impl StaticFusableStage for UnifyWidth {
@@ -65,12 +135,22 @@ impl StaticFusableStage for UnifyWidth {
     where
         I: FusedIterator<Item = char> + 'a,
     {
-        UnifyWidthAdapter { input }
+        UnifyWidthAdapter {
+            input: input.peekable(),
+            keep_halfwidth_kana: self.keep_halfwidth_kana,
+        }
     }
 }
 
-pub struct UnifyWidthAdapter<I> {
-    input: I,
+/// Full-width ASCII, symbol and line variants to their standard forms
+#[inline(always)]
+fn narrow(c: char) -> char {
+    line_variant_to_standard(width_variant_symbol_to_standard(fullwidth_to_halfwidth(c)))
+}
+
+pub struct UnifyWidthAdapter<I: Iterator<Item = char>> {
+    input: Peekable<I>,
+    keep_halfwidth_kana: bool,
 }
 
 impl<I: Iterator<Item = char>> Iterator for UnifyWidthAdapter<I> {
@@ -78,13 +158,29 @@ impl<I: Iterator<Item = char>> Iterator for UnifyWidthAdapter<I> {
 
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
-        self.input.next().map(fullwidth_to_halfwidth)
+        let c = self.input.next()?;
+        if c.is_ascii() {
+            return Some(c);
+        }
+        if self.keep_halfwidth_kana {
+            // Half-width kana and marks pass through, so nothing composes
+            return Some(narrow(c));
+        }
+        let wide = halfwidth_katakana_to_fullwidth(c);
+        if let Some(&mark) = self.input.peek()
+            && let Some(composed) = compose_kana_voicing(wide, mark)
+        {
+            self.input.next();
+            return Some(composed);
+        }
+        Some(narrow(wide))
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        // 1:1 mapping preserves exact string length
-        self.input.size_hint()
+        // Composition can halve the count; never grows
+        let (lower, upper) = self.input.size_hint();
+        (lower.div_ceil(2), upper)
     }
 }
 
@@ -92,12 +188,21 @@ impl<I: FusedIterator<Item = char>> FusedIterator for UnifyWidthAdapter<I> {}
 
 impl StageTestConfig for UnifyWidth {
     fn one_to_one_languages() -> &'static [Lang] {
-        &[JPN, ZHO, KOR]
+        &[] // Half-width sound marks compose with their base (ﾊﾟ → パ)
     }
 
     fn samples(lang: Lang) -> &'static [&'static str] {
         match lang {
-            JPN => &["Ｈｅｌｌｏ　Ｗｏｒｌｄ！", "１２３４５円"],
+            JPN => &[
+                "Ｈｅｌｌｏ　Ｗｏｒｌｄ！",
+                "１２３４５円",
+                "ﾊﾟﾋﾟﾌﾟ",
+                "ｶﾞｷﾞｸﾞ ｳﾞ",
+                "ｱﾞｲﾟ",
+                "ハﾟ",
+                "￥1,000 ￩戻る",
+                "Ｈｅｌｌｏ　ﾊﾟﾋﾟ ハﾟ ｶﾀｶﾅ　￥１００",
+            ],
             ZHO => &["你好　Ｗｏｒｌｄ", "全角１２３"],
             KOR => &["안녕　Ｗｏｒｌｄ", "１２３"],
             _ => &["Full-width ABC１２３！　", "Normal text"],
@@ -115,6 +220,9 @@ impl StageTestConfig for UnifyWidth {
             ("！＠＃", "!@#"),
             ("　", " "),
             ("Ｈｅｌｌｏ　Ｗｏｒｌｄ！", "Hello World!"),
+            ("￥￦￡", "¥₩£"),
+            ("ｆｏｏ＿ｂａｒ", "foo_bar"),
+            ("﹍﹏", "__"),
         ]
     }
 }
@@ -126,6 +234,109 @@ mod contract_tests {
 
     #[test]
     fn universal_contract_compliance() {
-        assert_stage_contract!(UnifyWidth);
+        assert_stage_contract!(UNIFY_WIDTH);
+        assert_stage_contract!(UNIFY_WIDTH_KEEP_KANA);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn halfwidth_sound_marks_compose_with_their_base() {
+        let ctx = Context::new(JPN);
+        let stage = UNIFY_WIDTH;
+
+        assert_eq!(
+            stage.apply(Cow::Borrowed("ｶﾀｶﾅ"), &ctx).unwrap(),
+            "カタカナ"
+        );
+        let out = stage.apply(Cow::Borrowed("ﾊﾟﾋﾟﾌﾟ"), &ctx).unwrap();
+        assert_eq!(out, "パピプ");
+        assert!(!out.contains(['\u{FF9F}', '゜', '\u{309A}']));
+
+        assert_eq!(
+            stage.apply(Cow::Borrowed("ｶﾞｻﾞﾀﾞﾊﾞ ｳﾞｧ"), &ctx).unwrap(),
+            "ガザダバ ヴァ"
+        );
+        // Already-wide base still composes with a half-width mark
+        assert_eq!(stage.apply(Cow::Borrowed("ハﾟ"), &ctx).unwrap(), "パ");
+    }
+
+    #[test]
+    fn uncomposable_sound_marks_become_spacing_marks() {
+        let ctx = Context::new(JPN);
+        let stage = UNIFY_WIDTH;
+
+        assert_eq!(stage.apply(Cow::Borrowed("ｱﾞ"), &ctx).unwrap(), "ア゛");
+        assert_eq!(stage.apply(Cow::Borrowed("ﾟ"), &ctx).unwrap(), "゜");
+        assert_eq!(stage.apply(Cow::Borrowed("ｶﾟ"), &ctx).unwrap(), "カ゜");
+    }
+
+    #[test]
+    fn fused_path_composes_like_apply() {
+        let ctx = Context::new(JPN);
+        let input = "ＨＥＬＬＯ　ﾊﾟﾋﾟﾌﾟﾍﾟﾎﾟ－－－日本語です。";
+        let applied = UNIFY_WIDTH.apply(Cow::Borrowed(input), &ctx).unwrap();
+        let fused: String = UNIFY_WIDTH
+            .static_fused_adapter(input.chars(), &ctx)
+            .collect();
+        assert_eq!(applied, fused);
+        assert_eq!(applied, "HELLO パピプペポ---日本語です。");
+    }
+
+    #[test]
+    fn width_variant_symbols_map_to_standard_forms() {
+        let ctx = Context::new(JPN);
+        let cases = [
+            ("￥500", "¥500"),
+            ("￦1000 ￠99", "₩1000 ¢99"),
+            ("￢￣￤", "¬¯¦"),
+            ("ｱ￨ｲ", "ア│イ"),
+            ("￩￪￫￬ ￭￮", "←↑→↓ ■○"),
+            ("｜", "|"),
+        ];
+
+        for (input, expected) in cases {
+            assert!(UNIFY_WIDTH.needs_apply(input, &ctx).unwrap(), "{input}");
+            assert_eq!(
+                UNIFY_WIDTH.apply(Cow::Borrowed(input), &ctx).unwrap(),
+                expected
+            );
+            let fused: String = UNIFY_WIDTH
+                .static_fused_adapter(input.chars(), &ctx)
+                .collect();
+            assert_eq!(fused, expected);
+        }
+        // Standard forms and the unassigned U+FFE7 are left alone
+        assert!(!UNIFY_WIDTH.needs_apply("¥ ← ■ \u{FFE7}", &ctx).unwrap());
+    }
+
+    #[test]
+    fn underscore_variants_become_ascii_underscore() {
+        let ctx = Context::new(JPN);
+        let input = "ｓｎａｋｅ＿ｃａｓｅ snake\u{FE4D}case ﹏ ︳ tilde\u{FE4B}";
+        let out = UNIFY_WIDTH.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "snake_case snake_case _ _ tilde\u{203E}");
+
+        let fused: String = UNIFY_WIDTH
+            .static_fused_adapter(input.chars(), &ctx)
+            .collect();
+        assert_eq!(fused, out);
+        assert!(!UNIFY_WIDTH.needs_apply(&out, &ctx).unwrap());
+    }
+
+    #[test]
+    fn keep_halfwidth_kana_only_unifies_latin_and_symbols() {
+        let ctx = Context::new(JPN);
+        let stage = UNIFY_WIDTH_KEEP_KANA;
+        let input = "Ｈｅｌｌｏ　ﾊﾟﾋﾟ ハﾟ ｶﾀｶﾅ　￥１００";
+
+        let out = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+        assert_eq!(out, "Hello ﾊﾟﾋﾟ ハﾟ ｶﾀｶﾅ ¥100");
+
+        assert!(!stage.needs_apply("ﾊﾟ ｱｲｳ｡", &ctx).unwrap());
+        assert!(UNIFY_WIDTH.needs_apply("ﾊﾟ", &ctx).unwrap());
     }
 }
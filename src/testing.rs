@@ -1 +1,3 @@
 pub mod stage_contract;
+
+pub use stage_contract::{ContractViolation, check_contract};
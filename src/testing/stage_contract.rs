@@ -1,6 +1,12 @@
 #[cfg(test)]
 use crate::stage::StaticFusableStage;
-use crate::{lang::Lang, stage::Stage};
+use crate::{
+    context::Context,
+    lang::Lang,
+    stage::{Stage, StageError},
+};
+use std::borrow::Cow;
+use thiserror::Error;
 
 /// Trait that stages implement to opt into the universal test suite.
 pub trait StageTestConfig: Stage + Sized {
@@ -67,9 +73,7 @@ macro_rules! assert_stage_contract {
 // ============================================================================
 
 #[cfg(test)]
-use crate::{ENG, all_langs, context::Context};
-#[cfg(test)]
-use std::borrow::Cow;
+use crate::{ENG, all_langs};
 
 #[cfg(test)]
 pub fn zero_copy_when_no_changes<S: StageTestConfig>(stage: S) {
@@ -132,21 +136,28 @@ pub fn zero_copy_when_no_changes<S: StageTestConfig>(stage: S) {
 
 #[cfg(test)]
 pub fn fused_path_equivalent_to_apply<S: Stage + StaticFusableStage + StageTestConfig>(stage: S) {
-    for &lang in S::one_to_one_languages() {
+    if !stage.supports_static_fusion() {
+        return;
+    }
+    // The fused path runs in every language, not only the 1:1 ones
+    for &lang in all_langs() {
         let ctx = Context::new(lang);
-        let input = "AbCdEfGhIjKlMnOpQrStUvWxYz ÀÉÎÖÜñç 123!@# テスト";
-
-        let via_apply = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
-
-        if stage.supports_static_fusion() {
-            let adapter = stage.static_fused_adapter(input.chars(), &ctx);
-            let via_fused: String = adapter.collect();
+        let fixed = ["AbCdEfGhIjKlMnOpQrStUvWxYz ÀÉÎÖÜñç 123!@# テスト"];
+        let inputs = fixed
+            .iter()
+            .chain(S::samples(lang))
+            .chain(S::should_pass_through(lang))
+            .chain(S::should_transform(lang).iter().map(|(input, _)| input));
+        for &input in inputs {
+            let via_apply = stage.apply(Cow::Borrowed(input), &ctx).unwrap();
+            let via_fused: String = stage.static_fused_adapter(input.chars(), &ctx).collect();
             assert_eq!(
                 via_apply.as_ref(),
                 via_fused,
-                "static fused adapter path ≠ apply() in {lang:?}\n\
+                "static fused adapter path ≠ apply() in {} on `{input}`\n\
              apply(): {via_apply:?}\n\
-             fused:   {via_fused:?}"
+             fused:   {via_fused:?}",
+                lang.code
             );
         }
     }
@@ -250,3 +261,228 @@ pub fn no_panic_on_mixed_scripts<S: StageTestConfig>(stage: S) {
         &ctx,
     );
 }
+
+// ============================================================================
+// Runtime contract check
+// ============================================================================
+
+/// A broken stage contract, reported by [`check_contract`].
+#[derive(Debug, Error)]
+pub enum ContractViolation {
+    /// `needs_apply` returned `true` for text the stage leaves unchanged, so
+    /// the pipeline would allocate for nothing. `input` is the pass-through
+    /// case, or the sample whose output still asked for a second pass.
+    #[error("stage `{stage}` is not zero-copy in {} on `{input}`: needs_apply() is true", lang.code)]
+    NotZeroCopy {
+        stage: &'static str,
+        lang: Lang,
+        input: &'static str,
+    },
+
+    /// `apply` disagrees with one of the stage's own `should_transform` cases.
+    #[error(
+        "stage `{stage}` turned `{input}` into {output:?} in {}, expected {expected:?}",
+        lang.code
+    )]
+    WrongTransform {
+        stage: &'static str,
+        lang: Lang,
+        input: &'static str,
+        expected: &'static str,
+        output: String,
+    },
+
+    /// `needs_apply` disagrees with what `apply` actually does.
+    #[error(
+        "needs_apply() of stage `{stage}` predicted {predicted} in {} on `{input}`, \
+         but apply() returned {output:?}",
+        lang.code
+    )]
+    NeedsApplyMismatch {
+        stage: &'static str,
+        lang: Lang,
+        input: &'static str,
+        predicted: bool,
+        output: String,
+    },
+
+    /// Applying the stage to its own output changed it again.
+    #[error("stage `{stage}` is not idempotent in {} on `{input}`: {once:?} → {twice:?}", lang.code)]
+    NotIdempotent {
+        stage: &'static str,
+        lang: Lang,
+        input: &'static str,
+        once: String,
+        twice: String,
+    },
+
+    #[error(transparent)]
+    Stage(#[from] StageError),
+}
+
+/// Check a stage against the zero-copy, idempotency and `needs_apply`
+/// accuracy contracts, and its expected transforms, on its own
+/// [`StageTestConfig`] cases in `lang`.
+///
+/// The runtime counterpart of [`assert_stage_contract!`](crate::assert_stage_contract)
+/// for custom stages: it returns the first violation instead of panicking,
+/// so it can run outside a test harness.
+///
+/// Generic rather than `&dyn Stage`: the cases are associated functions of
+/// [`StageTestConfig`], which a trait object cannot reach.
+pub fn check_contract<S: StageTestConfig>(stage: &S, lang: Lang) -> Result<(), ContractViolation> {
+    let ctx = Context::new(lang);
+    for &input in S::samples(lang) {
+        let predicted = stage.needs_apply(input, &ctx)?;
+        let once = stage.apply(Cow::Borrowed(input), &ctx)?;
+        if predicted != (once != input) {
+            return Err(ContractViolation::NeedsApplyMismatch {
+                stage: stage.name(),
+                lang,
+                input,
+                predicted,
+                output: once.into_owned(),
+            });
+        }
+        // A second pass must find nothing left to do
+        if stage.needs_apply(&once, &ctx)? {
+            let twice = stage.apply(once.clone(), &ctx)?;
+            if twice != once {
+                return Err(ContractViolation::NotIdempotent {
+                    stage: stage.name(),
+                    lang,
+                    input,
+                    once: once.into_owned(),
+                    twice: twice.into_owned(),
+                });
+            }
+            return Err(ContractViolation::NotZeroCopy {
+                stage: stage.name(),
+                lang,
+                input,
+            });
+        }
+    }
+    for &input in S::should_pass_through(lang) {
+        if stage.needs_apply(input, &ctx)? {
+            return Err(ContractViolation::NotZeroCopy {
+                stage: stage.name(),
+                lang,
+                input,
+            });
+        }
+    }
+    for &(input, expected) in S::should_transform(lang) {
+        let output = stage.apply(Cow::Borrowed(input), &ctx)?;
+        if output != expected {
+            return Err(ContractViolation::WrongTransform {
+                stage: stage.name(),
+                lang,
+                input,
+                expected,
+                output: output.into_owned(),
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LowerCase, TUR};
+
+    /// Appends `!` to any text without one – never done
+    struct Exclaim;
+
+    impl Stage for Exclaim {
+        fn name(&self) -> &'static str {
+            "exclaim"
+        }
+
+        fn needs_apply(&self, _text: &str, _ctx: &Context) -> Result<bool, StageError> {
+            Ok(true)
+        }
+
+        fn apply<'a>(
+            &self,
+            text: Cow<'a, str>,
+            _ctx: &Context,
+        ) -> Result<Cow<'a, str>, StageError> {
+            Ok(Cow::Owned(format!("{text}!")))
+        }
+    }
+
+    impl StageTestConfig for Exclaim {
+        fn one_to_one_languages() -> &'static [Lang] {
+            &[]
+        }
+
+        fn samples(_lang: Lang) -> &'static [&'static str] {
+            &["hello"]
+        }
+    }
+
+    /// Claims a transform it does not make
+    struct Quiet;
+
+    impl Stage for Quiet {
+        fn name(&self) -> &'static str {
+            "quiet"
+        }
+
+        fn needs_apply(&self, _text: &str, _ctx: &Context) -> Result<bool, StageError> {
+            Ok(false)
+        }
+
+        fn apply<'a>(
+            &self,
+            text: Cow<'a, str>,
+            _ctx: &Context,
+        ) -> Result<Cow<'a, str>, StageError> {
+            Ok(text)
+        }
+    }
+
+    impl StageTestConfig for Quiet {
+        fn one_to_one_languages() -> &'static [Lang] {
+            &[]
+        }
+
+        fn should_transform(_lang: Lang) -> &'static [(&'static str, &'static str)] {
+            &[("HELLO", "hello")]
+        }
+    }
+
+    #[test]
+    fn check_contract_passes_built_in_and_reports_buggy_stage() {
+        for &lang in all_langs() {
+            check_contract(&LowerCase, lang).unwrap();
+        }
+
+        let err = check_contract(&Exclaim, TUR).unwrap_err();
+        assert!(matches!(
+            &err,
+            ContractViolation::NotIdempotent { stage: "exclaim", input: "hello", once, twice, .. }
+                if once == "hello!" && twice == "hello!!"
+        ));
+        assert_eq!(
+            err.to_string(),
+            "stage `exclaim` is not idempotent in TUR on `hello`: \"hello!\" → \"hello!!\""
+        );
+
+        let err = check_contract(&Quiet, TUR).unwrap_err();
+        assert!(matches!(
+            &err,
+            ContractViolation::WrongTransform {
+                input: "HELLO",
+                expected: "hello",
+                ..
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "stage `quiet` turned `HELLO` into \"HELLO\" in TUR, expected \"hello\""
+        );
+    }
+}
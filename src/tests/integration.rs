@@ -2,10 +2,14 @@
 mod integration_tests {
 
     use crate::{
-        ARA, COLLAPSE_WHITESPACE, CaseFold, DEU, JPN, LowerCase, NLD, Normy, SegmentWords,
+        ARA, COLLAPSE_WHITESPACE, CanonicalizeMarks, CaseFold, DEU, DynamicNormyBuilder, ENG,
+        ErrorPolicy, NFD, NORMALIZE_CJK_PUNCTUATION, NORMALIZE_WHITESPACE_FULL, FRA, JPN,
+        REPLACE_CONTROL_CHARS, LowerCase, NLD, Normy, ParseError, SegmentWords, StripHtml,
         TRIM_WHITESPACE, TUR, ZHO,
+        lang::DEFAULT_LANG,
         stage::{
-            normalize_punctuation::NormalizePunctuation, remove_diacritics::RemoveDiacritics,
+            normalize_punctuation::NormalizePunctuation,
+            normalize_whitespace::NormalizeWhitespace, remove_diacritics::RemoveDiacritics,
             strip_control_chars::StripControlChars, unify_width::UnifyWidth,
         },
     };
@@ -142,7 +146,7 @@ mod integration_tests {
 
     #[test]
     fn test_replace_fullwidth() {
-        let normy = Normy::builder().add_stage(UnifyWidth).build();
+        let normy = Normy::builder().add_stage(UnifyWidth::default()).build();
         let text = "Ｈｅｌｌｏ　Ｗｏｒｌｄ！";
         let normalized = normy.normalize(text).unwrap();
         assert_eq!(normalized, "Hello World!");
@@ -193,4 +197,838 @@ mod integration_tests {
         let result = normy.normalize(text).unwrap();
         assert_eq!(&*result, "Hello 中 华 人 民 共 和 国");
     }
+
+    #[test]
+    fn normalize_truncated_cuts_on_char_boundary() {
+        let normy = Normy::builder().lang(DEU).add_stage(LowerCase).build();
+
+        let (out, truncated) = normy.normalize_truncated("ÄÖÜ ÄÖÜ", 5).unwrap();
+        assert_eq!(out, "äö"); // 'ü' would straddle byte 5
+        assert!(truncated);
+
+        let (out, truncated) = normy.normalize_truncated("short", 64).unwrap();
+        assert_eq!(out, "short");
+        assert!(!truncated);
+    }
+
+    #[test]
+    fn normalize_truncated_keeps_combining_sequences_whole() {
+        let normy = Normy::builder().build();
+        let input = "cafe\u{0301}s"; // 'e' + U+0301 spans bytes 3..6
+
+        let (out, truncated) = normy.normalize_truncated(input, 5).unwrap();
+        assert_eq!(out, "caf");
+        assert!(truncated);
+        assert!(matches!(out, std::borrow::Cow::Borrowed(_)));
+
+        let (out, _) = normy.normalize_truncated(input, 6).unwrap();
+        assert_eq!(out, "cafe\u{0301}");
+
+        // Devanagari vowel sign, Thai vowel + tone mark, emoji skin tone
+        for (input, max_bytes) in [("कि", 3), ("กี่", 3), ("👍🏽", 4), ("👍🏽", 7)] {
+            let (out, truncated) = normy.normalize_truncated(input, max_bytes).unwrap();
+            assert_eq!((out.as_ref(), truncated), ("", true), "{input:?} at {max_bytes}");
+        }
+        let (out, _) = normy.normalize_truncated("ab कि", 7).unwrap();
+        assert_eq!(out, "ab ");
+    }
+
+    #[test]
+    fn normalize_with_log_records_removed_spans() {
+        let normy = Normy::builder().add_stage(StripControlChars).build();
+        let input = "id:\u{0007}42\u{0000}!";
+
+        let (out, log) = normy.normalize_with_log(input).unwrap();
+        assert_eq!(out, "id:42!");
+        assert_eq!(log.len(), 2);
+        for edit in &log {
+            assert_eq!(edit.stage, "remove_control_chars");
+            assert!(edit.replacement.is_empty());
+        }
+        assert_eq!(&input[log[0].source_range.clone()], "\u{0007}");
+        assert_eq!(&input[log[1].source_range.clone()], "\u{0000}");
+    }
+
+    #[test]
+    fn normalize_with_log_tracks_each_stage_input() {
+        let normy = Normy::builder()
+            .add_stage(StripHtml)
+            .add_stage(LowerCase)
+            .build();
+
+        let (out, log) = normy.normalize_with_log("<b>Hi</b>").unwrap();
+        assert_eq!(out, normy.normalize("<b>Hi</b>").unwrap());
+
+        let removed: Vec<_> = log
+            .iter()
+            .filter(|e| e.stage == "strip_html")
+            .map(|e| &"<b>Hi</b>"[e.source_range.clone()])
+            .collect();
+        assert_eq!(removed, ["<b>", "</b>"]);
+
+        // Lowercase range is relative to the already-stripped "Hi"
+        let lower = log.iter().find(|e| e.stage == "lowercase").unwrap();
+        assert_eq!(lower.source_range, 0..1);
+        assert_eq!(lower.replacement, "h");
+
+        // Nothing to log when no stage applies
+        let (out, log) = normy.normalize_with_log("plain").unwrap();
+        assert_eq!(out, "plain");
+        assert!(log.is_empty());
+    }
+
+    /// Fails whenever the text contains `!`
+    struct Explode;
+
+    impl crate::stage::Stage for Explode {
+        fn name(&self) -> &'static str {
+            "explode"
+        }
+        fn needs_apply(
+            &self,
+            text: &str,
+            _ctx: &crate::context::Context,
+        ) -> Result<bool, crate::stage::StageError> {
+            Ok(text.contains('!'))
+        }
+        fn apply<'a>(
+            &self,
+            _text: std::borrow::Cow<'a, str>,
+            _ctx: &crate::context::Context,
+        ) -> Result<std::borrow::Cow<'a, str>, crate::stage::StageError> {
+            Err(crate::stage::StageError::Failed("explode", "boom".into()))
+        }
+    }
+
+    impl crate::stage::StaticFusableStage for Explode {
+        type Adapter<'a, I>
+            = crate::stage::StaticIdentityAdapter<'a, I>
+        where
+            I: std::iter::FusedIterator<Item = char> + 'a;
+
+        fn static_fused_adapter<'a, I>(
+            &self,
+            input: I,
+            _ctx: &'a crate::context::Context,
+        ) -> Self::Adapter<'a, I>
+        where
+            I: std::iter::FusedIterator<Item = char> + 'a,
+        {
+            crate::stage::StaticIdentityAdapter::new(input)
+        }
+    }
+
+    #[test]
+    fn error_policy_controls_stage_failures() {
+        let build = |policy| {
+            Normy::builder()
+                .on_stage_error(policy)
+                .add_stage(LowerCase)
+                .add_stage(Explode)
+                .add_stage(TRIM_WHITESPACE)
+                .build()
+        };
+
+        assert!(build(ErrorPolicy::Abort).normalize(" HELLO! ").is_err());
+        assert_eq!(
+            build(ErrorPolicy::SkipStage).normalize(" HELLO! ").unwrap(),
+            "hello!"
+        );
+        assert_eq!(
+            build(ErrorPolicy::UseInput).normalize(" HELLO! ").unwrap(),
+            " HELLO! "
+        );
+
+        // Inputs the failing stage never touches are unaffected
+        assert_eq!(
+            build(ErrorPolicy::Abort).normalize(" HELLO ").unwrap(),
+            "hello"
+        );
+    }
+
+    #[test]
+    fn error_policy_applies_to_dynamic_and_logged_paths() {
+        let normy = Normy::dynamic_builder()
+            .on_stage_error(ErrorPolicy::SkipStage)
+            .add_stage(Explode)
+            .add_stage(LowerCase)
+            .build();
+        assert_eq!(normy.normalize("BANG!").unwrap(), "bang!");
+
+        let (out, log) = normy.normalize_with_log("BANG!").unwrap();
+        assert_eq!(out, "bang!");
+        assert!(log.iter().all(|e| e.stage == "lowercase"));
+
+        let strict = Normy::dynamic_builder().add_stage(Explode).build();
+        assert!(strict.normalize("BANG!").is_err());
+        assert!(strict.normalize_with_log("BANG!").is_err());
+    }
+
+    #[test]
+    fn normalize_grapheme_count_counts_clusters_not_chars() {
+        let normy = Normy::builder().add_stage(NFD).build();
+
+        let (out, graphemes) = normy.normalize_grapheme_count("café").unwrap();
+        assert_eq!(out, "cafe\u{0301}");
+        assert_eq!(out.chars().count(), 5);
+        assert_eq!(graphemes, 4);
+
+        let (_, graphemes) = normy.normalize_grapheme_count("").unwrap();
+        assert_eq!(graphemes, 0);
+    }
+
+    #[test]
+    fn cjk_spaces_collapse_with_unicode_whitespace() {
+        let normy = Normy::builder()
+            .lang(JPN)
+            .add_stage(NORMALIZE_CJK_PUNCTUATION)
+            .add_stage(NORMALIZE_WHITESPACE_FULL)
+            .build();
+
+        let input = "\u{3000}東京\u{3000}\u{3000}大阪、\u{2003}京都\u{3000}";
+        assert_eq!(normy.normalize(input).unwrap(), "東京 大阪, 京都");
+        // The punctuation stage alone leaves spacing to the whitespace stage
+        let punct = Normy::builder().add_stage(NORMALIZE_CJK_PUNCTUATION).build();
+        assert_eq!(punct.normalize("東京\u{3000}大阪").unwrap(), "東京\u{3000}大阪");
+        // U+303F is a visible symbol, not a space
+        assert_eq!(normy.normalize("東京\u{303F}大阪").unwrap(), "東京\u{303F}大阪");
+    }
+
+    #[test]
+    fn closure_stage_runs_in_dynamic_pipeline() {
+        let normy = Normy::dynamic_builder()
+            .add_stage(TRIM_WHITESPACE)
+            .add_fn("ascii_upper", |text, _ctx| {
+                if text.bytes().any(|b| b.is_ascii_lowercase()) {
+                    Ok(std::borrow::Cow::Owned(text.to_ascii_uppercase()))
+                } else {
+                    Ok(text)
+                }
+            })
+            .build();
+
+        assert_eq!(normy.normalize("  hello, café  ").unwrap(), "HELLO, CAFé");
+        // Untouched input stays borrowed through the closure
+        assert!(matches!(
+            normy.normalize("ABC").unwrap(),
+            std::borrow::Cow::Borrowed(_)
+        ));
+
+        let (_, log) = normy.normalize_with_log("abc").unwrap();
+        assert!(log.iter().all(|e| e.stage == "ascii_upper"));
+    }
+
+    #[test]
+    fn lossy_bytes_are_decoded_then_normalized() {
+        let normy = Normy::builder()
+            .add_stage(TRIM_WHITESPACE)
+            .add_stage(LowerCase)
+            .build();
+
+        // 0xFF is never valid UTF-8; the truncated 0xC3 lacks its continuation byte
+        let input = b"  Hello \xFFW\xC3\x96rld \xC3";
+        assert_eq!(
+            normy.normalize_bytes_lossy(input).unwrap(),
+            "hello \u{FFFD}w\u{00F6}rld \u{FFFD}"
+        );
+        assert_eq!(normy.normalize_bytes_lossy(b"valid").unwrap(), "valid");
+    }
+
+    #[test]
+    fn compiled_char_table_matches_staged_pipeline() {
+        let normy = Normy::builder()
+            .lang(FRA)
+            .add_stage(NormalizePunctuation)
+            .add_stage(LowerCase)
+            .add_stage(RemoveDiacritics)
+            .add_stage(REPLACE_CONTROL_CHARS)
+            .build();
+        let table = normy.compile_char_table().expect("all stages are 1:1");
+
+        for input in [
+            "« Déjà Vu » — L’ÉTÉ…",
+            "ÇA VA\u{0007} BIEN",
+            "Ελληνικά ΚΕΙΜΕΝΟ",
+            "東京 “quoted”",
+            "already plain",
+            "",
+        ] {
+            assert_eq!(table.apply(input), normy.normalize(input).unwrap(), "{input}");
+        }
+        assert!(matches!(
+            table.apply("already plain"),
+            std::borrow::Cow::Borrowed(_)
+        ));
+        assert_eq!(table.map('É'), 'e');
+    }
+
+    #[test]
+    fn char_table_is_refused_for_non_one_to_one_stages() {
+        // Removal, composition and multi-char folds all change the char count
+        let removes = Normy::builder()
+            .add_stage(LowerCase)
+            .add_stage(StripControlChars)
+            .build();
+        assert!(removes.compile_char_table().is_none());
+
+        let composes = Normy::builder().add_stage(UnifyWidth::default()).build();
+        assert!(composes.compile_char_table().is_none());
+
+        let expands = Normy::builder().lang(DEU).add_stage(CaseFold).build();
+        assert!(expands.compile_char_table().is_none());
+        let folds = Normy::builder().lang(TUR).add_stage(CaseFold).build();
+        assert!(folds.compile_char_table().is_some());
+
+        let drops_harakat = Normy::builder().lang(ARA).add_stage(RemoveDiacritics).build();
+        assert!(drops_harakat.compile_char_table().is_none());
+
+        let closure = Normy::dynamic_builder()
+            .add_fn("noop", |text, _ctx| Ok(text))
+            .build();
+        assert!(closure.compile_char_table().is_none());
+    }
+
+    #[test]
+    fn disabled_stages_are_skipped_at_runtime() {
+        let normy = Normy::builder()
+            .lang(FRA)
+            .add_stage(LowerCase)
+            .add_stage(RemoveDiacritics)
+            .add_stage(TRIM_WHITESPACE)
+            .build();
+
+        assert_eq!(normy.normalize("  Éléphant  ").unwrap(), "elephant");
+
+        let view = normy.with_disabled(&["remove_diacritics"]);
+        assert_eq!(view.normalize("  Éléphant  ").unwrap(), "éléphant");
+
+        let none = normy.with_disabled(&["lowercase", "remove_diacritics", "normalize_whitespace"]);
+        assert!(matches!(
+            none.normalize("  Éléphant  ").unwrap(),
+            std::borrow::Cow::Borrowed("  Éléphant  ")
+        ));
+        // Unknown names are ignored
+        let unknown = normy.with_disabled(&["no_such_stage"]);
+        assert_eq!(unknown.normalize("  Éléphant  ").unwrap(), "elephant");
+    }
+
+    #[test]
+    fn default_process_skipping_runs_the_visited_stages() {
+        use crate::{
+            context::Context,
+            process::Process,
+            stage::{Stage, StageError},
+        };
+        use std::borrow::Cow;
+
+        // Implements only the required methods
+        struct Pair(LowerCase, RemoveDiacritics);
+        impl Process for Pair {
+            fn process<'a>(
+                &self,
+                text: Cow<'a, str>,
+                ctx: &Context,
+            ) -> Result<Cow<'a, str>, StageError> {
+                self.process_skipping(text, ctx, &[])
+            }
+
+            fn for_each_stage<'s>(
+                &'s self,
+                f: &mut dyn FnMut(&'s dyn Stage) -> Result<(), StageError>,
+            ) -> Result<(), StageError> {
+                f(&self.0)?;
+                f(&self.1)
+            }
+        }
+
+        let ctx = Context::new(FRA);
+        let pair = Pair(LowerCase, RemoveDiacritics);
+        assert_eq!(pair.process(Cow::Borrowed("Éléphant"), &ctx).unwrap(), "elephant");
+        let kept = pair.process_skipping(Cow::Borrowed("Éléphant"), &ctx, &["lowercase"]);
+        assert_eq!(kept.unwrap(), "Elephant");
+        let all = ["lowercase", "remove_diacritics"];
+        let none = pair.process_skipping(Cow::Borrowed("éa"), &ctx, &all);
+        assert!(matches!(none.unwrap(), Cow::Borrowed("éa")));
+    }
+
+    #[test]
+    fn char_offsets_point_back_into_source() {
+        let normy = Normy::builder().add_stage(LowerCase).build();
+        let input = "ÀB çD";
+        let pairs: Vec<(char, usize)> = normy.normalize_char_offsets(input).unwrap().collect();
+        assert_eq!(
+            pairs,
+            vec![('à', 0), ('b', 2), (' ', 3), ('ç', 4), ('d', 6)]
+        );
+        for (c, offset) in pairs {
+            let source = input[offset..].chars().next().unwrap();
+            assert_eq!(source.to_lowercase().next(), Some(c));
+        }
+
+        // Removals skip source offsets; expansions share one
+        let normy = Normy::builder()
+            .lang(DEU)
+            .add_stage(StripControlChars)
+            .add_stage(CaseFold)
+            .build();
+        let pairs: Vec<(char, usize)> = normy
+            .normalize_char_offsets("A\u{0007}ß!")
+            .unwrap()
+            .collect();
+        assert_eq!(pairs, vec![('a', 0), ('s', 2), ('s', 2), ('!', 4)]);
+    }
+
+    #[test]
+    fn builder_defaults_to_english_and_reports_lang() {
+        let builder = Normy::builder();
+        assert_eq!(builder.current_lang(), ENG);
+
+        let builder = builder.lang(TUR);
+        assert_eq!(builder.current_lang(), TUR);
+
+        // lang_default resets to ENG: dotted-I rules no longer apply
+        let normy = builder.lang_default().add_stage(LowerCase).build();
+        assert_eq!(normy.normalize("I").unwrap(), "i");
+
+        let dynamic = Normy::dynamic_builder().lang(DEU);
+        assert_eq!(dynamic.current_lang(), DEU);
+        assert_eq!(dynamic.lang_default().current_lang(), DEFAULT_LANG);
+    }
+
+    #[test]
+    fn kv_config_builds_configured_stages() {
+        let from_kv = DynamicNormyBuilder::from_kv(
+            TUR,
+            &[
+                (
+                    "normalize_whitespace",
+                    "collapse=true, trim=true, normalize_unicode=false, replacement_char=_",
+                ),
+                ("lowercase", ""),
+            ],
+        )
+        .unwrap()
+        .build();
+        let by_hand = Normy::dynamic_builder()
+            .lang(TUR)
+            .add_stage(NormalizeWhitespace {
+                collapse: true,
+                trim: true,
+                normalize_unicode: false,
+                replacement_char: '_',
+            })
+            .add_stage(LowerCase)
+            .build();
+
+        for text in ["  İSTANBUL   Boğazı ", "a\u{00A0} b", ""] {
+            assert_eq!(from_kv.normalize(text).unwrap(), by_hand.normalize(text).unwrap());
+        }
+        assert_eq!(from_kv.normalize("  İSTANBUL   Boğazı ").unwrap(), "istanbul_boğazı");
+
+        assert_eq!(
+            DynamicNormyBuilder::from_kv(ENG, &[("lower-case", "")]).err(),
+            Some(ParseError::UnknownStage("lower-case".into()))
+        );
+    }
+
+    #[test]
+    fn fingerprint_tracks_stages_options_and_language() {
+        let build = || {
+            Normy::builder()
+                .lang(TUR)
+                .add_stage(NORMALIZE_WHITESPACE_FULL)
+                .add_stage(LowerCase)
+                .build()
+                .pipeline_fingerprint()
+        };
+        let base = build();
+        assert_eq!(base, build());
+
+        // Same stages built dynamically share the fingerprint
+        let dynamic = Normy::dynamic_builder()
+            .lang(TUR)
+            .add_stage(NORMALIZE_WHITESPACE_FULL)
+            .add_stage(LowerCase)
+            .build();
+        assert_eq!(dynamic.pipeline_fingerprint(), base);
+
+        let extra = Normy::builder()
+            .lang(TUR)
+            .add_stage(NORMALIZE_WHITESPACE_FULL)
+            .add_stage(LowerCase)
+            .add_stage(RemoveDiacritics)
+            .build();
+        assert_ne!(extra.pipeline_fingerprint(), base);
+
+        let other_options = Normy::builder()
+            .lang(TUR)
+            .add_stage(COLLAPSE_WHITESPACE)
+            .add_stage(LowerCase)
+            .build();
+        assert_ne!(other_options.pipeline_fingerprint(), base);
+
+        let other_lang = Normy::builder()
+            .lang(DEU)
+            .add_stage(NORMALIZE_WHITESPACE_FULL)
+            .add_stage(LowerCase)
+            .build();
+        assert_ne!(other_lang.pipeline_fingerprint(), base);
+    }
+
+    #[test]
+    fn canonicalize_marks_cleans_up_stacked_accent_stages() {
+        // A custom step that accents every `e`, run after decomposition – an
+        // already accented `é` ends up with two acutes
+        let build = |canonicalize: bool| {
+            let builder = Normy::dynamic_builder()
+                .lang(FRA)
+                .add_stage(NFD)
+                .add_fn("accent_e", |text, _| {
+                    Ok(std::borrow::Cow::Owned(text.replace('e', "e\u{0301}")))
+                });
+            if canonicalize {
+                builder.add_stage(CanonicalizeMarks).build()
+            } else {
+                builder.build()
+            }
+        };
+
+        let raw = build(false).normalize("café te").unwrap().into_owned();
+        assert_eq!(raw, "cafe\u{0301}\u{0301} te\u{0301}");
+
+        let clean = build(true).normalize("café te").unwrap();
+        assert_eq!(clean, "café té");
+    }
+
+    #[test]
+    fn normalize_with_distance_measures_the_changed_bytes() {
+        let normy = Normy::builder().add_stage(LowerCase).build();
+
+        let (out, distance) = normy.normalize_with_distance("already clean").unwrap();
+        assert!(matches!(out, std::borrow::Cow::Borrowed(_)));
+        assert_eq!(distance, 0);
+
+        // Only "HELLO" differs: five bytes
+        let (out, distance) = normy.normalize_with_distance("say HELLO now").unwrap();
+        assert_eq!(out, "say hello now");
+        assert_eq!(distance, 5);
+
+        let trim = Normy::builder().add_stage(TRIM_WHITESPACE).build();
+        let (out, distance) = trim.normalize_with_distance("  padded ").unwrap();
+        assert_eq!(out, "padded");
+        assert!(distance > 0);
+    }
+
+    #[test]
+    fn auto_picks_the_language_from_the_script() {
+        let normy = Normy::builder().add_stage(SegmentWords).build();
+
+        // English rules leave CJK alone; the detected Chinese rules do not
+        assert_eq!(normy.normalize("Hello世界").unwrap(), "Hello世界");
+        assert_eq!(normy.auto("Hello世界").unwrap(), "Hello 世界");
+        // Kana makes it Japanese: script transitions only, kanji untouched
+        assert_eq!(normy.auto("Rustは最高").unwrap(), "Rust は最高");
+
+        let latin = normy.auto("plain English text").unwrap();
+        assert!(matches!(latin, std::borrow::Cow::Borrowed(_)));
+        assert_eq!(latin, "plain English text");
+    }
+
+    #[test]
+    fn spec_round_trip_rebuilds_the_same_pipeline() {
+        let original = Normy::builder()
+            .lang(DEU)
+            .add_stage(crate::NFC)
+            .add_stage(NORMALIZE_WHITESPACE_FULL)
+            .add_stage(CaseFold)
+            .add_stage(crate::TRANSLITERATE_ESCAPED)
+            .build();
+        let spec = original.to_spec();
+        assert!(spec.starts_with("DEU|nfc|normalize_whitespace:collapse=true"));
+        assert!(spec.ends_with("|transliterate:fallback=escape"));
+
+        let rebuilt = DynamicNormyBuilder::from_spec(&spec).unwrap().build();
+        assert_eq!(rebuilt.to_spec(), spec);
+        assert_eq!(rebuilt.pipeline_fingerprint(), original.pipeline_fingerprint());
+        for text in ["  Größe   Straße ", "Ärger 😀 λ", ""] {
+            assert_eq!(rebuilt.normalize(text).unwrap(), original.normalize(text).unwrap());
+        }
+
+        assert_eq!(
+            DynamicNormyBuilder::from_spec("XXX|nfc").err(),
+            Some(ParseError::UnknownLang("XXX".into()))
+        );
+    }
+
+    #[test]
+    fn normalize_segmented_routes_each_script_run() {
+        let latin = Normy::dynamic_builder().lang(ENG).add_stage(LowerCase).build();
+        let cjk = Normy::dynamic_builder()
+            .lang(ZHO)
+            .add_fn("bracket", |text, _| Ok(std::borrow::Cow::Owned(format!("[{text}]"))))
+            .build();
+        let router = |class| match class {
+            crate::CharClass::Cjk => &cjk,
+            _ => &latin,
+        };
+
+        assert_eq!(latin.normalize_segmented("Hello 世界", router).unwrap(), "hello [世界]");
+        assert_eq!(
+            latin.normalize_segmented("世界 HELLO 世界!", router).unwrap(),
+            "[世界 ]hello [世界]!"
+        );
+        // No script chars: the receiver's own pipeline
+        assert_eq!(cjk.normalize_segmented(" \u{2605} ", router).unwrap(), "[ \u{2605} ]");
+    }
+
+    #[test]
+    fn is_normalized_holds_for_output_and_fails_for_raw_input() {
+        let fused = Normy::builder()
+            .lang(FRA)
+            .add_stage(CaseFold)
+            .add_stage(RemoveDiacritics)
+            .add_stage(COLLAPSE_WHITESPACE)
+            .build();
+        let unfused = Normy::builder()
+            .lang(FRA)
+            .add_stage(StripHtml)
+            .add_stage(CaseFold)
+            .build();
+
+        let raw = "<b>Crème</b>   BRÛLÉE ";
+        let out = fused.normalize(raw).unwrap();
+        assert!(!fused.is_normalized(raw).unwrap());
+        assert!(fused.is_normalized(&out).unwrap());
+        assert!(fused.is_normalized("").unwrap());
+
+        let out = unfused.normalize(raw).unwrap();
+        assert!(!unfused.is_normalized(raw).unwrap());
+        assert!(unfused.is_normalized(&out).unwrap());
+
+        let dynamic = DynamicNormyBuilder::from_kv(
+            FRA,
+            &[("strip_html", ""), ("case_fold", ""), ("remove_diacritics", "")],
+        )
+        .unwrap()
+        .build();
+        let out = dynamic.normalize(raw).unwrap();
+        assert_eq!(out, "creme   brulee");
+        assert!(!dynamic.is_normalized(raw).unwrap());
+        assert!(dynamic.is_normalized(&out).unwrap());
+    }
+
+    #[test]
+    fn max_stages_rejects_configs_one_over_the_limit() {
+        let pairs = [("nfc", ""), ("lowercase", ""), ("normalize_whitespace", "")];
+        let capped = || DynamicNormyBuilder::default().lang(ENG).with_max_stages(3);
+
+        let at_limit = capped().add_kv(&pairs).unwrap().build();
+        assert_eq!(at_limit.normalize(" A  B ").unwrap(), "a b");
+
+        let over = [("strip_html", "")];
+        assert_eq!(
+            capped().add_kv(&pairs).unwrap().add_kv(&over).err(),
+            Some(ParseError::TooManyStages { limit: 3, count: 4 })
+        );
+        assert!(capped().add_spec("ENG|nfc|lowercase|normalize_whitespace").is_ok());
+        let err = capped()
+            .add_spec("ENG|nfc|lowercase|normalize_whitespace|strip_html")
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), "pipeline has 4 stages, more than the limit of 3");
+
+        // The constructors for untrusted configs take the cap up front
+        let spec = "ENG|nfc|lowercase|normalize_whitespace|strip_html";
+        assert!(DynamicNormyBuilder::from_spec_with_max_stages(spec, 4).is_ok());
+        assert_eq!(
+            DynamicNormyBuilder::from_spec_with_max_stages(spec, 3).err(),
+            Some(ParseError::TooManyStages { limit: 3, count: 4 })
+        );
+        let over_kv = [pairs.as_slice(), &over].concat();
+        assert_eq!(
+            DynamicNormyBuilder::from_kv_with_max_stages(ENG, &over_kv, 3).err(),
+            Some(ParseError::TooManyStages { limit: 3, count: 4 })
+        );
+        // The cap stays in force for later additions
+        let built = DynamicNormyBuilder::from_kv_with_max_stages(ENG, &pairs, 3).unwrap();
+        assert!(built.add_kv(&over).is_err());
+    }
+
+    #[test]
+    fn diff_across_langs_shows_turkish_dotless_i() {
+        let outputs = crate::diff_across_langs("INTEL", "lowercase").unwrap();
+        assert_eq!(outputs[0], (ENG, "intel".to_owned()));
+        assert!(outputs.contains(&(TUR, "ıntel".to_owned())));
+        assert!(outputs.iter().skip(1).all(|(lang, out)| *lang != ENG && out != "intel"));
+
+        // Nothing language-dependent: only the baseline is returned
+        assert_eq!(crate::diff_across_langs("abc", "nfc").unwrap().len(), 1);
+        assert!(crate::diff_across_langs("abc", "lower-case").is_err());
+    }
+
+    #[test]
+    fn snapshot_records_every_stage_transition() {
+        let normy = Normy::builder()
+            .lang(TUR)
+            .add_stage(TRIM_WHITESPACE)
+            .add_stage(LowerCase)
+            .add_stage(RemoveDiacritics)
+            .build();
+
+        let snapshot = normy.snapshot("  İSTANBUL'DA ÇAY  ");
+        let names: Vec<&str> = snapshot.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, ["normalize_whitespace", "lowercase", "remove_diacritics"]);
+        assert_eq!(snapshot[0].1, "İSTANBUL'DA ÇAY");
+        assert_eq!(snapshot[1].1, "istanbul'da çay");
+        assert_eq!(snapshot[2].1, normy.normalize("  İSTANBUL'DA ÇAY  ").unwrap());
+        // Turkish keeps Ç as a letter: the last stage changes nothing
+        assert_eq!(snapshot[2].1, snapshot[1].1);
+    }
+
+    #[test]
+    fn normalize_stats_counts_bytes_chars_and_fired_stages() {
+        let normy = Normy::builder()
+            .add_stage(StripHtml)
+            .add_stage(LowerCase)
+            .add_stage(crate::NFC)
+            .build();
+
+        let (out, stats) = normy.normalize_stats("<p>Ça VA</p>").unwrap();
+        assert_eq!(out, "ça va");
+        assert_eq!(
+            stats,
+            crate::NormalizeStats {
+                bytes_in: 13,
+                bytes_out: 6,
+                chars_in: 12,
+                chars_out: 5,
+                stages_fired: 2,
+            }
+        );
+
+        let (out, stats) = normy.normalize_stats("déjà").unwrap();
+        assert!(matches!(out, std::borrow::Cow::Borrowed(_)));
+        assert_eq!(stats.stages_fired, 0);
+        assert_eq!((stats.bytes_in, stats.chars_in), (6, 4));
+    }
+
+    #[test]
+    fn normalize_ascii_rejects_or_escapes_leftover_chars() {
+        let normy = Normy::builder()
+            .lang(crate::RUS)
+            .add_stage(crate::Transliterate::default())
+            .add_stage(RemoveDiacritics)
+            .build();
+        assert_eq!(normy.normalize_ascii("Привет").unwrap(), "Privet");
+
+        // The snowman has no transliteration and survives the pipeline
+        let err = normy.normalize_ascii("Привет ☃").unwrap_err();
+        assert!(matches!(
+            err,
+            crate::NormyError::Stage(crate::stage::StageError::NonAscii {
+                first_char: '☃',
+                offset: 7,
+            })
+        ));
+        assert_eq!(normy.normalize_ascii_escaped("Привет ☃").unwrap(), "Privet \\u{2603}");
+        assert_eq!(normy.normalize_ascii_escaped("Привет").unwrap(), "Privet");
+        assert_eq!(normy.normalize_ascii_escaped("🔟☃1").unwrap(), "\\u{1F51F}\\u{2603}1");
+    }
+
+    #[test]
+    fn normalize_with_script_reports_the_dominant_class() {
+        use crate::CharClass;
+
+        let normy = Normy::builder()
+            .add_stage(crate::UNIFY_WIDTH)
+            .add_stage(LowerCase)
+            .build();
+
+        let (out, class) = normy.normalize_with_script("東京タワーＡＢＣ").unwrap();
+        assert_eq!((out.as_ref(), class), ("東京タワーabc", CharClass::Cjk));
+
+        // Full-width Latin only becomes Western once normalized
+        let (out, class) = normy.normalize_with_script("ＨＥＬＬＯ world ！").unwrap();
+        assert_eq!((out.as_ref(), class), ("hello world !", CharClass::Western));
+
+        assert_eq!(normy.normalize_with_script(" 😀 ").unwrap().1, CharClass::Other);
+
+        let dynamic = DynamicNormyBuilder::from_kv(ENG, &[("unify_width", ""), ("lowercase", "")])
+            .unwrap()
+            .build();
+        let (out, class) = dynamic.normalize_with_script("ＨＥＬＬＯ world ！").unwrap();
+        assert_eq!((out.as_ref(), class), ("hello world !", CharClass::Western));
+    }
+
+    #[cfg(feature = "cache")]
+    #[test]
+    fn cached_normy_serves_repeats_and_evicts_least_recent() {
+        use crate::{CacheStats, CachedNormy};
+
+        let normy = Normy::builder().add_stage(LowerCase).build();
+        let cached = CachedNormy::new(normy, 2);
+
+        assert_eq!(cached.normalize("Rust").unwrap(), "rust");
+        assert_eq!(cached.normalize("Rust").unwrap(), "rust");
+        assert_eq!(cached.stats(), CacheStats { hits: 1, misses: 1 });
+
+        // "Go" then "Zig" fill the cache; "Rust" was used less recently
+        cached.normalize("Go").unwrap();
+        cached.normalize("Rust").unwrap();
+        cached.normalize("Zig").unwrap();
+        assert_eq!(cached.len(), 2);
+        assert_eq!(cached.stats(), CacheStats { hits: 2, misses: 3 });
+        assert_eq!(cached.normalize("Go").unwrap(), "go");
+        assert_eq!(cached.stats(), CacheStats { hits: 2, misses: 4 });
+
+        cached.clear();
+        assert!(cached.is_empty());
+        assert_eq!(cached.stats(), CacheStats::default());
+
+        let dynamic = DynamicNormyBuilder::default().lang(DEU).add_stage(CaseFold).build();
+        let dynamic = CachedNormy::new(dynamic, 0);
+        assert_eq!(dynamic.normalize("Straße").unwrap(), "strasse");
+        assert_eq!(dynamic.normalize("Straße").unwrap(), "strasse");
+        assert_eq!(dynamic.stats(), CacheStats { hits: 0, misses: 2 });
+    }
+
+    #[test]
+    fn builder_warns_when_remove_diacritics_runs_without_nfc() {
+        let builder = Normy::builder().add_stage(LowerCase).add_stage(RemoveDiacritics);
+        let missing = crate::PipelineWarning::MissingNfc {
+            stage: "remove_diacritics",
+        };
+        assert_eq!(builder.warnings(), [missing]);
+        assert_eq!(builder.build_strict().err(), Some(missing));
+
+        // NFD after NFC decomposes again, so the warning still fires
+        let builder = Normy::builder()
+            .add_stage(crate::NFC)
+            .add_stage(NFD)
+            .add_stage(RemoveDiacritics);
+        assert_eq!(builder.warnings(), [missing]);
+
+        let normy = Normy::builder()
+            .lang(FRA)
+            .add_stage(crate::NFC)
+            .add_stage(RemoveDiacritics)
+            .build_strict()
+            .unwrap();
+        assert_eq!(normy.normalize("cafe\u{301}").unwrap(), "cafe");
+
+        // CanonicalizeMarks runs NFC, so it composes too
+        let builder = Normy::builder()
+            .add_stage(CanonicalizeMarks)
+            .add_stage(RemoveDiacritics);
+        assert!(builder.warnings().is_empty());
+        assert!(builder.build_strict().is_ok());
+
+        let dynamic = DynamicNormyBuilder::from_spec("FRA|transliterate|nfkc|remove_diacritics");
+        assert_eq!(
+            dynamic.unwrap().warnings(),
+            [crate::PipelineWarning::MissingNfc {
+                stage: "transliterate"
+            }]
+        );
+    }
 }
@@ -364,7 +364,7 @@ mod prop_tests {
         // UnifyWidth idempotency
         #[test]
         fn unify_width_idempotent(s in ".{0,500}") {
-            let normy = Normy::builder().lang(JPN).add_stage(UnifyWidth).build();
+            let normy = Normy::builder().lang(JPN).add_stage(UnifyWidth::default()).build();
             let once = normy.normalize(&s).unwrap().into_owned();
             let twice = normy.normalize(&once).unwrap().into_owned();
             prop_assert_eq!(once, twice, "UnifyWidth not idempotent");
@@ -373,7 +373,7 @@ mod prop_tests {
         // Full-width to half-width conversion
         #[test]
         fn fullwidth_to_halfwidth(s in "[Ａ-Ｚａ-ｚ０-９]{1,50}") {
-            let normy = Normy::builder().lang(JPN).add_stage(UnifyWidth).build();
+            let normy = Normy::builder().lang(JPN).add_stage(UnifyWidth::default()).build();
             let result = normy.normalize(&s).unwrap();
             prop_assert!(
                 result.chars().all(|c| c.is_ascii_alphanumeric()),
@@ -68,12 +68,6 @@ pub const fn is_format_control(c: char) -> bool {
     )
 }
 
-// The calling function now uses the new standalone function
-#[inline]
-pub fn contains_format_controls(text: &str) -> bool {
-    text.chars().any(is_format_control)
-}
-
 // This is very fast and avoids hash/table indirections.
 #[inline(always)]
 pub fn is_unicode_whitespace(c: char) -> bool {
@@ -151,6 +145,90 @@ pub fn fullwidth_to_halfwidth(c: char) -> char {
     }
 }
 
+// Fullwidth currency/signs (U+FFE0–FFE6) + halfwidth forms/arrows (U+FFE8–FFEE).
+#[inline(always)]
+pub fn is_width_variant_symbol(c: char) -> bool {
+    matches!(c, '\u{FFE0}'..='\u{FFE6}' | '\u{FFE8}'..='\u{FFEE}')
+}
+
+/// Width variant symbols U+FFE0–FFEE, in code point order (U+FFE7 is unassigned).
+static WIDTH_VARIANT_SYMBOLS: [char; 15] = [
+    '¢', '£', '¬', '¯', '¦', '¥', '₩', '\u{FFE7}', '│', '←', '↑', '→', '↓', '■', '○',
+];
+
+#[inline(always)]
+pub fn width_variant_symbol_to_standard(c: char) -> char {
+    if is_width_variant_symbol(c) {
+        WIDTH_VARIANT_SYMBOLS[(c as u32 - 0xFFE0) as usize]
+    } else {
+        c
+    }
+}
+
+// Low line and overline variants of the CJK Compatibility and Small Form
+// Variants blocks (U+FE33–FE34, U+FE49–FE4F).
+#[inline(always)]
+pub fn is_line_variant(c: char) -> bool {
+    matches!(c, '\u{FE33}' | '\u{FE34}' | '\u{FE49}'..='\u{FE4F}')
+}
+
+/// Vertical, dashed, centreline and wavy low lines → `_`; the overline
+/// variants → `‾` (U+203E), which has no ASCII counterpart.
+#[inline(always)]
+pub fn line_variant_to_standard(c: char) -> char {
+    match c {
+        '\u{FE33}' | '\u{FE34}' | '\u{FE4D}'..='\u{FE4F}' => '_',
+        '\u{FE49}'..='\u{FE4C}' => '\u{203E}',
+        _ => c,
+    }
+}
+
+// Halfwidth CJK punctuation + katakana + sound marks (U+FF61–FF9F).
+#[inline(always)]
+pub fn is_halfwidth_katakana(c: char) -> bool {
+    matches!(c, '\u{FF61}'..='\u{FF9F}')
+}
+
+/// Halfwidth forms U+FF61–FF9F, in code point order.
+/// Sound marks map to the spacing ゛/゜; composition happens in `compose_kana_voicing`.
+static HALFWIDTH_KATAKANA: [char; 63] = [
+    '。', '「', '」', '、', '・', 'ヲ', 'ァ', 'ィ', 'ゥ', 'ェ', 'ォ', 'ャ', 'ュ', 'ョ', 'ッ', 'ー',
+    'ア', 'イ', 'ウ', 'エ', 'オ', 'カ', 'キ', 'ク', 'ケ', 'コ', 'サ', 'シ', 'ス', 'セ', 'ソ', 'タ',
+    'チ', 'ツ', 'テ', 'ト', 'ナ', 'ニ', 'ヌ', 'ネ', 'ノ', 'ハ', 'ヒ', 'フ', 'ヘ', 'ホ', 'マ', 'ミ',
+    'ム', 'メ', 'モ', 'ヤ', 'ユ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ワ', 'ン', '゛', '゜',
+];
+
+#[inline(always)]
+pub fn halfwidth_katakana_to_fullwidth(c: char) -> char {
+    if is_halfwidth_katakana(c) {
+        HALFWIDTH_KATAKANA[(c as u32 - 0xFF61) as usize]
+    } else {
+        c
+    }
+}
+
+/// Compose a full-width katakana with a halfwidth voiced (ﾞ) or semi-voiced (ﾟ) mark.
+///
+/// `ｶ` + `ﾞ` → `ガ`, `ﾊ` + `ﾟ` → `パ`. Returns `None` when the pair has no
+/// precomposed form, in which case the mark stays a separate char.
+#[inline(always)]
+pub fn compose_kana_voicing(base: char, mark: char) -> Option<char> {
+    let offset = match (mark, base) {
+        // Voiced: カ行 サ行 タ行 ハ行 – the voiced form follows the base
+        ('\u{FF9E}', 'カ' | 'キ' | 'ク' | 'ケ' | 'コ' | 'サ' | 'シ' | 'ス' | 'セ' | 'ソ')
+        | ('\u{FF9E}', 'タ' | 'チ' | 'ツ' | 'テ' | 'ト' | 'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ') => {
+            1
+        }
+        ('\u{FF9E}', 'ウ') => return Some('ヴ'),
+        ('\u{FF9E}', 'ワ') => return Some('ヷ'),
+        ('\u{FF9E}', 'ヲ') => return Some('ヺ'),
+        // Semi-voiced: ハ行 only, two code points after the base
+        ('\u{FF9F}', 'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ') => 2,
+        _ => return None,
+    };
+    char::from_u32(base as u32 + offset)
+}
+
 #[inline(always)]
 pub fn normalize_punctuation_char(c: char) -> char {
     match c {
@@ -165,6 +243,76 @@ pub fn normalize_punctuation_char(c: char) -> char {
     }
 }
 
+// CJK punctuation (CJK Symbols and Punctuation block + halfwidth forms +
+// Vertical Forms U+FE10–FE19 + Small Form Variants U+FE50–FE6B) → ASCII.
+// Quote brackets are folded only when `fold_quotes` is set.
+#[inline(always)]
+pub fn normalize_cjk_punctuation_char(c: char, fold_quotes: bool) -> char {
+    match c {
+        '\u{FE10}'..='\u{FE19}' => vertical_form_to_horizontal(c),
+        '\u{FE50}'..='\u{FE6B}' => small_form_to_ascii(c),
+        '、' | '､' => ',',
+        '。' | '｡' => '.',
+        '〈' | '《' => '<',
+        '〉' | '》' => '>',
+        '【' | '〔' | '〖' | '〘' | '〚' => '[',
+        '】' | '〕' | '〗' | '〙' | '〛' => ']',
+        '〜' => '~',
+        '「' | '」' | '〝' | '〞' | '〟' | '＂' | '｢' | '｣' if fold_quotes => '"',
+        '『' | '』' | '＇' if fold_quotes => '\'',
+        _ => c,
+    }
+}
+
+// Vertical Forms (U+FE10–FE19) → horizontal ASCII; the ellipsis has no ASCII form.
+#[inline(always)]
+fn vertical_form_to_horizontal(c: char) -> char {
+    match c {
+        '︐' | '︑' => ',',
+        '︒' => '.',
+        '︓' => ':',
+        '︔' => ';',
+        '︕' => '!',
+        '︖' => '?',
+        '︗' => '[',
+        '︘' => ']',
+        '︙' => '…',
+        _ => c,
+    }
+}
+
+// Small Form Variants (U+FE50–FE6B) → ASCII. Unassigned slots pass through.
+#[inline(always)]
+fn small_form_to_ascii(c: char) -> char {
+    match c {
+        '﹐' | '﹑' => ',',
+        '﹒' => '.',
+        '﹔' => ';',
+        '﹕' => ':',
+        '﹖' => '?',
+        '﹗' => '!',
+        '﹘' | '﹣' => '-',
+        '﹙' => '(',
+        '﹚' => ')',
+        '﹛' => '{',
+        '﹜' => '}',
+        '﹝' => '[',
+        '﹞' => ']',
+        '﹟' => '#',
+        '﹠' => '&',
+        '﹡' => '*',
+        '﹢' => '+',
+        '﹤' => '<',
+        '﹥' => '>',
+        '﹦' => '=',
+        '﹨' => '\\',
+        '﹩' => '$',
+        '﹪' => '%',
+        '﹫' => '@',
+        _ => c,
+    }
+}
+
 // Hangul syllables + jamo + compatibility + extended ranges.
 #[inline(always)]
 pub fn is_hangul(c: char) -> bool {
@@ -241,6 +389,7 @@ pub fn is_se_asian_script(c: char) -> bool {
         0xA9E0..=0xA9FF  | // Myanmar Ext-B
         0x1780..=0x17FF  | // Khmer
         0x19E0..=0x19FF  | // Khmer Symbols
+        0x1980..=0x19DF  | // New Tai Lue
         0x1A00..=0x1AAF    // Tai Tham
     )
 }
@@ -278,11 +427,21 @@ pub const fn is_virama(c: char) -> bool {
         0x0DCA | // Sinhala
         0x103A | // Myanmar
         0x17D2 | // Khmer
-        0x1BAA | // Tai Tham
+        0x1A60 | // Tai Tham (sakot)
+        0x1BAA | // Sundanese
         0x1B44 // Balinese
     )
 }
 
+// Nukta signs (Devanagari, Bengali, Gurmukhi, Gujarati, Oriya, Kannada).
+#[inline(always)]
+pub const fn is_nukta(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x093C | 0x09BC | 0x0A3C | 0x0ABC | 0x0B3C | 0x0CBC
+    )
+}
+
 // Codepoints for the most common non-breaking conjunct initial consonants in Devanagari (Hindi)
 // 'र' (ra), 'य' (ya), 'व' (va), 'ह' (ha)
 // The heuristic prevents ZWSP insertion when the Virama is followed by one of these characters.
@@ -310,7 +469,8 @@ const fn is_modern_alphabetic_script(cp: u32) -> bool {
         0x0870..=0x089F | // Arabic Extended-B
         0x08A0..=0x08FF | // Arabic Extended-A
         0x10A0..=0x10FF | // Georgian
-        0x13A0..=0x13FF   // Cherokee
+        0x13A0..=0x13FF | // Cherokee
+        0x1800..=0x18AF   // Mongolian
     )
 }
 
@@ -324,6 +484,244 @@ pub fn is_extended_latin(c: char) -> bool {
     matches!(c as u32, 0x00C0..=0x02AF) // Latin-1 Supplement + Extended A/B
 }
 
+/// Combining characters that attach to the preceding base character.
+///
+/// Covers the combining diacritical blocks, Hebrew/Arabic points, kana voicing
+/// marks, variation selectors and ZWJ – enough to keep a base + its marks together.
+#[inline(always)]
+pub fn is_combining_mark(c: char) -> bool {
+    matches!(c as u32,
+        0x0300..=0x036F | // Combining Diacritical Marks
+        0x0483..=0x0489 | // Cyrillic combining
+        0x0591..=0x05C7 | // Hebrew points
+        0x064B..=0x065F | 0x0670 | // Arabic harakat
+        0x1AB0..=0x1AFF | // Combining Diacritical Marks Extended
+        0x1DC0..=0x1DFF | // Combining Diacritical Marks Supplement
+        0x200D |          // ZWJ
+        0x20D0..=0x20FF | // Combining Marks for Symbols
+        0x3099..=0x309A | // Kana voicing marks
+        0xFE00..=0xFE0F | // Variation selectors
+        0xFE20..=0xFE2F   // Combining Half Marks
+    )
+}
+
+// The four Combining Diacritical Marks blocks: base (U+0300–036F), Extended
+// (U+1AB0–1AFF), Supplement (U+1DC0–1DFF) and for Symbols (U+20D0–20FF).
+#[inline(always)]
+pub fn is_combining_diacritical_mark(c: char) -> bool {
+    matches!(c,
+        '\u{0300}'..='\u{036F}' |
+        '\u{1AB0}'..='\u{1AFF}' |
+        '\u{1DC0}'..='\u{1DFF}' |
+        '\u{20D0}'..='\u{20FF}'
+    )
+}
+
+// Combining Half Marks (U+FE20–FE2F): ligature tildes/macrons split over two bases.
+#[inline(always)]
+pub fn is_combining_half_mark(c: char) -> bool {
+    matches!(c, '\u{FE20}'..='\u{FE2F}')
+}
+
+// Superscript digits and signs (⁰¹²³⁴⁵⁶⁷⁸⁹⁺⁻) → their ASCII counterparts.
+#[inline(always)]
+pub fn superscript_numeric_to_ascii(c: char) -> Option<char> {
+    match c {
+        '\u{2070}' => Some('0'),
+        '\u{00B9}' => Some('1'),
+        '\u{00B2}' => Some('2'),
+        '\u{00B3}' => Some('3'),
+        '\u{2074}'..='\u{2079}' => char::from_u32(c as u32 - 0x2074 + '4' as u32),
+        '\u{207A}' => Some('+'),
+        '\u{207B}' => Some('-'),
+        _ => None,
+    }
+}
+
+// Superscripts and Subscripts block (U+2070–209F) plus Latin-1 ¹²³ → baseline ASCII.
+// The one non-ASCII target is ₔ → ə (subscript schwa).
+#[inline(always)]
+pub fn super_subscript_to_baseline(c: char) -> Option<char> {
+    if let Some(ascii) = superscript_numeric_to_ascii(c) {
+        return Some(ascii);
+    }
+    Some(match c {
+        '\u{2071}' => 'i',
+        '\u{207C}' | '\u{208C}' => '=',
+        '\u{207D}' | '\u{208D}' => '(',
+        '\u{207E}' | '\u{208E}' => ')',
+        '\u{207F}' => 'n',
+        '\u{2080}'..='\u{2089}' => char::from_u32(c as u32 - 0x2080 + '0' as u32)?,
+        '\u{208A}' => '+',
+        '\u{208B}' => '-',
+        '\u{2090}' => 'a',
+        '\u{2091}' => 'e',
+        '\u{2092}' => 'o',
+        '\u{2093}' => 'x',
+        '\u{2094}' => '\u{0259}',
+        '\u{2095}' => 'h',
+        '\u{2096}' => 'k',
+        '\u{2097}' => 'l',
+        '\u{2098}' => 'm',
+        '\u{2099}' => 'n',
+        '\u{209A}' => 'p',
+        '\u{209B}' => 's',
+        '\u{209C}' => 't',
+        _ => return None,
+    })
+}
+
+// Letterlike Symbols (U+2100–214F) that are styled letters → the plain letter.
+// Ω K Å are the canonical singletons U+2126, U+212A, U+212B.
+#[inline(always)]
+pub fn letterlike_to_letter(c: char) -> Option<char> {
+    Some(match c {
+        'ℂ' | 'ℭ' => 'C',
+        'ℇ' => 'Ɛ',
+        'ℊ' => 'g',
+        'ℋ' | 'ℌ' | 'ℍ' => 'H',
+        'ℎ' => 'h',
+        'ℏ' => 'ħ',
+        'ℐ' | 'ℑ' => 'I',
+        'ℒ' => 'L',
+        'ℓ' => 'l',
+        'ℕ' => 'N',
+        'ℙ' => 'P',
+        'ℚ' => 'Q',
+        'ℛ' | 'ℜ' | 'ℝ' => 'R',
+        'ℤ' | 'ℨ' => 'Z',
+        '\u{2126}' => 'Ω',
+        '\u{212A}' => 'K',
+        '\u{212B}' => 'Å',
+        'ℬ' => 'B',
+        'ℯ' | 'ⅇ' => 'e',
+        'ℰ' => 'E',
+        'ℱ' => 'F',
+        'ℳ' => 'M',
+        'ℴ' => 'o',
+        'ℹ' | 'ⅈ' => 'i',
+        'ⅅ' => 'D',
+        'ⅆ' => 'd',
+        'ⅉ' => 'j',
+        _ => return None,
+    })
+}
+
+// Letterlike Symbols that abbreviate words or units → their ASCII spelling
+#[inline(always)]
+pub fn letterlike_expansion(c: char) -> Option<&'static str> {
+    Some(match c {
+        '℀' => "a/c",
+        '℁' => "a/s",
+        '℃' => "°C",
+        '℅' => "c/o",
+        '℆' => "c/u",
+        '℉' => "°F",
+        '№' => "No.",
+        '℠' => "SM",
+        '℡' => "TEL",
+        '™' => "TM",
+        '℻' => "FAX",
+        _ => return None,
+    })
+}
+
+// Phonetic Extensions (U+1D00–1D7F) with a plain Latin base → that ASCII letter.
+// Small capitals and turned/reversed letters go to lowercase, modifier capitals
+// keep their case. Digraphs (ᴁ ᴕ ᵫ ᵺ) and Greek/Cyrillic forms have no entry.
+#[inline(always)]
+pub fn phonetic_extension_to_ascii(c: char) -> Option<char> {
+    Some(match c {
+        'ᴀ' | 'ᵃ' => 'a',
+        'ᴃ' | 'ᵇ' | 'ᵬ' => 'b',
+        'ᴄ' => 'c',
+        'ᴅ' | 'ᴆ' | 'ᵈ' | 'ᵭ' => 'd',
+        'ᴇ' | 'ᵉ' => 'e',
+        'ᵮ' => 'f',
+        'ᵍ' | 'ᵹ' => 'g',
+        'ᴉ' | 'ᵢ' | 'ᵻ' => 'i',
+        'ᴊ' => 'j',
+        'ᴋ' | 'ᵏ' => 'k',
+        'ᴌ' => 'l',
+        'ᴍ' | 'ᵐ' | 'ᵯ' => 'm',
+        'ᴎ' | 'ᵰ' => 'n',
+        'ᴏ' | 'ᴐ' | 'ᵒ' => 'o',
+        'ᴘ' | 'ᵖ' | 'ᵱ' | 'ᵽ' => 'p',
+        'ᴙ' | 'ᴚ' | 'ᵣ' | 'ᵲ' | 'ᵳ' => 'r',
+        'ᵴ' => 's',
+        'ᴛ' | 'ᵗ' | 'ᵵ' => 't',
+        'ᴜ' | 'ᵘ' | 'ᵤ' | 'ᵾ' => 'u',
+        'ᴠ' | 'ᵛ' | 'ᵥ' => 'v',
+        'ᴡ' => 'w',
+        'ᴢ' | 'ᵶ' => 'z',
+        'ᴬ' => 'A',
+        'ᴮ' | 'ᴯ' => 'B',
+        'ᴰ' => 'D',
+        'ᴱ' => 'E',
+        'ᴳ' => 'G',
+        'ᴴ' => 'H',
+        'ᴵ' => 'I',
+        'ᴶ' => 'J',
+        'ᴷ' => 'K',
+        'ᴸ' => 'L',
+        'ᴹ' => 'M',
+        'ᴺ' | 'ᴻ' => 'N',
+        'ᴼ' => 'O',
+        'ᴾ' => 'P',
+        'ᴿ' => 'R',
+        'ᵀ' => 'T',
+        'ᵁ' => 'U',
+        'ᵂ' => 'W',
+        _ => return None,
+    })
+}
+
+// Mongolian free variation selectors FVS1–FVS4 (U+180B–180D, U+180F)
+#[inline(always)]
+pub fn is_mongolian_fvs(c: char) -> bool {
+    matches!(c, '\u{180B}'..='\u{180D}' | '\u{180F}')
+}
+
+// Private Use Area: BMP block + Supplementary PUA-A/B planes (15, 16).
+#[inline(always)]
+pub fn is_private_use(c: char) -> bool {
+    matches!(c as u32,
+        0xE000..=0xF8FF |     // Private Use Area
+        0xF0000..=0xFFFFD |   // Supplementary PUA-A
+        0x100000..=0x10FFFD   // Supplementary PUA-B
+    )
+}
+
+// Spacing Modifier Letters (U+02B0–02FF) folded for search.
+// `None` = not handled, `Some(None)` = remove, `Some(Some(c))` = replace.
+// Tone letters and spacing diacritics not listed here pass through.
+#[inline(always)]
+pub fn fold_modifier_letter(c: char) -> Option<Option<char>> {
+    let folded = match c {
+        // Superscript modifier small letters
+        'ʰ' => 'h',
+        'ʲ' => 'j',
+        'ʳ' => 'r',
+        'ʷ' => 'w',
+        'ʸ' => 'y',
+        'ˡ' => 'l',
+        'ˢ' => 's',
+        'ˣ' => 'x',
+        // Prime, apostrophe, turned/reversed comma, half rings, acute
+        'ʹ' | 'ʻ' | 'ʼ' | 'ʽ' | 'ʾ' | 'ʿ' | 'ˊ' => '\'',
+        'ʺ' | '˝' => '"',
+        'ˋ' => '`',
+        'ˆ' => '^',
+        '˜' => '~',
+        // Length mark
+        'ː' => ':',
+        // Stress marks and half-length carry no letters
+        'ˈ' | 'ˌ' | 'ˑ' => return Some(None),
+        _ => return None,
+    };
+    Some(Some(folded))
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[repr(u8)]
 pub enum CharClass {
@@ -332,7 +730,7 @@ pub enum CharClass {
     Western,      // ASCII letters/digits/punct
     Cjk,          // Han ideographs + Kana + Kangxi radicals
     Hangul,       // Hangul syllables & Jamo
-    SEAsian,      // Thai, Lao, Myanmar, Khmer, Tai Tham
+    SEAsian,      // Thai, Lao, Myanmar, Khmer, Tai Tham, New Tai Lue
     NonCJKScript, // Greek, Cyrillic, Arabic, Hebrew, etc.
     Indic,
 }
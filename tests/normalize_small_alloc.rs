@@ -0,0 +1,74 @@
+//! Heap allocations made by `Normy::normalize_small`, counted by a wrapping
+//! global allocator. Lives outside the library, which forbids `unsafe`.
+
+use normy::{COLLAPSE_WHITESPACE, DynamicNormyBuilder, ENG, LowerCase, Normy, StripHtml};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Allocations made on this thread while running `f`
+fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let out = f();
+    (out, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn fused_short_output_stays_off_the_heap() {
+    let normy = Normy::builder()
+        .add_stage(LowerCase)
+        .add_stage(COLLAPSE_WHITESPACE)
+        .build();
+    normy.normalize_small("Warm Up").unwrap();
+
+    let (tag, count) = allocations(|| normy.normalize_small("Rust   LANG").unwrap());
+    assert_eq!(tag, "rust lang");
+    assert!(tag.is_inline());
+    assert_eq!(count, 0);
+
+    let long = "WORD ".repeat(40);
+    let (out, count) = allocations(|| normy.normalize_small(&long).unwrap());
+    assert!(!out.is_inline());
+    assert!(count > 0);
+}
+
+#[test]
+fn non_fusable_stages_still_allocate_intermediates() {
+    let normy = Normy::builder().add_stage(StripHtml).build();
+    normy.normalize_small("<i>warm up</i>").unwrap();
+
+    let (out, count) = allocations(|| normy.normalize_small("<b>bold</b>").unwrap());
+    assert_eq!(out, "bold");
+    assert!(out.is_inline());
+    assert!(count > 0, "StripHtml builds its output on the heap");
+}
+
+#[test]
+fn dynamic_pipelines_keep_the_final_copy_inline() {
+    let normy = DynamicNormyBuilder::from_kv(ENG, &[("strip_html", ""), ("lowercase", "")])
+        .unwrap()
+        .build();
+
+    let out = normy.normalize_small("<b>BOLD</b>").unwrap();
+    assert_eq!(out, "bold");
+    assert!(out.is_inline());
+}